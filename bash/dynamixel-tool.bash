@@ -1,33 +1,3 @@
-_dynamixel-tool-models() {
-    local P
-    if echo "${COMP_WORDS[@]}" | egrep '\-P\s*2|--protocol[= ]*2'>/dev/null; then
-        P='-P2'
-    fi
-    echo $("${COMP_WORDS[0]}" ${P} list-models 2>/dev/null)
-}
-
-_dynamixel-tool-regs() {
-    local P M regs models
-
-    if echo "${COMP_WORDS[@]}" | egrep '\-P\s*2|--protocol[= ]*2'>/dev/null; then
-        P='-P2'
-    fi
-
-    M=$(echo $1 | cut -d / -f 1)
-
-    if [[ $("${COMP_WORDS[0]}" ${P} list-models 2>/dev/null | egrep ^$M | wc -l) == 1 ]]; then
-        M=$("${COMP_WORDS[0]}" ${P} list-models 2>/dev/null | egrep ^$M)
-    fi
-
-    regs="$("${COMP_WORDS[0]}" ${P} list-registers $M 2>/dev/null | cut -c 11- | sed s,^,$M/,)"
-
-    if [[ -n $regs ]]; then
-        echo "$regs"
-    else
-        echo $("${COMP_WORDS[0]}" ${P} list-models 2>/dev/null)
-    fi
-}
-
 _dynamixel-tool() {
     local i cur prev opts cmds
     COMPREPLY=()
@@ -42,18 +12,90 @@ _dynamixel-tool() {
             "$1")
                 cmd="dynamixel__tool"
                 ;;
+            action)
+                cmd+="__action"
+                ;;
+            backup)
+                cmd+="__backup"
+                ;;
+            benchmark)
+                cmd+="__benchmark"
+                ;;
+            blink)
+                cmd+="__blink"
+                ;;
+            change-baud)
+                cmd+="__change__baud"
+                ;;
+            change-id)
+                cmd+="__change__id"
+                ;;
+            clear)
+                cmd+="__clear"
+                ;;
+            completions)
+                cmd+="__completions"
+                ;;
+            detect-baud)
+                cmd+="__detect__baud"
+                ;;
+            detect-model)
+                cmd+="__detect__model"
+                ;;
+            diff)
+                cmd+="__diff"
+                ;;
+            dump)
+                cmd+="__dump"
+                ;;
+            expect-count)
+                cmd+="__expect__count"
+                ;;
+            factory-reset)
+                cmd+="__factory__reset"
+                ;;
+            firmware)
+                cmd+="__firmware"
+                ;;
             help)
                 cmd+="__help"
                 ;;
+            identify)
+                cmd+="__identify"
+                ;;
             list-models)
                 cmd+="__list__models"
                 ;;
+            list-ports)
+                cmd+="__list__ports"
+                ;;
             list-registers)
                 cmd+="__list__registers"
                 ;;
+            monitor)
+                cmd+="__monitor"
+                ;;
+            move)
+                cmd+="__move"
+                ;;
+            ping)
+                cmd+="__ping"
+                ;;
             read-bytes)
                 cmd+="__read__bytes"
                 ;;
+            read-bytes-multiple)
+                cmd+="__read__bytes__multiple"
+                ;;
+            read-int16)
+                cmd+="__read__int16"
+                ;;
+            read-int32)
+                cmd+="__read__int32"
+                ;;
+            read-int8)
+                cmd+="__read__int8"
+                ;;
             read-reg)
                 cmd+="__read__reg"
                 ;;
@@ -66,15 +108,66 @@ _dynamixel-tool() {
             read-uint8)
                 cmd+="__read__uint8"
                 ;;
+            reboot)
+                cmd+="__reboot"
+                ;;
+            reg-write)
+                cmd+="__reg__write"
+                ;;
+            restore)
+                cmd+="__restore"
+                ;;
+            run)
+                cmd+="__run"
+                ;;
             scan)
                 cmd+="__scan"
                 ;;
+            set-return-level)
+                cmd+="__set__return__level"
+                ;;
+            show-indirect)
+                cmd+="__show__indirect"
+                ;;
+            torque)
+                cmd+="__torque"
+                ;;
+            torque-off)
+                cmd+="__torque__off"
+                ;;
+            torque-on)
+                cmd+="__torque__on"
+                ;;
+            tune)
+                cmd+="__tune"
+                ;;
+            verify)
+                cmd+="__verify"
+                ;;
+            watch)
+                cmd+="__watch"
+                ;;
             write-bytes)
                 cmd+="__write__bytes"
                 ;;
+            write-bytes-multiple)
+                cmd+="__write__bytes__multiple"
+                ;;
+            write-int16)
+                cmd+="__write__int16"
+                ;;
+            write-int32)
+                cmd+="__write__int32"
+                ;;
+            write-int8)
+                cmd+="__write__int8"
+                ;;
             write-reg)
                 cmd+="__write__reg"
                 ;;
+            write-regs)
+                cmd+="__write__regs"
+                ;;
             write-uint16)
                 cmd+="__write__uint16"
                 ;;
@@ -91,7 +184,7 @@ _dynamixel-tool() {
 
     case "${cmd}" in
         dynamixel__tool)
-            opts="-h -V -f -d -p -b -r -j -P --help --version --force --debug --port --baudrate --retries --json --protocol list-models list-registers scan read-uint8 read-uint16 read-uint32 read-bytes read-reg write-uint8 write-uint16 write-uint32 write-bytes write-reg help"
+            opts="-h -V -f -d -p -b -r -j -P --help --version --force --dry-run --debug --port --usb-id --baudrate --retries --timeout --tx-delay --echo-cancel --rs485-delay-before --rs485-delay-after --rs485-rts-on-send --rs485-rts-after-send --json --out --concise-json --json-always-array --json-map --stream --csv --yaml --color --virtual-regs --regs-file --protocol list-models completions list-registers list-ports scan ping firmware expect-count identify read-uint8 read-uint16 read-uint32 read-int8 read-int16 read-int32 read-bytes read-bytes-multiple read-reg write-uint8 write-uint16 write-uint32 write-int8 write-int16 write-int32 write-bytes reg-write action write-bytes-multiple write-reg write-regs dump diff restore verify watch run monitor tune show-indirect set-return-level torque torque-on torque-off blink move detect-baud benchmark detect-model change-id change-baud reboot clear backup factory-reset help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -105,6 +198,10 @@ _dynamixel-tool() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --usb-id)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --baudrate)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -121,6 +218,46 @@ _dynamixel-tool() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --timeout)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --tx-delay)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --rs485-delay-before)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --rs485-delay-after)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --rs485-rts-on-send)
+                    COMPREPLY=($(compgen -W "low high" -- "${cur}"))
+                    return 0
+                    ;;
+                --rs485-rts-after-send)
+                    COMPREPLY=($(compgen -W "low high" -- "${cur}"))
+                    return 0
+                    ;;
+                --out)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --color)
+                    COMPREPLY=($(compgen -W "auto always never" -- "${cur}"))
+                    return 0
+                    ;;
+                --virtual-regs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --regs-file)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --protocol)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -136,8 +273,8 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__help)
-            opts="<SUBCOMMAND>..."
+        dynamixel__tool__action)
+            opts="-h --help <ID>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -150,8 +287,8 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__list__models)
-            opts="-h --help"
+        dynamixel__tool__backup)
+            opts="-h --restore --help <IDS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -164,13 +301,29 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__list__registers)
-            opts="-h --help $(_dynamixel-tool-models)"
+        dynamixel__tool__benchmark)
+            opts="-c -s -h --count --size --help <ID>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --count)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -c)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --size)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -s)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -178,13 +331,29 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__read__bytes)
-            opts="-h --help <IDS> <ADDRESS> <COUNT>"
+        dynamixel__tool__blink)
+            opts="-m -d -h --model --duration --help <ID>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --duration)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -d)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -192,22 +361,44 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__read__reg)
-            opts="-h --help <IDS> <REG>"
+        dynamixel__tool__change__baud)
+            opts="-h --help <IDS> <BAUDRATE>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
-
-            if [[ ${COMP_WORDS[$((COMP_CWORD-2))]} == read-reg ]]; then
-                opts=$(_dynamixel-tool-regs "${cur}")
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__change__id)
+            opts="-m -h --model --help <OLD_ID> <NEW_ID>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
             fi
-
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__read__uint16)
-            opts="-h --help <IDS> <ADDRESS>"
+        dynamixel__tool__clear)
+            opts="-h --help <IDS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -220,8 +411,8 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__read__uint32)
-            opts="-h --help <IDS> <ADDRESS>"
+        dynamixel__tool__completions)
+            opts="-h --help bash elvish fish powershell zsh"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -234,8 +425,8 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__read__uint8)
-            opts="-h --help <IDS> <ADDRESS>"
+        dynamixel__tool__detect__baud)
+            opts="-h --help <IDS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -248,8 +439,8 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__scan)
-            opts="-h --help <SCAN_START> <SCAN_END>"
+        dynamixel__tool__detect__model)
+            opts="-h --help <IDS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -262,13 +453,25 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__write__bytes)
-            opts="-h --help <IDS> <ADDRESS> <VALUES>..."
+        dynamixel__tool__diff)
+            opts="-m -h --model --all --ignore --help <ID_A> <ID_B>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --ignore)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 *)
                     COMPREPLY=()
                     ;;
@@ -276,22 +479,44 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__write__reg)
-            opts="-h --help <IDS> <REG> <VALUE>"
+        dynamixel__tool__dump)
+            opts="-m -h --model --eeprom-only --ram-only --as-commands --help <IDS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
-
-            if [[ ${COMP_WORDS[$((COMP_CWORD-2))]} == write-reg ]]; then
-                opts=$(_dynamixel-tool-regs "${cur}")
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__expect__count)
+            opts="-h --help <COUNT> <SCAN_START> <SCAN_END>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
             fi
-
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__write__uint16)
-            opts="-h --help <IDS> <ADDRESS> <VALUE>"
+        dynamixel__tool__factory__reset)
+            opts="-h --keep-id --keep-baud --help <IDS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -304,8 +529,8 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__write__uint32)
-            opts="-h --help <IDS> <ADDRESS> <VALUE>"
+        dynamixel__tool__firmware)
+            opts="-h --help <IDS>"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -318,8 +543,8 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        dynamixel__tool__write__uint8)
-            opts="-h --help <IDS> <ADDRESS> <VALUE>"
+        dynamixel__tool__help)
+            opts="<SUBCOMMAND>..."
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -332,7 +557,718 @@ _dynamixel-tool() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-    esac
-}
-
-complete -F _dynamixel-tool -o bashdefault -o default dynamixel-tool
+        dynamixel__tool__identify)
+            opts="-h --probe-baudrates --help <IDS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__list__models)
+            opts="-v -h --family --verbose --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --family)
+                    COMPREPLY=($(compgen -W "ax mx xl xm xh pro" -- "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__list__ports)
+            opts="-h --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__list__registers)
+            opts="-h --grep --filter --address --access --area --sort --help <MODEL>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --filter)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --grep)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --access)
+                    COMPREPLY=($(compgen -W "r w rw" -- "${cur}"))
+                    return 0
+                    ;;
+                --area)
+                    COMPREPLY=($(compgen -W "eeprom ram" -- "${cur}"))
+                    return 0
+                    ;;
+                --sort)
+                    COMPREPLY=($(compgen -W "address name size" -- "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__monitor)
+            opts="-i -c -h --interval --count --help <IDS> <REGS>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --interval)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -i)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --count)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -c)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__move)
+            opts="-m -w -h --model --wait --timeout --tolerance --help <IDS> <POSITION>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --timeout)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --tolerance)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__ping)
+            opts="-h --help <IDS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__bytes)
+            opts="-s -h --sync --strict --hex --help <IDS> <ADDRESS> <COUNT>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__bytes__multiple)
+            opts="-h --fast --hex --help <SPECS>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__int16)
+            opts="-s -h --sync --fast --hex --help <IDS> <ADDRESS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__int32)
+            opts="-s -h --sync --fast --hex --help <IDS> <ADDRESS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__int8)
+            opts="-s -h --sync --fast --hex --help <IDS> <ADDRESS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__reg)
+            opts="-h --scaled --hex --help <IDS> <REG>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__uint16)
+            opts="-s -h --sync --fast --hex --help <IDS> <ADDRESS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__uint32)
+            opts="-s -h --sync --fast --hex --help <IDS> <ADDRESS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__read__uint8)
+            opts="-s -h --sync --fast --hex --help <IDS> <ADDRESS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__reboot)
+            opts="-h --help <IDS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__reg__write)
+            opts="-h --help <IDS> <ADDRESS> <VALUES>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__restore)
+            opts="-m -h --model --file --dry-run --help <ID>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --file)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__run)
+            opts="-k -h --keep-going --ndjson --reconnect --help <SCRIPT>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__scan)
+            opts="-h --detail --models --sort --bauds --all-bauds --help <SCAN_START> <SCAN_END>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --sort)
+                    COMPREPLY=($(compgen -W "id model" -- "${cur}"))
+                    return 0
+                    ;;
+                --bauds)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__set__return__level)
+            opts="-m -h --model --help <IDS> <LEVEL>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__show__indirect)
+            opts="-h --help <ID> <MODEL>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__torque)
+            opts="-m -h --model --help <IDS> on off"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__torque__off)
+            opts="-m -h --model --help <IDS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__torque__on)
+            opts="-m -h --model --help <IDS>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__tune)
+            opts="-h --help <IDS> <GAINS>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__verify)
+            opts="-m -h --model --file --fix --help <ID>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --model)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -m)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --file)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__watch)
+            opts="-i -c -h --interval --count --help <IDS> <REG>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --interval)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -i)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --count)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -c)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__bytes)
+            opts="-h --help <IDS> <ADDRESS> <VALUES>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__bytes__multiple)
+            opts="-h --bulk --help <SPECS>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__int16)
+            opts="-s -h --sync --deferred --map --help <IDS> <ADDRESS> <VALUE>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__int32)
+            opts="-s -h --sync --deferred --map --help <IDS> <ADDRESS> <VALUE>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__int8)
+            opts="-s -h --sync --deferred --map --help <IDS> <ADDRESS> <VALUE>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__reg)
+            opts="-h --help <IDS> <REG> <VALUE>"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__regs)
+            opts="-h --help <ID> <ASSIGNMENTS>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__uint16)
+            opts="-s -h --sync --deferred --map --help <IDS> <ADDRESS> <VALUE>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__uint32)
+            opts="-s -h --sync --deferred --map --help <IDS> <ADDRESS> <VALUE>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        dynamixel__tool__write__uint8)
+            opts="-s -h --sync --deferred --map --help <IDS> <ADDRESS> <VALUE>..."
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+    esac
+}
+
+complete -F _dynamixel-tool -o bashdefault -o default dynamixel-tool
+
+_dynamixel-tool_regs() {
+    local cur="${COMP_WORDS[COMP_CWORD]}" word model
+    for word in "${COMP_WORDS[@]:1:COMP_CWORD-1}"; do
+        case "${word}" in
+            read-reg|write-reg|write-regs|watch|monitor)
+                case "${cur}" in
+                    */*)
+                        model="${cur%%/*}"
+                        COMPREPLY=($(compgen -P "${model}/" -W "$({ dynamixel-tool list-registers "$model" || dynamixel-tool -P2 list-registers "$model"; } 2>/dev/null | awk '{print $4}')" -- "${cur#*/}"))
+                        return 0
+                        ;;
+                    [A-Z]*)
+                        COMPREPLY=($(compgen -S / -W "$({ dynamixel-tool list-models; dynamixel-tool -P2 list-models; } 2>/dev/null | cut -f1)" -- "${cur}"))
+                        compopt -o nospace
+                        return 0
+                        ;;
+                esac
+                ;;
+        esac
+    done
+    _dynamixel-tool "$@"
+}
+
+complete -F _dynamixel-tool_regs -o bashdefault -o default dynamixel-tool
@@ -1,3 +1,4 @@
+pub mod model;
 pub mod port;
 pub mod protocol;
 pub mod regs;
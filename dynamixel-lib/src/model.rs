@@ -0,0 +1,317 @@
+use crate::protocol::ProtocolVersion::{self, *};
+use crate::regs::{self, Area, IndirectBlock, Reg};
+
+/// Per-model constants not covered by the register database
+#[derive(Debug)]
+struct ModelInfo {
+    name: &'static str,
+    /// Protocol the constants apply to, `None` for both
+    proto: Option<ProtocolVersion>,
+    numbers: &'static [u16],
+    /// Position ticks over the full position range in degrees
+    resolution: Option<(u32, f32)>,
+    /// Velocity unit in rpm
+    rpm_per_tick: Option<f32>,
+}
+
+impl ModelInfo {
+    fn applies_to(&self, proto: ProtocolVersion) -> bool {
+        self.proto.is_none() || self.proto == Some(proto)
+    }
+}
+
+const fn info(
+    name: &'static str,
+    proto: Option<ProtocolVersion>,
+    numbers: &'static [u16],
+    resolution: Option<(u32, f32)>,
+    rpm_per_tick: Option<f32>,
+) -> ModelInfo {
+    ModelInfo {
+        name,
+        proto,
+        numbers,
+        resolution,
+        rpm_per_tick,
+    }
+}
+
+const AX: Option<(u32, f32)> = Some((1024, 300.0));
+const MX: Option<(u32, f32)> = Some((4096, 360.0));
+
+const fn pro(ticks: u32) -> Option<(u32, f32)> {
+    Some((ticks, 360.0))
+}
+
+/// Position control gains. AX style models use compliance margin and slope
+/// instead of PID gains, protocol 2 MX and X series have separate position
+/// and velocity gains.
+static GAINS: &[&[&str]] = &[
+    &[
+        "CWComplianceMargin",
+        "CCWComplianceMargin",
+        "CWComplianceSlope",
+        "CCWComplianceSlope",
+    ],
+    &["PGain", "IGain", "DGain"],
+    &["PositionPGain", "PositionIGain", "PositionDGain"],
+];
+
+/// BaudRate register values and the rates they select
+static V1_BAUDRATES: &[(u8, u32)] = &[
+    (1, 1000000),
+    (3, 500000),
+    (4, 400000),
+    (7, 250000),
+    (9, 200000),
+    (16, 115200),
+    (34, 57600),
+    (103, 19200),
+    (207, 9600),
+];
+static MX_V1_BAUDRATES: &[(u8, u32)] = &[
+    (1, 1000000),
+    (3, 500000),
+    (4, 400000),
+    (7, 250000),
+    (9, 200000),
+    (16, 115200),
+    (34, 57600),
+    (103, 19200),
+    (207, 9600),
+    (250, 2250000),
+    (251, 2500000),
+    (252, 3000000),
+];
+static V2_BAUDRATES: &[(u8, u32)] = &[
+    (0, 9600),
+    (1, 57600),
+    (2, 115200),
+    (3, 1000000),
+    (4, 2000000),
+    (5, 3000000),
+    (6, 4000000),
+    (7, 4500000),
+];
+static PRO_BAUDRATES: &[(u8, u32)] = &[
+    (0, 9600),
+    (1, 57600),
+    (2, 115200),
+    (3, 1000000),
+    (4, 2000000),
+    (5, 3000000),
+    (6, 4000000),
+    (7, 4500000),
+    (8, 10500000),
+];
+
+static MODELS: &[ModelInfo] = &[
+    info("AX-12A", None, &[12], AX, Some(0.111)),
+    info("AX-12W", None, &[300], AX, Some(0.111)),
+    info("AX-18A", None, &[18], AX, Some(0.111)),
+    info("AX-S1", None, &[13], None, None),
+    info("DX-113", None, &[113], AX, Some(0.111)),
+    info("DX-116", None, &[116], AX, Some(0.111)),
+    info("DX-117", None, &[117], AX, Some(0.111)),
+    info("EX-106+", None, &[107], Some((4096, 250.92)), Some(0.111)),
+    info("RX-10", None, &[10], AX, Some(0.111)),
+    info("RX-24F", None, &[24], AX, Some(0.111)),
+    info("RX-28", None, &[28], AX, Some(0.111)),
+    info("RX-64", None, &[64], AX, Some(0.111)),
+    info("MX-12W", None, &[360], MX, Some(0.916)),
+    info("MX-28", Some(V1), &[29, 30], MX, Some(0.114)),
+    info("MX-28", Some(V2), &[30], MX, Some(0.229)),
+    info("MX-64", Some(V1), &[310, 311], MX, Some(0.114)),
+    info("MX-64", Some(V2), &[311], MX, Some(0.229)),
+    info("MX-106", Some(V1), &[320, 321], MX, Some(0.114)),
+    info("MX-106", Some(V2), &[321], MX, Some(0.229)),
+    info("XL320", None, &[350], AX, Some(0.111)),
+    info("XL330-M077", None, &[1190], MX, Some(0.229)),
+    info("XL330-M288", None, &[1200], MX, Some(0.229)),
+    info("XC330-T181", None, &[1210], MX, Some(0.229)),
+    info("XC330-T288", None, &[1220], MX, Some(0.229)),
+    info("XC330-M181", None, &[1230], MX, Some(0.229)),
+    info("XC330-M288", None, &[1240], MX, Some(0.229)),
+    info("XL430-W250", None, &[1060], MX, Some(0.229)),
+    info("2XL430-W250", None, &[1090], MX, Some(0.229)),
+    info("XC430-W150", None, &[1070], MX, Some(0.229)),
+    info("XC430-W240", None, &[1080], MX, Some(0.229)),
+    info("2XC430-W250", None, &[1160], MX, Some(0.229)),
+    info("XM430-W350", None, &[1020], MX, Some(0.229)),
+    info("XM430-W210", None, &[1030], MX, Some(0.229)),
+    info("XH430-W350", None, &[1000], MX, Some(0.229)),
+    info("XH430-W210", None, &[1010], MX, Some(0.229)),
+    info("XH430-V350", None, &[1040], MX, Some(0.229)),
+    info("XH430-V210", None, &[1050], MX, Some(0.229)),
+    info("XD430-T350", None, &[1001], MX, Some(0.229)),
+    info("XD430-T210", None, &[1011], MX, Some(0.229)),
+    info("XW430-T333", None, &[1270], MX, Some(0.229)),
+    info("XW430-T200", None, &[1280], MX, Some(0.229)),
+    info("XM540-W270", None, &[1120], MX, Some(0.229)),
+    info("XM540-W150", None, &[1130], MX, Some(0.229)),
+    info("XH540-W270", None, &[1100], MX, Some(0.229)),
+    info("XH540-W150", None, &[1110], MX, Some(0.229)),
+    info("XH540-V270", None, &[1140], MX, Some(0.229)),
+    info("XH540-V150", None, &[1150], MX, Some(0.229)),
+    info("XD540-T270", None, &[1101], MX, Some(0.229)),
+    info("XD540-T150", None, &[1111], MX, Some(0.229)),
+    info("XW540-T260", None, &[1170], MX, Some(0.229)),
+    info("XW540-T140", None, &[1180], MX, Some(0.229)),
+    info("L42-10-S300-R", None, &[35072], MX, None),
+    info("L54-30-S400-R", None, &[37896], pro(288360), None),
+    info("L54-30-S500-R", None, &[37928], pro(361384), None),
+    info("L54-50-S290-R", None, &[38152], pro(207692), None),
+    info("L54-50-S500-R", None, &[38176], pro(361384), None),
+    info("M42-10-S260-R", None, &[43288], pro(263187), None),
+    info("M42-10-S260-RA", None, &[43289], pro(263187), None),
+    info("M54-40-S250-R", None, &[46096], pro(502834), None),
+    info("M54-40-S250-RA", None, &[46097], pro(502834), None),
+    info("M54-60-S250-R", None, &[46352], pro(502834), None),
+    info("M54-60-S250-RA", None, &[46353], pro(502834), None),
+    info("H42-20-S300-R", None, &[51200], pro(303750), None),
+    info("H42-20-S300-RA", None, &[51201], pro(303750), None),
+    info("H54-100-S500-R", None, &[53768], pro(501923), None),
+    info("H54-100-S500-RA", None, &[53769], pro(501923), None),
+    info("H54-200-S500-R", None, &[54024], pro(501923), None),
+    info("H54-200-S500-RA", None, &[54025], pro(501923), None),
+    info("PH42-020-S300-R", None, &[2000], pro(607500), Some(0.01)),
+    info("PH54-100-S500-R", None, &[2010], pro(1003846), Some(0.01)),
+    info("PH54-200-S500-R", None, &[2020], pro(1003846), Some(0.01)),
+    info("PM42-010-S260-R", None, &[2100], pro(526374), Some(0.01)),
+    info("PM54-040-S250-R", None, &[2110], pro(1003846), Some(0.01)),
+    info("PM54-060-S250-R", None, &[2120], pro(1003846), Some(0.01)),
+];
+
+/// A device model together with its register map and model constants,
+/// for one protocol version
+#[derive(Debug, Clone)]
+pub struct ServoModel {
+    name: &'static str,
+    proto: ProtocolVersion,
+    registers: Vec<Reg>,
+    info: Option<&'static ModelInfo>,
+}
+
+impl ServoModel {
+    /// Look up a model by name, `None` if it has no registers for `proto`
+    pub fn lookup(proto: ProtocolVersion, name: &str) -> Option<Self> {
+        let registers = regs::list_registers(proto, name);
+        let name = registers.first()?.model;
+
+        Some(ServoModel {
+            name,
+            proto,
+            info: MODELS
+                .iter()
+                .find(|info| info.name == name && info.applies_to(proto)),
+            registers,
+        })
+    }
+
+    /// Look up a model by the value of its ModelNumber register
+    pub fn from_model_number(proto: ProtocolVersion, number: u16) -> Option<Self> {
+        MODELS
+            .iter()
+            .filter(|info| info.applies_to(proto))
+            .filter(|info| info.numbers.contains(&number))
+            .find_map(|info| Self::lookup(proto, info.name))
+    }
+
+    /// Name of the model with this ModelNumber, also for models without a
+    /// register map
+    pub fn name_from_model_number(proto: ProtocolVersion, number: u16) -> Option<&'static str> {
+        MODELS
+            .iter()
+            .filter(|info| info.applies_to(proto))
+            .find(|info| info.numbers.contains(&number))
+            .map(|info| info.name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn proto(&self) -> ProtocolVersion {
+        self.proto
+    }
+
+    pub fn model_numbers(&self) -> &'static [u16] {
+        self.info.map_or(&[], |info| info.numbers)
+    }
+
+    pub fn registers(&self) -> &[Reg] {
+        &self.registers
+    }
+
+    pub fn register(&self, name: &str) -> Option<Reg> {
+        self.registers.iter().find(|reg| reg.name == name).cloned()
+    }
+
+    /// Register containing `address`, possibly not at its start
+    pub fn register_at(&self, address: u16) -> Option<Reg> {
+        regs::find_register_by_address(self.proto, self.name, address)
+    }
+
+    /// First address of the RAM area, everything below is EEPROM
+    pub fn ram_start(&self) -> Option<u16> {
+        regs::ram_start_address(self.proto, self.name)
+    }
+
+    pub fn area(&self, reg: &Reg) -> Option<Area> {
+        self.ram_start().map(|ram_start| {
+            if reg.address < ram_start {
+                Area::Eeprom
+            } else {
+                Area::Ram
+            }
+        })
+    }
+
+    pub fn indirect_blocks(&self) -> &'static [IndirectBlock] {
+        regs::indirect_blocks(self.proto, self.name)
+    }
+
+    /// Position control gain registers, either compliance or PID
+    pub fn gain_registers(&self) -> Vec<Reg> {
+        GAINS
+            .iter()
+            .map(|names| {
+                names
+                    .iter()
+                    .filter_map(|name| self.register(name))
+                    .collect()
+            })
+            .find(|regs: &Vec<Reg>| !regs.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// BaudRate register values and the rates they select
+    pub fn baudrates(&self) -> &'static [(u8, u32)] {
+        let is_pro = self.name.starts_with(['H', 'M', 'L']) && self.name.contains("-S");
+
+        match self.proto {
+            V1 if self.name.starts_with("MX") => MX_V1_BAUDRATES,
+            V1 => V1_BAUDRATES,
+            V2 if self.name == "XL320" => &V2_BAUDRATES[..4],
+            V2 if is_pro => PRO_BAUDRATES,
+            V2 => V2_BAUDRATES,
+        }
+    }
+
+    /// Position ticks over the full position range
+    pub fn resolution(&self) -> Option<u32> {
+        self.info
+            .and_then(|info| info.resolution)
+            .map(|(ticks, _)| ticks)
+    }
+
+    pub fn degrees_per_tick(&self) -> Option<f32> {
+        self.info
+            .and_then(|info| info.resolution)
+            .map(|(ticks, degrees)| degrees / ticks as f32)
+    }
+
+    pub fn rpm_per_tick(&self) -> Option<f32> {
+        self.info.and_then(|info| info.rpm_per_tick)
+    }
+}
@@ -1,4 +1,4 @@
-use super::Rs485;
+use super::{Rs485, Rs485Config};
 
 use anyhow::Result;
 use glob::glob;
@@ -10,14 +10,8 @@ use std::os::unix::io::AsRawFd;
 pub fn is_port_open(port_name: &str) -> bool {
     glob("/proc/[0-9]*/fd/*")
         .unwrap()
-        .filter_map(|p| match p {
-            Ok(path) => Some(path),
-            Err(_) => None,
-        })
-        .filter_map(|path| match fs::read_link(path) {
-            Ok(link) => Some(link),
-            Err(_) => None,
-        })
+        .filter_map(|p| p.ok())
+        .filter_map(|path| fs::read_link(path).ok())
         .any(|link| link.to_str() == Some(port_name))
 }
 
@@ -30,11 +24,8 @@ impl Rs485 for NativePort {
         }
     }
 
-    fn rs485_enable(&self, enable: bool) -> Result<()> {
-        let mut rs485 = ioctl::serial_rs485::default();
-        if enable {
-            rs485.flags |= ioctl::SER_RS485_ENABLED | ioctl::SER_RS485_RTS_ON_SEND;
-        }
+    fn rs485_enable(&self, config: Option<Rs485Config>) -> Result<()> {
+        let rs485 = ioctl::serial_rs485::new(config);
         match unsafe { ioctl::serial_rs485_set(self.as_raw_fd(), &rs485) } {
             Ok(_) => Ok(()),
             Err(err) => Err(err.into()),
@@ -51,11 +42,8 @@ impl Rs485 for super::SerialStream {
         }
     }
 
-    fn rs485_enable(&self, enable: bool) -> Result<()> {
-        let mut rs485 = ioctl::serial_rs485::default();
-        if enable {
-            rs485.flags |= ioctl::SER_RS485_ENABLED | ioctl::SER_RS485_RTS_ON_SEND;
-        }
+    fn rs485_enable(&self, config: Option<Rs485Config>) -> Result<()> {
+        let rs485 = ioctl::serial_rs485::new(config);
         match unsafe { ioctl::serial_rs485_set(self.as_raw_fd(), &rs485) } {
             Ok(_) => Ok(()),
             Err(err) => Err(err.into()),
@@ -85,6 +73,25 @@ mod ioctl {
         padding: [u32; 5],
     }
 
+    impl serial_rs485 {
+        /// Settings enabling RS485 mode with `config`, disabling it for `None`
+        pub fn new(config: Option<Rs485Config>) -> Self {
+            let mut rs485 = Self::default();
+            if let Some(config) = config {
+                rs485.flags |= SER_RS485_ENABLED;
+                if config.rts_on_send {
+                    rs485.flags |= SER_RS485_RTS_ON_SEND;
+                }
+                if config.rts_after_send {
+                    rs485.flags |= SER_RS485_RTS_AFTER_SEND;
+                }
+                rs485.delay_rts_before_send = config.delay_before_send;
+                rs485.delay_rts_after_send = config.delay_after_send;
+            }
+            rs485
+        }
+    }
+
     const TIOCGRS485: u32 = 0x542E;
     const TIOCSRS485: u32 = 0x542F;
 
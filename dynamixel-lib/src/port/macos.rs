@@ -1,11 +1,24 @@
-use super::{OpenPortError, Rs485};
+use super::{OpenPortError, Rs485, Rs485Config};
 use anyhow::Result;
+use nix::errno::Errno;
+use nix::fcntl::{self, OFlag};
+use nix::sys::stat::Mode;
+use nix::unistd;
 use serialport::SerialPort;
 use serialport::TTYPort as NativePort;
 
-// TODO: implement proper check
-pub fn is_port_open(_port_name: &str) -> bool {
-    false
+/// There is no /proc to look for other openers, but serial ports are opened
+/// with TIOCEXCL, so a second open fails with EBUSY while another process
+/// holds the port. O_NONBLOCK keeps the probe from waiting for carrier.
+pub fn is_port_open(port_name: &str) -> bool {
+    let flags = OFlag::O_RDWR | OFlag::O_NOCTTY | OFlag::O_NONBLOCK;
+    match fcntl::open(port_name, flags, Mode::empty()) {
+        Ok(fd) => {
+            let _ = unistd::close(fd);
+            false
+        }
+        Err(e) => e == Errno::EBUSY,
+    }
 }
 
 impl Rs485 for NativePort {
@@ -16,7 +29,7 @@ impl Rs485 for NativePort {
         .into())
     }
 
-    fn rs485_enable(&self, _enable: bool) -> Result<()> {
+    fn rs485_enable(&self, _config: Option<Rs485Config>) -> Result<()> {
         Err(OpenPortError::Rs485Error {
             port_name: self.name().unwrap_or_default(),
         }
@@ -32,7 +45,7 @@ impl Rs485 for super::SerialStream {
         .into())
     }
 
-    fn rs485_enable(&self, _enable: bool) -> Result<()> {
+    fn rs485_enable(&self, _config: Option<Rs485Config>) -> Result<()> {
         Err(OpenPortError::Rs485Error {
             port_name: self.name().unwrap_or_default(),
         }
@@ -18,8 +18,9 @@ pub use tokio_serial::SerialStream;
 
 use anyhow::Result;
 use core::time::Duration;
-use log::debug;
+use log::{debug, info};
 use serialport::{self, ClearBuffer, SerialPortType};
+use std::{fmt::Display, str::FromStr, sync::Mutex};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -30,35 +31,104 @@ pub enum OpenPortError {
     PortBusy { port_name: String },
     #[error("rs485 configuration failed on {port_name:?}")]
     Rs485Error { port_name: String },
+    #[error("failed to open {port_name:?}")]
+    OpenFailed {
+        port_name: String,
+        source: serialport::Error,
+    },
+}
+
+/// RS485 transceiver control, used when the port driver supports it
+#[derive(Debug, Clone, Copy)]
+pub struct Rs485Config {
+    /// Drive RTS high while sending, low otherwise
+    pub rts_on_send: bool,
+    /// Drive RTS high after sending
+    pub rts_after_send: bool,
+    /// Delay between setting RTS and sending, in milliseconds
+    pub delay_before_send: u32,
+    /// Delay between sending and resetting RTS, in milliseconds
+    pub delay_after_send: u32,
+}
+
+impl Default for Rs485Config {
+    fn default() -> Self {
+        Self {
+            rts_on_send: true,
+            rts_after_send: false,
+            delay_before_send: 0,
+            delay_after_send: 0,
+        }
+    }
 }
 
 trait Rs485 {
     fn rs485_is_enabled(&self) -> Result<bool>;
-    fn rs485_enable(&self, enable: bool) -> Result<()>;
+    /// Enable RS485 mode with `config`, disable it for `None`
+    fn rs485_enable(&self, config: Option<Rs485Config>) -> Result<()>;
 
     fn rs485_is_supported(&self) -> bool {
         match self.rs485_is_enabled() {
-            Ok(enabled) => self.rs485_enable(enabled).is_ok(),
+            Ok(enabled) => self
+                .rs485_enable(enabled.then(Rs485Config::default))
+                .is_ok(),
             Err(_) => false,
         }
     }
 }
 
-#[derive(PartialEq)]
-struct UsbId(u16, u16);
+/// USB vendor and product id of a serial adapter
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct UsbId(pub u16, pub u16);
+
+#[derive(Error, Debug)]
+pub enum UsbIdError {
+    #[error("expected hex VID:PID like 10c4:ea60, got '{0}'")]
+    BadUsbId(String),
+}
+
+impl FromStr for UsbId {
+    type Err = UsbIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |s| u16::from_str_radix(s, 16).ok();
+        s.split_once(':')
+            .and_then(|(vid, pid)| Some(UsbId(parse(vid)?, parse(pid)?)))
+            .ok_or_else(|| UsbIdError::BadUsbId(s.to_string()))
+    }
+}
+
+impl Display for UsbId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.0, self.1)
+    }
+}
 
 static COMPATIBLE_IDS: &[UsbId] = &[
     UsbId(0x16d0, 0x06a7), // MCS USB2AX
     UsbId(0x0403, 0x6014), // FTDI FT232H Single HS USB-UART/FIFO IC
+    UsbId(0x0403, 0x6001), // FTDI FT232R USB UART
+    UsbId(0x0403, 0x6015), // FTDI FT231X USB UART
     UsbId(0x1a86, 0x7523), // QinHeng Electronics HL-340 USB-Serial adapter
+    UsbId(0x1a86, 0x55d4), // QinHeng Electronics CH9102 USB-Serial adapter
+    UsbId(0x10c4, 0xea60), // Silicon Labs CP210x UART Bridge
     UsbId(0x0483, 0x5740), // STMicroelectronics Virtual COM Port
 ];
 
+/// Adapters added with `add_compatible_ids`
+static EXTRA_IDS: Mutex<Vec<UsbId>> = Mutex::new(Vec::new());
+
+/// Let "auto" port detection also pick adapters with these ids
+pub fn add_compatible_ids(ids: &[UsbId]) {
+    EXTRA_IDS.lock().unwrap().extend_from_slice(ids);
+}
+
 fn open_port_impl(
     port_name: &str,
     baudrate: u32,
     force: bool,
-    rs485: bool
+    rs485: Option<Rs485Config>,
+    timeout: Duration,
 ) -> Result<Box<dyn SerialPort + Send>> {
     let true_name: String = if port_name == "auto" {
         guess_port()?
@@ -73,7 +143,12 @@ fn open_port_impl(
         .into());
     }
 
-    let mut port = serialport::new(&true_name, baudrate).open_native()?;
+    let mut port = serialport::new(&true_name, baudrate)
+        .open_native()
+        .map_err(|source| OpenPortError::OpenFailed {
+            port_name: true_name.clone(),
+            source,
+        })?;
 
     if port.rs485_is_supported() && port.rs485_enable(rs485).is_err() && !force {
         return Err(OpenPortError::Rs485Error {
@@ -82,31 +157,41 @@ fn open_port_impl(
         .into());
     }
 
-    port.set_timeout(Duration::from_millis(10))?;
+    port.set_timeout(timeout)?;
 
     port.clear(ClearBuffer::All)?;
     debug!("open_port OK: {} @ {} baud", &true_name, baudrate);
     Ok(Box::new(port))
 }
 
+/// Default timeout for a read to return any data
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(10);
 
 pub fn open_port(
     port_name: &str,
     baudrate: u32,
     force: bool,
+    timeout: Duration,
+    rs485: Rs485Config,
 ) -> Result<Box<dyn SerialPort + Send>> {
-    open_port_impl(port_name, baudrate, force, true)
+    open_port_impl(port_name, baudrate, force, Some(rs485), timeout)
 }
 
 pub fn open_port_plain(
     port_name: &str,
     baudrate: u32,
     force: bool,
+    timeout: Duration,
 ) -> Result<Box<dyn SerialPort + Send>> {
-    open_port_impl(port_name, baudrate, force, false)
+    open_port_impl(port_name, baudrate, force, None, timeout)
 }
 
-fn open_port_async_impl(port_name: &str, baudrate: u32, force: bool, rs485: bool) -> Result<SerialStream> {
+fn open_port_async_impl(
+    port_name: &str,
+    baudrate: u32,
+    force: bool,
+    rs485: Option<Rs485Config>,
+) -> Result<SerialStream> {
     let true_name: String = if port_name == "auto" {
         guess_port()?
     } else {
@@ -120,7 +205,12 @@ fn open_port_async_impl(port_name: &str, baudrate: u32, force: bool, rs485: bool
         .into());
     }
 
-    let port = tokio_serial::new(&true_name, baudrate).open_native_async()?;
+    let port = tokio_serial::new(&true_name, baudrate)
+        .open_native_async()
+        .map_err(|source| OpenPortError::OpenFailed {
+            port_name: true_name.clone(),
+            source,
+        })?;
 
     if port.rs485_is_supported() && port.rs485_enable(rs485).is_err() && !force {
         return Err(OpenPortError::Rs485Error {
@@ -135,30 +225,74 @@ fn open_port_async_impl(port_name: &str, baudrate: u32, force: bool, rs485: bool
 }
 
 pub fn open_port_async(port_name: &str, baudrate: u32, force: bool) -> Result<SerialStream> {
-    open_port_async_impl(port_name, baudrate, force, true)
+    open_port_async_impl(port_name, baudrate, force, Some(Rs485Config::default()))
 }
 
 pub fn open_port_async_plain(port_name: &str, baudrate: u32, force: bool) -> Result<SerialStream> {
-    open_port_async_impl(port_name, baudrate, force, false)
+    open_port_async_impl(port_name, baudrate, force, None)
 }
 
-fn guess_port() -> Result<String> {
-    serialport::available_ports()?
+/// A serial port as seen by "auto" port detection
+#[derive(Debug)]
+pub struct PortInfo {
+    pub name: String,
+    /// usb, pci, bluetooth or unknown
+    pub kind: &'static str,
+    pub usb_id: Option<UsbId>,
+    /// Whether the port looks Dynamixel compatible, `None` if only opening
+    /// it can tell
+    pub compatible: Option<bool>,
+}
+
+/// List serial ports without opening them
+pub fn list_ports() -> Result<Vec<PortInfo>> {
+    let mut ports: Vec<_> = serialport::available_ports()?
         .into_iter()
-        .filter(|info| match &info.port_type {
-            SerialPortType::UsbPort(usb_info) => {
-                COMPATIBLE_IDS.contains(&UsbId(usb_info.vid, usb_info.pid))
+        .map(|info| {
+            let (kind, usb_id, compatible) = match &info.port_type {
+                SerialPortType::UsbPort(usb_info) => {
+                    let id = UsbId(usb_info.vid, usb_info.pid);
+                    let compatible =
+                        COMPATIBLE_IDS.contains(&id) || EXTRA_IDS.lock().unwrap().contains(&id);
+                    ("usb", Some(id), Some(compatible))
+                }
+                SerialPortType::Unknown => ("unknown", None, None),
+                SerialPortType::PciPort => ("pci", None, Some(false)),
+                SerialPortType::BluetoothPort => ("bluetooth", None, Some(false)),
+            };
+            PortInfo {
+                name: info.port_name,
+                kind,
+                usb_id,
+                compatible,
             }
-            SerialPortType::Unknown => {
-                !is_port_open(&info.port_name)
-                    && match serialport::new(&info.port_name, 9600).open_native() {
+        })
+        .collect();
+    // available_ports() order varies between boots
+    ports.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(ports)
+}
+
+fn guess_port() -> Result<String> {
+    let candidates: Vec<_> = list_ports()?
+        .into_iter()
+        .filter(|info| {
+            info.compatible.unwrap_or_else(|| {
+                // Ports of unknown type are taken if they do rs485
+                !is_port_open(&info.name)
+                    && match serialport::new(&info.name, 9600).open_native() {
                         Ok(p) => p.rs485_is_supported(),
                         Err(_) => false,
                     }
-            }
-            SerialPortType::PciPort | SerialPortType::BluetoothPort => false,
+            })
         })
-        .map(|info| info.port_name)
-        .next()
-        .ok_or_else(|| OpenPortError::NoCompatiblePort.into())
+        .map(|info| info.name)
+        .collect();
+
+    let port = candidates
+        .first()
+        .cloned()
+        .ok_or(OpenPortError::NoCompatiblePort)?;
+    info!("auto picked {} of {}", port, candidates.join(", "));
+    Ok(port)
 }
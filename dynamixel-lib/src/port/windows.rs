@@ -1,10 +1,40 @@
-use super::{OpenPortError, Rs485};
+use super::{OpenPortError, Rs485, Rs485Config};
 use anyhow::Result;
 use serialport::COMPort as NativePort;
 use serialport::SerialPort;
+use std::{iter, ptr};
+use windows_sys::Win32::Foundation::{
+    CloseHandle, GetLastError, ERROR_ACCESS_DENIED, ERROR_SHARING_VIOLATION, GENERIC_READ,
+    GENERIC_WRITE, INVALID_HANDLE_VALUE,
+};
+use windows_sys::Win32::Storage::FileSystem::{CreateFileW, OPEN_EXISTING};
 
-// TODO: implement proper check
-pub fn is_port_open(_port_name: &str) -> bool {
+/// COM ports allow a single handle, so opening one held by another process
+/// is denied. The probe handle is closed right away.
+pub fn is_port_open(port_name: &str) -> bool {
+    let path = if port_name.starts_with(r"\\.\") {
+        port_name.to_string()
+    } else {
+        format!(r"\\.\{}", port_name)
+    };
+    let path: Vec<u16> = path.encode_utf16().chain(iter::once(0)).collect();
+
+    let handle = unsafe {
+        CreateFileW(
+            path.as_ptr(),
+            GENERIC_READ | GENERIC_WRITE,
+            0,
+            ptr::null(),
+            OPEN_EXISTING,
+            0,
+            0,
+        )
+    };
+    if handle == INVALID_HANDLE_VALUE {
+        let error = unsafe { GetLastError() };
+        return error == ERROR_ACCESS_DENIED || error == ERROR_SHARING_VIOLATION;
+    }
+    unsafe { CloseHandle(handle) };
     false
 }
 
@@ -16,7 +46,7 @@ impl Rs485 for NativePort {
         .into())
     }
 
-    fn rs485_enable(&self, _enable: bool) -> Result<()> {
+    fn rs485_enable(&self, _config: Option<Rs485Config>) -> Result<()> {
         Err(OpenPortError::Rs485Error {
             port_name: self.name().unwrap_or_default(),
         }
@@ -32,7 +62,7 @@ impl Rs485 for super::SerialStream {
         .into())
     }
 
-    fn rs485_enable(&self, _enable: bool) -> Result<()> {
+    fn rs485_enable(&self, _config: Option<Rs485Config>) -> Result<()> {
         Err(OpenPortError::Rs485Error {
             port_name: self.name().unwrap_or_default(),
         }
@@ -0,0 +1,363 @@
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io::{self, Read, Write},
+    time::Duration,
+};
+
+use serialport::{ClearBuffer, DataBits, FlowControl, Parity, SerialPort, StopBits};
+
+use super::{v1, v2};
+use crate::protocol::ProtocolVersion;
+
+const BROADCAST_ID: u8 = 0xFE;
+
+/// Instructions answered by a status without parameters
+const ACKNOWLEDGED: &[u8] = &[3, 4, 5, 6, 8, 0x10, 0x20];
+
+/// Serial port that sends nothing. It records the instruction packets
+/// written to it and acknowledges writes with a status without errors, so
+/// that write sequences run to the end. Anything expecting data times out.
+pub struct DryRunPort {
+    version: ProtocolVersion,
+    packets: Vec<Vec<u8>>,
+    reply: RefCell<VecDeque<u8>>,
+    baud_rate: u32,
+    timeout: Duration,
+}
+
+impl DryRunPort {
+    pub fn new(version: ProtocolVersion, baud_rate: u32) -> Self {
+        Self {
+            version,
+            packets: Vec::new(),
+            reply: RefCell::new(VecDeque::new()),
+            baud_rate,
+            timeout: Duration::ZERO,
+        }
+    }
+
+    /// Instruction packets written so far, one per write
+    pub fn packets(&self) -> &[Vec<u8>] {
+        &self.packets
+    }
+}
+
+fn split_instruction(version: ProtocolVersion, packet: &[u8]) -> Option<(u8, u8, Vec<u8>)> {
+    match version {
+        ProtocolVersion::V1 => v1::split_instruction_v1(packet),
+        ProtocolVersion::V2 => v2::split_instruction_v2(packet),
+    }
+}
+
+impl Write for DryRunPort {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.packets.push(buf.to_vec());
+
+        if let Some((id, instruction, _)) = split_instruction(self.version, buf) {
+            if id != BROADCAST_ID && ACKNOWLEDGED.contains(&instruction) {
+                let status = match self.version {
+                    ProtocolVersion::V1 => v1::ok_status_v1(id),
+                    ProtocolVersion::V2 => v2::ok_status_v2(id),
+                };
+                self.reply.get_mut().extend(status);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Read for DryRunPort {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let reply = self.reply.get_mut();
+        if reply.is_empty() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+
+        let n = buf.len().min(reply.len());
+        for (dst, src) in buf.iter_mut().zip(reply.drain(..n)) {
+            *dst = src;
+        }
+        Ok(n)
+    }
+}
+
+impl SerialPort for DryRunPort {
+    fn name(&self) -> Option<String> {
+        Some("dry-run".to_string())
+    }
+
+    fn baud_rate(&self) -> serialport::Result<u32> {
+        Ok(self.baud_rate)
+    }
+
+    fn data_bits(&self) -> serialport::Result<DataBits> {
+        Ok(DataBits::Eight)
+    }
+
+    fn flow_control(&self) -> serialport::Result<FlowControl> {
+        Ok(FlowControl::None)
+    }
+
+    fn parity(&self) -> serialport::Result<Parity> {
+        Ok(Parity::None)
+    }
+
+    fn stop_bits(&self) -> serialport::Result<StopBits> {
+        Ok(StopBits::One)
+    }
+
+    fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn set_baud_rate(&mut self, baud_rate: u32) -> serialport::Result<()> {
+        self.baud_rate = baud_rate;
+        Ok(())
+    }
+
+    fn set_data_bits(&mut self, _data_bits: DataBits) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_flow_control(&mut self, _flow_control: FlowControl) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_parity(&mut self, _parity: Parity) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_stop_bits(&mut self, _stop_bits: StopBits) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn set_timeout(&mut self, timeout: Duration) -> serialport::Result<()> {
+        self.timeout = timeout;
+        Ok(())
+    }
+
+    fn write_request_to_send(&mut self, _level: bool) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn write_data_terminal_ready(&mut self, _level: bool) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn read_clear_to_send(&mut self) -> serialport::Result<bool> {
+        Ok(false)
+    }
+
+    fn read_data_set_ready(&mut self) -> serialport::Result<bool> {
+        Ok(false)
+    }
+
+    fn read_ring_indicator(&mut self) -> serialport::Result<bool> {
+        Ok(false)
+    }
+
+    fn read_carrier_detect(&mut self) -> serialport::Result<bool> {
+        Ok(false)
+    }
+
+    fn bytes_to_read(&self) -> serialport::Result<u32> {
+        Ok(self.reply.borrow().len() as u32)
+    }
+
+    fn bytes_to_write(&self) -> serialport::Result<u32> {
+        Ok(0)
+    }
+
+    fn clear(&self, buffer_to_clear: ClearBuffer) -> serialport::Result<()> {
+        if matches!(buffer_to_clear, ClearBuffer::Input | ClearBuffer::All) {
+            self.reply.borrow_mut().clear();
+        }
+        Ok(())
+    }
+
+    fn try_clone(&self) -> serialport::Result<Box<dyn SerialPort>> {
+        Err(serialport::Error::new(
+            serialport::ErrorKind::Unknown,
+            "dry run port can't be cloned",
+        ))
+    }
+
+    fn set_break(&self) -> serialport::Result<()> {
+        Ok(())
+    }
+
+    fn clear_break(&self) -> serialport::Result<()> {
+        Ok(())
+    }
+}
+
+/// Little endian reader over instruction parameters
+struct Params<'a>(&'a [u8]);
+
+impl<'a> Params<'a> {
+    fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        if self.0.len() < n {
+            return None;
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Some(head)
+    }
+
+    /// Address or size, one byte on protocol 1 and two on protocol 2
+    fn field(&mut self, version: ProtocolVersion) -> Option<usize> {
+        match version {
+            ProtocolVersion::V1 => self.bytes(1).map(|b| b[0].into()),
+            ProtocolVersion::V2 => self
+                .bytes(2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]).into()),
+        }
+    }
+
+    fn rest(&mut self) -> &'a [u8] {
+        std::mem::take(&mut self.0)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn target(id: u8) -> String {
+    match id {
+        BROADCAST_ID => "broadcast".to_string(),
+        id => format!("id {}", id),
+    }
+}
+
+/// Describe the parameters of `instruction`, None if they don't parse
+fn describe_params(
+    version: ProtocolVersion,
+    instruction: u8,
+    params: &mut Params,
+) -> Option<String> {
+    Some(match instruction {
+        // Ping, action, reboot
+        1 | 5 | 8 => String::new(),
+        // Read
+        2 => format!(
+            "address {} count {}",
+            params.field(version)?,
+            params.field(version)?
+        ),
+        // Write, reg write
+        3 | 4 => format!(
+            "address {} data {}",
+            params.field(version)?,
+            hex(params.rest())
+        ),
+        // Factory reset, with a scope on protocol 2
+        6 => match params.bytes(1) {
+            Some(scope) => format!("scope {:02X}", scope[0]),
+            None => String::new(),
+        },
+        // Sync read, fast sync read
+        0x82 | 0x8A => format!(
+            "address {} count {} ids {:?}",
+            params.field(version)?,
+            params.field(version)?,
+            params.rest()
+        ),
+        // Sync write
+        0x83 => {
+            let address = params.field(version)?;
+            let size = params.field(version)?;
+            let mut writes = Vec::new();
+            while !params.is_empty() {
+                let id = params.bytes(1)?[0];
+                writes.push(format!("id {} data {}", id, hex(params.bytes(size)?)));
+            }
+            format!("address {} size {}: {}", address, size, writes.join(", "))
+        }
+        // Protocol 1 bulk read, a zero byte then count, id and address
+        0x92 if version == ProtocolVersion::V1 => {
+            params.bytes(1)?;
+            let mut reads = Vec::new();
+            while !params.is_empty() {
+                let count = params.bytes(1)?[0];
+                let id = params.bytes(1)?[0];
+                let address = params.bytes(1)?[0];
+                reads.push(format!("id {} address {} count {}", id, address, count));
+            }
+            reads.join(", ")
+        }
+        // Protocol 2 bulk read and write
+        0x92 | 0x93 => {
+            let mut requests = Vec::new();
+            while !params.is_empty() {
+                let id = params.bytes(1)?[0];
+                let address = params.field(version)?;
+                let size = params.field(version)?;
+                requests.push(if instruction == 0x92 {
+                    format!("id {} address {} count {}", id, address, size)
+                } else {
+                    format!(
+                        "id {} address {} data {}",
+                        id,
+                        address,
+                        hex(params.bytes(size)?)
+                    )
+                });
+            }
+            requests.join(", ")
+        }
+        _ => format!("params {}", hex(params.rest())),
+    })
+}
+
+fn instruction_name(instruction: u8) -> String {
+    let name = match instruction {
+        1 => "PING",
+        2 => "READ",
+        3 => "WRITE",
+        4 => "REG_WRITE",
+        5 => "ACTION",
+        6 => "FACTORY_RESET",
+        8 => "REBOOT",
+        0x10 => "CLEAR",
+        0x20 => "BACKUP",
+        0x82 => "SYNC_READ",
+        0x83 => "SYNC_WRITE",
+        0x8A => "FAST_SYNC_READ",
+        0x92 => "BULK_READ",
+        0x93 => "BULK_WRITE",
+        other => return format!("INSTRUCTION {:02X}", other),
+    };
+    name.to_string()
+}
+
+/// One line summary of an instruction packet: instruction, target id and
+/// decoded parameters, e.g. `WRITE id 1 address 116 data 00 08 00 00`
+pub fn describe_packet(version: ProtocolVersion, packet: &[u8]) -> String {
+    let (id, instruction, params) = match split_instruction(version, packet) {
+        Some(split) => split,
+        None => return "malformed packet".to_string(),
+    };
+
+    let details = describe_params(version, instruction, &mut Params(&params))
+        .unwrap_or_else(|| format!("malformed params {}", hex(&params)));
+    [instruction_name(instruction), target(id), details]
+        .iter()
+        .filter(|s| !s.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
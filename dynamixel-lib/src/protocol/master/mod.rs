@@ -1,27 +1,279 @@
+mod dry_run;
+#[cfg(test)]
+mod mock;
 mod v1;
 mod v2;
 
-use serialport::SerialPort;
+pub use dry_run::{describe_packet, DryRunPort};
 
-use super::{ProtocolVersion, Result};
+use std::{cell::Cell, io::ErrorKind, time::Duration};
+
+use log::debug;
+use serialport::{ClearBuffer, SerialPort};
+
+use super::{ProtocolError, ProtocolVersion, Result};
+
+/// Device information carried by a ping status packet, only protocol 2
+/// reports model number and firmware version
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PingResult {
+    pub model: Option<u16>,
+    pub firmware: Option<u8>,
+}
+
+/// Part of the control table a factory reset restores
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResetScope {
+    All = 0xFF,
+    KeepId = 0x01,
+    KeepIdBaudrate = 0x02,
+}
 
 pub trait Protocol: Send {
+    /// Number of retries made since the protocol was created
+    fn retry_count(&self) -> usize;
+
+    fn ping(&mut self, id: u8) -> Result<PingResult>;
+    /// Ping ids `scan_start` to `scan_end` inclusive, stopping short of the
+    /// broadcast id
     fn scan(&mut self, scan_start: u8, scan_end: u8) -> Result<Vec<u8>>;
+    /// Like `scan`, with the ping result of each found id
+    fn scan_ping(&mut self, scan_start: u8, scan_end: u8) -> Result<Vec<(u8, PingResult)>> {
+        // 0xFE is the broadcast id
+        Ok((scan_start..=scan_end.min(0xFD))
+            .filter_map(|id| self.ping(id).ok().map(|ping| (id, ping)))
+            .collect())
+    }
     fn read(&mut self, id: u8, address: u16, count: u16) -> Result<Vec<u8>>;
     fn write(&mut self, id: u8, address: u16, data: &[u8]) -> Result<()>;
+
+    /// Stage a write, applied on the next `action`
+    fn reg_write(&mut self, id: u8, address: u16, data: &[u8]) -> Result<()>;
+
+    /// Apply staged writes, usually broadcast to start several devices at
+    /// once. Broadcast action is not acknowledged.
+    fn action(&mut self, id: u8) -> Result<()>;
+
+    /// Write `data[i]` to each `ids[i]`. Atomic on protocol 2 only, protocol
+    /// 1 writes one id at a time.
     fn sync_write(&mut self, ids: &[u8], address: u16, data: &[&[u8]]) -> Result<()>;
-    fn sync_read(&mut self, ids: &[u8], address: u16, count: u16) -> Result<Vec<Vec<u8>>>;
+
+    /// Read the same range from each of `ids`. A single transaction on
+    /// protocol 2 only, protocol 1 reads one id at a time. Each id gets its
+    /// own result, so devices that don't answer don't fail the others.
+    fn sync_read(
+        &mut self,
+        ids: &[u8],
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<(u8, Result<Vec<u8>>)>>;
+
+    /// Like `sync_read`, but all devices answer in a single combined status
+    /// packet (protocol 2 only). Each entry holds the data or the status
+    /// error of a device, in order of `ids`.
+    fn fast_sync_read(
+        &mut self,
+        ids: &[u8],
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<std::result::Result<Vec<u8>, u8>>>;
+
+    /// Read a different (id, address, count) range from each device in a
+    /// single transaction. Each id may appear only once. Protocol 1 bulk
+    /// read is only understood by MX series devices.
+    fn bulk_read(&mut self, requests: &[(u8, u16, u16)]) -> Result<Vec<Vec<u8>>>;
+
+    /// Write different (id, address, data) to each device in a single
+    /// broadcast transaction (protocol 2 only). Each id may appear only once.
+    fn bulk_write(&mut self, writes: &[(u8, u16, &[u8])]) -> Result<()>;
+
+    /// Reboot a device (protocol 2 only). Broadcast reboot is not
+    /// acknowledged, so it always succeeds once sent.
+    fn reboot(&mut self, id: u8) -> Result<()>;
+
+    /// Reset the multi-turn present position to within one revolution
+    /// (protocol 2 only)
+    fn clear_multiturn(&mut self, id: u8) -> Result<()>;
+
+    /// Store the control table to flash, or restore it from there
+    /// (protocol 2 only)
+    fn control_table_backup(&mut self, id: u8, restore: bool) -> Result<()>;
+
+    /// Reset control table to factory defaults. Protocol 1 only supports
+    /// `ResetScope::All`.
+    fn factory_reset(&mut self, id: u8, scope: ResetScope) -> Result<()>;
+
+    /// Read `count` bytes, split into several reads at consecutive addresses
+    /// if it exceeds the protocol limit. Chunks are not read atomically.
+    fn read_chunked(&mut self, id: u8, address: u16, count: u16) -> Result<Vec<u8>> {
+        self.read(id, address, count)
+    }
+
+    /// Read like `read`, also from a device whose status reports a hardware
+    /// alert, e.g. its HardwareErrorStatus
+    fn read_alerted(&mut self, id: u8, address: u16, count: u16) -> Result<Vec<u8>> {
+        self.read(id, address, count)
+    }
 
     fn version(&self) -> ProtocolVersion;
 }
 
+/// Workarounds for the transceiver, applied to every instruction sent
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TxOptions {
+    /// Time to wait after sending, before reading the reply
+    pub delay: Duration,
+    /// Read back and drop the instruction, for half-duplex adapters that
+    /// echo what they send
+    pub echo_cancel: bool,
+}
+
+/// `timeout` is the port timeout set by `open_port`. Reads expecting many
+/// bytes extend it by the time it takes to transfer them.
 pub fn make_protocol<'a>(
     version: ProtocolVersion,
     port: &'a mut dyn SerialPort,
     retries: usize,
+    timeout: Duration,
+    tx: TxOptions,
 ) -> Box<dyn Protocol + 'a> {
     match version {
-        ProtocolVersion::V1 => Box::new(v1::ProtocolV1::new(port, retries)),
-        ProtocolVersion::V2 => Box::new(v2::ProtocolV2::new(port, retries)),
+        ProtocolVersion::V1 => Box::new(v1::ProtocolV1::new(port, retries, timeout, tx)),
+        ProtocolVersion::V2 => Box::new(v2::ProtocolV2::new(port, retries, timeout, tx)),
+    }
+}
+
+/// Retry limit of a protocol, counting the retries made
+struct Retries {
+    limit: usize,
+    count: Cell<usize>,
+}
+
+impl Retries {
+    fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            count: Cell::new(0),
+        }
+    }
+}
+
+/// Run `f` up to `retries.limit + 1` times. Input is flushed before each
+/// attempt so that stale bytes left by a failed attempt, or by an earlier
+/// transaction that timed out mid-reply, don't misalign the reply.
+fn with_retries<T>(
+    port: &mut dyn SerialPort,
+    retries: &Retries,
+    mut f: impl FnMut(&mut dyn SerialPort) -> Result<T>,
+) -> Result<T> {
+    let mut error = None;
+
+    for attempt in 0..=retries.limit {
+        if attempt > 0 {
+            retries.count.set(retries.count.get() + 1);
+        }
+        port.clear(ClearBuffer::Input)?;
+        match f(port) {
+            Ok(data) => return Ok(data),
+            Err(e) => error = Some(e),
+        }
+    }
+    Err(error.unwrap())
+}
+
+/// Run `f` with the port timeout extended by the transfer time of `count`
+/// reply bytes, at 10 bits per byte
+fn with_reply_timeout<T>(
+    port: &mut dyn SerialPort,
+    timeout: Duration,
+    count: usize,
+    f: impl FnOnce(&mut dyn SerialPort) -> Result<T>,
+) -> Result<T> {
+    let baudrate = match port.baud_rate() {
+        Ok(baudrate) if baudrate > 0 => baudrate,
+        _ => return f(port),
+    };
+    let transfer = Duration::from_micros(count as u64 * 10_000_000 / baudrate as u64);
+
+    port.set_timeout(timeout.saturating_add(transfer))?;
+    let res = f(port);
+    port.set_timeout(timeout)?;
+    res
+}
+
+/// Send an instruction packet. With `tx.echo_cancel` the echo of the
+/// packet is read back and checked, then `tx.delay` is waited before
+/// anything reads the reply.
+fn send(port: &mut dyn SerialPort, packet: &[u8], tx: TxOptions) -> Result<()> {
+    port.write_all(packet)?;
+    if tx.echo_cancel {
+        let mut echo = vec![0u8; packet.len()];
+        let timeout = port.timeout();
+        with_reply_timeout(port, timeout, packet.len(), |port| {
+            recv_exact(port, &mut echo)
+        })?;
+        if echo != packet {
+            debug!("echo {:02X?}", echo);
+            return Err(ProtocolError::BadPacket.into());
+        }
+    }
+    if !tx.delay.is_zero() {
+        std::thread::sleep(tx.delay);
+    }
+    Ok(())
+}
+
+/// Read exactly `buf.len()` bytes. Depending on platform, an expired port
+/// timeout is reported either as `TimedOut` or `WouldBlock`, both are mapped
+/// to `ProtocolError::TimedOut`.
+fn recv_exact(port: &mut dyn SerialPort, buf: &mut [u8]) -> Result<()> {
+    port.read_exact(buf).map_err(|e| match e.kind() {
+        ErrorKind::TimedOut | ErrorKind::WouldBlock => ProtocolError::TimedOut.into(),
+        _ => e.into(),
+    })
+}
+
+/// Bulk instructions address every device at most once, and never the
+/// broadcast id their packet is sent to
+fn check_bulk_ids(ids: &[u8]) -> Result<()> {
+    for (i, id) in ids.iter().enumerate() {
+        if *id == 0xFE || ids[..i].contains(id) {
+            return Err(ProtocolError::InvalidArg.into());
+        }
     }
+    Ok(())
+}
+
+/// Collect one status per bulk read request. Statuses are matched to
+/// requests by their id rather than by order. `recv` returns the id and
+/// parameters of the next status packet.
+fn collect_bulk_status(
+    requests: &[(u8, u16, u16)],
+    mut recv: impl FnMut() -> Result<(u8, Vec<u8>)>,
+) -> Result<Vec<Vec<u8>>> {
+    let mut result: Vec<Option<Vec<u8>>> = vec![None; requests.len()];
+
+    for _ in requests {
+        let (id, data) = recv().map_err(|e| {
+            let missing: Vec<_> = requests
+                .iter()
+                .zip(&result)
+                .filter(|(_, data)| data.is_none())
+                .map(|(r, _)| r.0)
+                .collect();
+            e.context(format!("No bulk read status from ids {:?}", missing))
+        })?;
+
+        let slot = requests
+            .iter()
+            .position(|r| r.0 == id)
+            .ok_or(ProtocolError::BadPacket)?;
+        let count = requests[slot].2 as usize;
+        if result[slot].is_some() || data.len() < count {
+            return Err(ProtocolError::BadPacket.into());
+        }
+        result[slot] = Some(data[..count].to_vec());
+    }
+
+    Ok(result.into_iter().map(Option::unwrap).collect())
 }
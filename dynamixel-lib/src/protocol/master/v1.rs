@@ -1,29 +1,51 @@
+use std::time::Duration;
+
 use log::debug;
 use serialport::SerialPort;
 
-use super::Protocol;
-use crate::protocol::{ProtocolError, ProtocolVersion, Result};
+use super::{
+    check_bulk_ids, collect_bulk_status, recv_exact, send, with_reply_timeout, with_retries,
+    PingResult, Protocol, ResetScope, Retries, TxOptions,
+};
+use crate::protocol::{ProtocolError, ProtocolVersion, Result, StatusByte};
 
 pub struct ProtocolV1<'a> {
     port: &'a mut dyn SerialPort,
-    retries: usize,
+    retries: Retries,
+    timeout: Duration,
+    tx: TxOptions,
 }
 
 impl<'a> ProtocolV1<'a> {
-    pub fn new(port: &'a mut dyn SerialPort, retries: usize) -> Self {
-        Self { port, retries }
+    pub fn new(
+        port: &'a mut dyn SerialPort,
+        retries: usize,
+        timeout: Duration,
+        tx: TxOptions,
+    ) -> Self {
+        Self {
+            port,
+            retries: Retries::new(retries),
+            timeout,
+            tx,
+        }
     }
 }
 
 impl<'a> Protocol for ProtocolV1<'a> {
+    fn retry_count(&self) -> usize {
+        self.retries.count.get()
+    }
+
+    fn ping(&mut self, id: u8) -> Result<PingResult> {
+        with_retries(self.port, &self.retries, |port| ping_v1(port, self.tx, id))
+    }
+
     fn scan(&mut self, scan_start: u8, scan_end: u8) -> Result<Vec<u8>> {
         let mut result: Vec<u8> = Vec::new();
-        (scan_start..scan_end).into_iter().for_each(|id| {
-            for _ in 0..=self.retries {
-                if ping_v1(self.port, id).is_ok() {
-                    result.push(id);
-                    break;
-                }
+        (scan_start..=scan_end.min(BROADCAST_ID - 1)).for_each(|id| {
+            if self.ping(id).is_ok() {
+                result.push(id);
             }
         });
         Ok(result)
@@ -34,54 +56,154 @@ impl<'a> Protocol for ProtocolV1<'a> {
             return Err(ProtocolError::InvalidAddress.into());
         }
 
-        if count > 0xFF {
+        if count > MAX_READ_COUNT {
             return Err(ProtocolError::InvalidCount.into());
         }
 
-        let mut error = None;
+        with_reply_timeout(self.port, self.timeout, count.into(), |port| {
+            with_retries(port, &self.retries, |port| {
+                read_v1(port, self.tx, id, address as u8, count as u8)
+            })
+        })
+    }
 
-        for _ in 0..=self.retries {
-            match read_v1(self.port, id, address as u8, count as u8) {
-                Ok(data) => return Ok(data),
-                Err(e) => error = Some(e),
-            }
+    fn read_chunked(&mut self, id: u8, address: u16, count: u16) -> Result<Vec<u8>> {
+        let mut result = Vec::with_capacity(count.into());
+
+        while result.len() < count.into() {
+            let offset = result.len() as u16;
+            let chunk = self.read(id, address + offset, (count - offset).min(MAX_READ_COUNT))?;
+            result.extend(chunk);
         }
-        Err(error.unwrap())
+        Ok(result)
     }
 
     fn write(&mut self, id: u8, address: u16, data: &[u8]) -> anyhow::Result<()> {
-        let mut error = None;
-
         if address > 0xFF {
             return Err(ProtocolError::InvalidAddress.into());
         }
 
-        for _ in 0..=self.retries {
-            match write_v1(self.port, id, address as u8, data) {
-                Ok(data) => return Ok(data),
-                Err(e) => error = Some(e),
-            }
+        with_retries(self.port, &self.retries, |port| {
+            write_v1(port, self.tx, OPCODE_WRITE, id, address as u8, data)
+        })
+    }
+
+    fn reg_write(&mut self, id: u8, address: u16, data: &[u8]) -> Result<()> {
+        if address > 0xFF {
+            return Err(ProtocolError::InvalidAddress.into());
         }
 
-        Err(error.unwrap())
+        with_retries(self.port, &self.retries, |port| {
+            write_v1(port, self.tx, OPCODE_REG_WRITE, id, address as u8, data)
+        })
+    }
+
+    fn action(&mut self, id: u8) -> Result<()> {
+        with_retries(self.port, &self.retries, |port| {
+            action_v1(port, self.tx, id)
+        })
     }
 
     fn version(&self) -> ProtocolVersion {
         crate::protocol::ProtocolVersion::V1
     }
 
-    fn sync_write(&mut self, _ids: &[u8], _address: u16, _data: &[&[u8]]) -> Result<()> {
+    /// Emulated with one write per id, so unlike protocol 2 the devices
+    /// are not updated at once and a failing id leaves the earlier ones
+    /// written
+    fn sync_write(&mut self, ids: &[u8], address: u16, data: &[&[u8]]) -> Result<()> {
+        if ids.len() != data.len() {
+            return Err(ProtocolError::InvalidArg.into());
+        }
+
+        ids.iter().zip(data).try_for_each(|(&id, data)| {
+            self.write(id, address, data)
+                .map_err(|e| e.context(format!("Sync write to id {} failed", id)))
+        })
+    }
+
+    /// Emulated with one read per id, so the values are not sampled at
+    /// once
+    fn sync_read(
+        &mut self,
+        ids: &[u8],
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<(u8, Result<Vec<u8>>)>> {
+        Ok(ids
+            .iter()
+            .map(|&id| (id, self.read(id, address, count)))
+            .collect())
+    }
+
+    fn fast_sync_read(
+        &mut self,
+        _ids: &[u8],
+        _address: u16,
+        _count: u16,
+    ) -> Result<Vec<std::result::Result<Vec<u8>, u8>>> {
         Err(ProtocolError::NotSupported.into())
     }
 
-    fn sync_read(&mut self, _ids: &[u8], _address: u16, _count: u16) -> Result<Vec<Vec<u8>>> {
+    fn bulk_read(&mut self, requests: &[(u8, u16, u16)]) -> Result<Vec<Vec<u8>>> {
+        if requests.iter().any(|r| r.1 > 0xFF) {
+            return Err(ProtocolError::InvalidAddress.into());
+        }
+
+        if requests.iter().any(|r| r.2 > MAX_READ_COUNT) || requests.len() > MAX_BULK_READ_COUNT {
+            return Err(ProtocolError::InvalidCount.into());
+        }
+
+        let reply_len = requests.iter().map(|r| r.2 as usize).sum();
+        with_reply_timeout(self.port, self.timeout, reply_len, |port| {
+            with_retries(port, &self.retries, |port| {
+                bulk_read_v1(port, self.tx, requests)
+            })
+        })
+    }
+
+    fn bulk_write(&mut self, _writes: &[(u8, u16, &[u8])]) -> Result<()> {
         Err(ProtocolError::NotSupported.into())
     }
+
+    fn reboot(&mut self, _id: u8) -> Result<()> {
+        Err(ProtocolError::NotSupported.into())
+    }
+
+    fn clear_multiturn(&mut self, _id: u8) -> Result<()> {
+        Err(ProtocolError::NotSupported.into())
+    }
+
+    fn control_table_backup(&mut self, _id: u8, _restore: bool) -> Result<()> {
+        Err(ProtocolError::NotSupported.into())
+    }
+
+    fn factory_reset(&mut self, id: u8, scope: ResetScope) -> Result<()> {
+        if scope != ResetScope::All {
+            return Err(ProtocolError::NotSupported.into());
+        }
+
+        with_retries(self.port, &self.retries, |port| {
+            factory_reset_v1(port, self.tx, id)
+        })
+    }
 }
 
+// Status packet of a read must fit the 255 byte buffer
+const MAX_READ_COUNT: u16 = 0xFF - 6;
+
+// Bulk read instruction packet with 3 bytes per device must fit as well
+const MAX_BULK_READ_COUNT: usize = (0xFF - 7) / 3;
+
 const OPCODE_PING: u8 = 1;
 const OPCODE_READ: u8 = 2;
 const OPCODE_WRITE: u8 = 3;
+const OPCODE_REG_WRITE: u8 = 4;
+const OPCODE_ACTION: u8 = 5;
+const OPCODE_FACTORY_RESET: u8 = 6;
+const OPCODE_BULK_READ: u8 = 0x92;
+
+const BROADCAST_ID: u8 = 0xFE;
 
 fn encode_instruction_v1(buffer: &mut [u8], id: u8, instruction: u8, params: &[u8]) -> usize {
     let length: u8 = (2 + params.len()) as u8;
@@ -102,7 +224,32 @@ fn encode_instruction_v1(buffer: &mut [u8], id: u8, instruction: u8, params: &[u
     6 + params.len()
 }
 
-fn decode_status_v1(buffer: &[u8], params: &mut [u8]) -> Result<usize> {
+/// Id, instruction and parameters of an instruction packet. The checksum
+/// is not checked.
+pub(super) fn split_instruction_v1(packet: &[u8]) -> Option<(u8, u8, Vec<u8>)> {
+    if packet.len() < 6 || packet[0..2] != [0xFF, 0xFF] {
+        return None;
+    }
+
+    let length = usize::from(packet[3]);
+    if length < 2 || packet.len() < 4 + length {
+        return None;
+    }
+
+    Some((packet[2], packet[4], packet[5..3 + length].to_vec()))
+}
+
+/// Status packet of `id` without error or parameters. It is laid out like
+/// an instruction packet, with the error byte in place of the instruction.
+pub(super) fn ok_status_v1(id: u8) -> Vec<u8> {
+    let mut buffer = [0u8; 6];
+    let len = encode_instruction_v1(&mut buffer, id, 0, &[]);
+    buffer[..len].to_vec()
+}
+
+/// Decode status packet of `id` into parameters, returning the packet length.
+/// A status of another device is a bad packet.
+fn decode_status_v1(buffer: &[u8], id: u8, params: &mut [u8]) -> Result<usize> {
     if buffer.len() < 6 || buffer[3] < 2 {
         return Err(ProtocolError::BadPacket.into());
     }
@@ -117,12 +264,20 @@ fn decode_status_v1(buffer: &[u8], params: &mut [u8]) -> Result<usize> {
         .cloned()
         .fold(0u8, |x, y| x.overflowing_add(y).0);
 
-    if csum != !buffer[5 + param_length] || buffer[4] != 0x0 {
+    if csum != !buffer[5 + param_length] {
+        return Err(ProtocolError::BadPacket.into());
+    }
+
+    if buffer[2] != id {
         return Err(ProtocolError::BadPacket.into());
     }
 
     if buffer[4] != 0 {
-        return Err(ProtocolError::StatusError(buffer[4]).into());
+        return Err(ProtocolError::StatusError(StatusByte {
+            version: ProtocolVersion::V1,
+            value: buffer[4],
+        })
+        .into());
     }
 
     params[..param_length].copy_from_slice(&buffer[5..5 + param_length]);
@@ -130,7 +285,7 @@ fn decode_status_v1(buffer: &[u8], params: &mut [u8]) -> Result<usize> {
     Ok(6 + param_length)
 }
 
-fn ping_v1(port: &mut dyn SerialPort, id: u8) -> Result<()> {
+fn ping_v1(port: &mut dyn SerialPort, tx: TxOptions, id: u8) -> Result<PingResult> {
     let mut buffer: [u8; 255] = [0u8; 255];
     let mut params: [u8; 255] = [0u8; 255];
 
@@ -139,15 +294,68 @@ fn ping_v1(port: &mut dyn SerialPort, id: u8) -> Result<()> {
 
     debug!("ping {}", id);
     debug!("send {:02X?}", &buffer[0..len_write]);
-    port.write_all(&buffer[0..len_write])?;
+    send(port, &buffer[0..len_write], tx)?;
+
+    recv_exact(port, &mut buffer[0..len_read])?;
+    debug!("recv {:02X?}", &buffer[0..len_read]);
+
+    decode_status_v1(&buffer, id, &mut params).map(|_| {
+        Ok(PingResult {
+            model: None,
+            firmware: None,
+        })
+    })?
+}
+
+fn action_v1(port: &mut dyn SerialPort, tx: TxOptions, id: u8) -> Result<()> {
+    let mut buffer: [u8; 255] = [0u8; 255];
+    let mut params: [u8; 255] = [0u8; 255];
+
+    let len_write = encode_instruction_v1(&mut buffer, id, OPCODE_ACTION, &[]);
+    let len_read = 6;
+
+    debug!("action {}", id);
+    debug!("send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
+
+    recv_exact(port, &mut buffer[0..len_read])?;
+    debug!("recv {:02X?}", &buffer[0..len_read]);
+
+    decode_status_v1(&buffer, id, &mut params).map(|_| Ok(()))?
+}
+
+fn factory_reset_v1(port: &mut dyn SerialPort, tx: TxOptions, id: u8) -> Result<()> {
+    let mut buffer: [u8; 255] = [0u8; 255];
+    let mut params: [u8; 255] = [0u8; 255];
+
+    let len_write = encode_instruction_v1(&mut buffer, id, OPCODE_FACTORY_RESET, &[]);
+    let len_read = 6;
+
+    debug!("factory_reset {}", id);
+    debug!("send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
 
-    port.read_exact(&mut buffer[0..len_read])?;
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
+
+    recv_exact(port, &mut buffer[0..len_read])?;
     debug!("recv {:02X?}", &buffer[0..len_read]);
 
-    decode_status_v1(&buffer, &mut params).map(|_| Ok(()))?
+    decode_status_v1(&buffer, id, &mut params).map(|_| Ok(()))?
 }
 
-fn read_v1(port: &mut dyn SerialPort, id: u8, address: u8, count: u8) -> Result<Vec<u8>> {
+fn read_v1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    id: u8,
+    address: u8,
+    count: u8,
+) -> Result<Vec<u8>> {
     let mut buffer = [0u8; 255];
     let mut params = [0u8; 255];
 
@@ -155,32 +363,208 @@ fn read_v1(port: &mut dyn SerialPort, id: u8, address: u8, count: u8) -> Result<
 
     debug!("read1 {} {} {}", id, address, count);
     debug!("send {:02X?}", &buffer[0..len_write]);
-    port.write_all(&buffer[0..len_write])?;
+    send(port, &buffer[0..len_write], tx)?;
 
     let len_read = (6 + count) as usize;
-    port.read_exact(&mut buffer[0..len_read])?;
+    recv_exact(port, &mut buffer[0..len_read])?;
     debug!("recv {:02X?}", &buffer[0..len_read]);
 
-    decode_status_v1(&buffer, &mut params).map(|_| Ok(params[0..count.into()].to_vec()))?
+    decode_status_v1(&buffer, id, &mut params).map(|_| Ok(params[0..count.into()].to_vec()))?
 }
 
-fn write_v1(port: &mut dyn SerialPort, id: u8, address: u8, data: &[u8]) -> Result<()> {
+// Plain or staged (`OPCODE_REG_WRITE`) write
+fn write_v1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    instruction: u8,
+    id: u8,
+    address: u8,
+    data: &[u8],
+) -> Result<()> {
     let mut buffer: [u8; 255] = [0; 255];
     let mut params: [u8; 255] = [0; 255];
 
     params[0] = address;
     params[1..data.len() + 1].copy_from_slice(data);
 
-    let len_write = encode_instruction_v1(&mut buffer, id, OPCODE_WRITE, &params[..data.len() + 1]);
+    let len_write = encode_instruction_v1(&mut buffer, id, instruction, &params[..data.len() + 1]);
 
-    debug!("write1 {} {} {:02X?}", id, address, data);
+    debug!("write1 {} {} {} {:02X?}", instruction, id, address, data);
     debug!("send {:02X?}", &buffer[0..len_write]);
-    port.write_all(&buffer[0..len_write])?;
+    send(port, &buffer[0..len_write], tx)?;
+
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
 
     let len_read = 6;
 
-    port.read_exact(&mut buffer[0..len_read])?;
+    recv_exact(port, &mut buffer[0..len_read])?;
     debug!("recv {:02X?}", &buffer[0..len_read]);
 
-    decode_status_v1(&buffer, &mut params).map(|_| Ok(()))?
+    decode_status_v1(&buffer, id, &mut params).map(|_| Ok(()))?
+}
+
+fn bulk_read_v1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    requests: &[(u8, u16, u16)],
+) -> Result<Vec<Vec<u8>>> {
+    let mut buffer: [u8; 255] = [0; 255];
+    let mut params: [u8; 255] = [0; 255];
+
+    check_bulk_ids(&requests.iter().map(|r| r.0).collect::<Vec<_>>())?;
+
+    // Reserved zero byte, then length, id and address per device
+    let mut req = vec![0x00];
+    for &(id, address, count) in requests {
+        req.extend([count as u8, id, address as u8]);
+    }
+
+    let len_write = encode_instruction_v1(&mut buffer, BROADCAST_ID, OPCODE_BULK_READ, &req);
+
+    debug!("bulk_read: send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    collect_bulk_status(requests, || {
+        recv_exact(port, &mut buffer[0..4])?;
+        if buffer[0..2] != [0xFF, 0xFF] {
+            return Err(ProtocolError::BadPacket.into());
+        }
+        let len_read = 4 + buffer[3] as usize;
+        if len_read > buffer.len() {
+            debug!("recv {:02X?}", &buffer[0..4]);
+            return Err(ProtocolError::BadPacket.into());
+        }
+        recv_exact(port, &mut buffer[4..len_read])?;
+        debug!("recv {:02X?}", &buffer[0..len_read]);
+
+        // Any requested id, matched against the requests by the caller
+        let param_length = decode_status_v1(&buffer[0..len_read], buffer[2], &mut params)? - 6;
+        Ok((buffer[2], params[..param_length].to_vec()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockPort;
+    use super::*;
+
+    #[test]
+    fn status_error_is_reported() {
+        // Id 1, overheat and overload
+        let status = [0xFF, 0xFF, 0x01, 0x02, 0x24, 0xD8];
+        let mut params = [0u8; 255];
+
+        let err = decode_status_v1(&status, 1, &mut params).unwrap_err();
+        match err.downcast_ref::<ProtocolError>() {
+            Some(ProtocolError::StatusError(status)) => {
+                assert_eq!(status.version, ProtocolVersion::V1);
+                assert_eq!(status.value, 0x24);
+            }
+            other => panic!("expected a status error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn bad_checksum_is_bad_packet() {
+        let status = [0xFF, 0xFF, 0x01, 0x02, 0x24, 0xD9];
+        let mut params = [0u8; 255];
+
+        let err = decode_status_v1(&status, 1, &mut params).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ProtocolError>(),
+            Some(ProtocolError::BadPacket)
+        ));
+    }
+
+    #[test]
+    fn status_params_are_returned() {
+        // Id 1 reading 0x20 0x02
+        let status = [0xFF, 0xFF, 0x01, 0x04, 0x00, 0x20, 0x02, 0xD8];
+        let mut params = [0u8; 255];
+
+        assert_eq!(decode_status_v1(&status, 1, &mut params).unwrap(), 8);
+        assert_eq!(params[..2], [0x20, 0x02]);
+    }
+
+    #[test]
+    fn retry_starts_from_flushed_input() {
+        // A stray byte misaligns the first reply. The byte left over from
+        // it must not misalign the reply to the retry as well.
+        let status = [0xFF, 0xFF, 0x01, 0x04, 0x00, 0x20, 0x02, 0xD8];
+        let mut attempt = 0;
+        let mut port = MockPort::new(move |_| {
+            attempt += 1;
+            let mut reply = if attempt == 1 { vec![0x00] } else { vec![] };
+            reply.extend(status);
+            reply
+        });
+        let mut proto = ProtocolV1::new(&mut port, 1, Duration::ZERO, TxOptions::default());
+
+        assert_eq!(proto.read(1, 36, 2).unwrap(), [0x20, 0x02]);
+        assert_eq!(proto.retry_count(), 1);
+        assert_eq!(port.packets().len(), 2);
+    }
+
+    #[test]
+    fn factory_reset_is_encoded() {
+        let mut port = MockPort::new(|packet| ok_status_v1(packet[2]));
+        let mut proto = ProtocolV1::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        proto.factory_reset(0, ResetScope::All).unwrap();
+        let err = proto.factory_reset(0, ResetScope::KeepId).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ProtocolError>(),
+            Some(ProtocolError::NotSupported)
+        ));
+        assert_eq!(port.packets(), [vec![0xFF, 0xFF, 0x00, 0x02, 0x06, 0xF7]]);
+    }
+
+    #[test]
+    fn deferred_write_is_encoded() {
+        // ROBOTIS examples: stage goal position 500 on id 1, then apply it
+        // with a broadcast action, which gets no status
+        let mut port = MockPort::new(|packet| match packet[2] {
+            BROADCAST_ID => Vec::new(),
+            id => ok_status_v1(id),
+        });
+        let mut proto = ProtocolV1::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        proto.reg_write(1, 30, &[0xF4, 0x01]).unwrap();
+        proto.action(BROADCAST_ID).unwrap();
+        assert_eq!(
+            port.packets(),
+            [
+                vec![0xFF, 0xFF, 0x01, 0x05, 0x04, 0x1E, 0xF4, 0x01, 0xE2],
+                vec![0xFF, 0xFF, 0xFE, 0x02, 0x05, 0xFA],
+            ]
+        );
+    }
+
+    #[test]
+    fn bulk_write_is_not_supported() {
+        let mut port = MockPort::new(|_| Vec::new());
+        let mut proto = ProtocolV1::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        let err = proto.bulk_write(&[(1, 30, &[0xF4, 0x01])]).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ProtocolError>(),
+            Some(ProtocolError::NotSupported)
+        ));
+        assert!(port.packets().is_empty());
+    }
+
+    #[test]
+    fn scan_reaches_id_253() {
+        let mut port = MockPort::new(|packet| match split_instruction_v1(packet) {
+            Some((253, OPCODE_PING, _)) => ok_status_v1(253),
+            _ => Vec::new(),
+        });
+        let mut proto = ProtocolV1::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        assert_eq!(proto.scan(0, BROADCAST_ID).unwrap(), [253]);
+        // The broadcast id is not pinged
+        assert_eq!(port.packets().len(), 254);
+    }
 }
@@ -1,61 +1,91 @@
 use std::{
     convert::TryInto,
     io::{Cursor, Write},
+    time::Duration,
 };
 
 use crc::{self, Crc, CRC_16_UMTS};
 use log::debug;
 use serialport::SerialPort;
 
-use super::Protocol;
-use crate::protocol::{ProtocolError, ProtocolVersion, Result};
+use super::{
+    check_bulk_ids, collect_bulk_status, recv_exact, send, with_reply_timeout, with_retries,
+    PingResult, Protocol, ResetScope, Retries, TxOptions,
+};
+use crate::protocol::{ProtocolError, ProtocolVersion, Result, StatusByte};
 
 pub struct ProtocolV2<'a> {
     port: &'a mut dyn SerialPort,
-    retries: usize,
+    retries: Retries,
+    timeout: Duration,
+    tx: TxOptions,
 }
 
 impl<'a> ProtocolV2<'a> {
-    pub fn new(port: &'a mut dyn SerialPort, retries: usize) -> Self {
-        Self { port, retries }
+    pub fn new(
+        port: &'a mut dyn SerialPort,
+        retries: usize,
+        timeout: Duration,
+        tx: TxOptions,
+    ) -> Self {
+        Self {
+            port,
+            retries: Retries::new(retries),
+            timeout,
+            tx,
+        }
     }
 }
 
 impl<'a> Protocol for ProtocolV2<'a> {
+    fn retry_count(&self) -> usize {
+        self.retries.count.get()
+    }
+
+    fn ping(&mut self, id: u8) -> Result<PingResult> {
+        with_retries(self.port, &self.retries, |port| ping(port, self.tx, id))
+    }
+
     fn scan(&mut self, scan_start: u8, scan_end: u8) -> Result<Vec<u8>> {
         let mut result: Vec<u8> = Vec::new();
-        (scan_start..scan_end).into_iter().for_each(|id| {
-            for _ in 0..=self.retries {
-                if ping(self.port, id).is_ok() {
-                    result.push(id);
-                    break;
-                }
+        (scan_start..=scan_end.min(BROADCAST_ID - 1)).for_each(|id| {
+            if self.ping(id).is_ok() {
+                result.push(id);
             }
         });
         Ok(result)
     }
 
     fn read(&mut self, id: u8, address: u16, count: u16) -> Result<Vec<u8>> {
-        let mut error = None;
-        for _ in 0..=self.retries {
-            match read1(self.port, id, address, count) {
-                Ok(data) => return Ok(data),
-                Err(e) => error = Some(e),
-            }
-        }
-        Err(error.unwrap())
+        with_reply_timeout(self.port, self.timeout, count.into(), |port| {
+            with_retries(port, &self.retries, |port| {
+                read1(port, self.tx, id, address, count, 0xFF)
+            })
+        })
+    }
+
+    fn read_alerted(&mut self, id: u8, address: u16, count: u16) -> Result<Vec<u8>> {
+        with_reply_timeout(self.port, self.timeout, count.into(), |port| {
+            with_retries(port, &self.retries, |port| {
+                read1(port, self.tx, id, address, count, !ALERT)
+            })
+        })
     }
 
     fn write(&mut self, id: u8, address: u16, data: &[u8]) -> Result<()> {
-        let mut error = None;
+        with_retries(self.port, &self.retries, |port| {
+            write1(port, self.tx, OPCODE_WRITE, id, address, data)
+        })
+    }
 
-        for _ in 0..=self.retries {
-            match write1(self.port, id, address, data) {
-                Ok(data) => return Ok(data),
-                Err(e) => error = Some(e),
-            }
-        }
-        Err(error.unwrap())
+    fn reg_write(&mut self, id: u8, address: u16, data: &[u8]) -> Result<()> {
+        with_retries(self.port, &self.retries, |port| {
+            write1(port, self.tx, OPCODE_REG_WRITE, id, address, data)
+        })
+    }
+
+    fn action(&mut self, id: u8) -> Result<()> {
+        with_retries(self.port, &self.retries, |port| action1(port, self.tx, id))
     }
 
     fn version(&self) -> ProtocolVersion {
@@ -63,107 +93,404 @@ impl<'a> Protocol for ProtocolV2<'a> {
     }
 
     fn sync_write(&mut self, ids: &[u8], address: u16, data: &[&[u8]]) -> Result<()> {
-        let mut error = None;
+        with_retries(self.port, &self.retries, |port| {
+            sync_write1(port, self.tx, ids, address, data)
+        })
+    }
 
-        for _ in 0..=self.retries {
-            match sync_write1(self.port, ids, address, data) {
-                Ok(data) => return Ok(data),
-                Err(e) => error = Some(e),
-            }
+    fn sync_read(
+        &mut self,
+        ids: &[u8],
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<(u8, Result<Vec<u8>>)>> {
+        // Retry while some ids fail, keeping their last results if they
+        // never answer
+        let mut partial = None;
+        let reply_len = ids.len() * count as usize;
+        let res = with_reply_timeout(self.port, self.timeout, reply_len, |port| {
+            with_retries(port, &self.retries, |port| {
+                let res = sync_read1(port, self.tx, ids, address, count)?;
+                if res.iter().any(|(_, data)| data.is_err()) {
+                    partial = Some(res);
+                    return Err(ProtocolError::TimedOut.into());
+                }
+                Ok(res)
+            })
+        });
+        match (res, partial) {
+            (Err(_), Some(partial)) => Ok(partial),
+            (res, _) => res,
         }
-        Err(error.unwrap())
     }
 
-    fn sync_read(&mut self, ids: &[u8], address: u16, count: u16) -> Result<Vec<Vec<u8>>> {
-        let mut error = None;
-        for _ in 0..=self.retries {
-            match sync_read1(self.port, ids, address, count) {
-                Ok(data) => return Ok(data),
-                Err(e) => error = Some(e),
-            }
-        }
-        Err(error.unwrap())
+    fn fast_sync_read(
+        &mut self,
+        ids: &[u8],
+        address: u16,
+        count: u16,
+    ) -> Result<Vec<std::result::Result<Vec<u8>, u8>>> {
+        let reply_len = ids.len() * count as usize;
+        with_reply_timeout(self.port, self.timeout, reply_len, |port| {
+            with_retries(port, &self.retries, |port| {
+                fast_sync_read1(port, self.tx, ids, address, count)
+            })
+        })
+    }
+
+    fn bulk_read(&mut self, requests: &[(u8, u16, u16)]) -> Result<Vec<Vec<u8>>> {
+        let reply_len = requests.iter().map(|r| r.2 as usize).sum();
+        with_reply_timeout(self.port, self.timeout, reply_len, |port| {
+            with_retries(port, &self.retries, |port| {
+                bulk_read1(port, self.tx, requests)
+            })
+        })
+    }
+
+    fn bulk_write(&mut self, writes: &[(u8, u16, &[u8])]) -> Result<()> {
+        with_retries(self.port, &self.retries, |port| {
+            bulk_write1(port, self.tx, writes)
+        })
+    }
+
+    fn reboot(&mut self, id: u8) -> Result<()> {
+        with_retries(self.port, &self.retries, |port| reboot1(port, self.tx, id))
+    }
+
+    fn clear_multiturn(&mut self, id: u8) -> Result<()> {
+        with_retries(self.port, &self.retries, |port| clear1(port, self.tx, id))
+    }
+
+    fn control_table_backup(&mut self, id: u8, restore: bool) -> Result<()> {
+        with_retries(self.port, &self.retries, |port| {
+            backup1(port, self.tx, id, restore)
+        })
+    }
+
+    fn factory_reset(&mut self, id: u8, scope: ResetScope) -> Result<()> {
+        with_retries(self.port, &self.retries, |port| {
+            factory_reset1(port, self.tx, id, scope)
+        })
     }
 }
 
 const OPCODE_PING: u8 = 1;
 const OPCODE_READ: u8 = 2;
 const OPCODE_WRITE: u8 = 3;
+const OPCODE_REG_WRITE: u8 = 4;
+const OPCODE_ACTION: u8 = 5;
+const OPCODE_FACTORY_RESET: u8 = 6;
+const OPCODE_REBOOT: u8 = 8;
+const OPCODE_CLEAR: u8 = 0x10;
+const OPCODE_BACKUP: u8 = 0x20;
 const OPCODE_SYNC_READ: u8 = 0x82;
 const OPCODE_SYNC_WRITE: u8 = 0x83;
+const OPCODE_FAST_SYNC_READ: u8 = 0x8A;
+const OPCODE_BULK_READ: u8 = 0x92;
+const OPCODE_BULK_WRITE: u8 = 0x93;
+const OPCODE_STATUS: u8 = 0x55;
+
+/// Status error bit of a device with errors in HardwareErrorStatus
+const ALERT: u8 = 0x80;
 
 const BROADCAST_ID: u8 = 0xFE;
 
+const HEADER: [u8; 3] = [0xFF, 0xFF, 0xFD];
+const STUFFING: u8 = 0xFD;
+
+/// Header is FF FF FD followed by a reserved byte. FF FF FD FD is a stuffed
+/// payload sequence rather than a header, so FD is never a valid reserved byte.
+fn is_header(buffer: &[u8]) -> bool {
+    buffer.len() >= 4 && buffer[0..3] == HEADER && buffer[3] != STUFFING
+}
+
 fn encode_instruction_v2(buffer: &mut [u8], id: u8, instruction: u8, params: &[u8]) -> usize {
-    let length = (3 + params.len()) as u16;
-    assert!(usize::from(length) <= buffer.len());
+    assert!(10 + params.len() + params.len() / 3 <= buffer.len());
 
-    buffer[0] = 0xFF;
-    buffer[1] = 0xFF;
-    buffer[2] = 0xFD;
+    buffer[0..3].copy_from_slice(&HEADER);
     buffer[3] = 0x00;
     buffer[4] = id;
-    buffer[5..7].copy_from_slice(&length.to_le_bytes());
     buffer[7] = instruction;
 
-    buffer[8..(8 + params.len())].clone_from_slice(params);
+    // Byte stuffing: FF FF FD within instruction and parameters is
+    // followed by an extra FD
+    let mut end = 8;
+    for &byte in params {
+        buffer[end] = byte;
+        end += 1;
+        if end >= 10 && buffer[end - 3..end] == HEADER {
+            buffer[end] = STUFFING;
+            end += 1;
+        }
+    }
+
+    let length = (end - 5) as u16;
+    buffer[5..7].copy_from_slice(&length.to_le_bytes());
 
     let crc = Crc::<u16>::new(&CRC_16_UMTS);
-    let cs = crc.checksum(&buffer[0..(8 + params.len())]);
+    let cs = crc.checksum(&buffer[0..end]);
 
-    buffer[8 + params.len()..10 + params.len()].clone_from_slice(&cs.to_le_bytes());
-    10 + params.len()
+    buffer[end..end + 2].clone_from_slice(&cs.to_le_bytes());
+    end + 2
 }
 
-fn decode_status_v2(buffer: &[u8], params: &mut [u8]) -> Result<usize> {
-    if buffer.len() < 10 {
+/// Receive a status packet, returning its total length. The length is taken
+/// from the packet header since byte stuffing makes it payload dependent.
+fn recv_status_v2(port: &mut dyn SerialPort, buffer: &mut [u8]) -> Result<usize> {
+    recv_exact(port, &mut buffer[0..7])?;
+
+    let length = u16::from_le_bytes(buffer[5..7].try_into().unwrap()) as usize;
+    if !is_header(buffer) || 7 + length > buffer.len() {
+        debug!("recv {:02X?}", &buffer[0..7]);
         return Err(ProtocolError::BadPacket.into());
     }
 
-    let length = u16::from_le_bytes(buffer[5..7].try_into().unwrap());
-    if length < 4 {
+    recv_exact(port, &mut buffer[7..7 + length])?;
+    debug!("recv {:02X?}", &buffer[0..7 + length]);
+    Ok(7 + length)
+}
+
+/// Check framing and CRC of a status packet, returning its unstuffed
+/// payload: instruction, error and parameters
+fn status_payload_v2(buffer: &[u8]) -> Result<Vec<u8>> {
+    if buffer.len() < 11 || !is_header(buffer) {
         return Err(ProtocolError::BadPacket.into());
     }
-    let param_length: usize = length as usize - 4;
 
-    if buffer.len() < (10 + param_length) || buffer[0..4] != [0xFF, 0xFF, 0xFD, 0x00] {
+    let length = u16::from_le_bytes(buffer[5..7].try_into().unwrap()) as usize;
+    if length < 4 || buffer.len() < 7 + length {
         return Err(ProtocolError::BadPacket.into());
     }
+    let crc_pos = 7 + length - 2;
 
     let crc = Crc::<u16>::new(&CRC_16_UMTS);
-    let cs = crc.checksum(&buffer[0..(9 + param_length)]);
+    let cs = crc.checksum(&buffer[0..crc_pos]);
 
-    if buffer[9 + param_length..11 + param_length] != cs.to_le_bytes() {
+    if buffer[crc_pos..crc_pos + 2] != cs.to_le_bytes() {
         return Err(ProtocolError::BadPacket.into());
     }
 
-    if buffer[8] != 0 {
-        return Err(ProtocolError::StatusError(buffer[8]).into());
+    Ok(unstuff(&buffer[7..crc_pos]))
+}
+
+/// Drop the FD stuffed after FF FF FD within instruction and parameters
+fn unstuff(bytes: &[u8]) -> Vec<u8> {
+    let mut payload: Vec<u8> = Vec::with_capacity(bytes.len());
+    for &byte in bytes {
+        if byte == STUFFING && payload.ends_with(&HEADER) {
+            continue;
+        }
+        payload.push(byte);
+    }
+    payload
+}
+
+/// Id, instruction and unstuffed parameters of an instruction packet. The
+/// CRC is not checked.
+pub(super) fn split_instruction_v2(packet: &[u8]) -> Option<(u8, u8, Vec<u8>)> {
+    if packet.len() < 10 || !is_header(packet) {
+        return None;
     }
 
-    params[..param_length].copy_from_slice(&buffer[9..9 + param_length]);
+    let length = u16::from_le_bytes(packet[5..7].try_into().unwrap()) as usize;
+    if length < 3 || packet.len() < 7 + length {
+        return None;
+    }
+
+    let payload = unstuff(&packet[7..7 + length - 2]);
+    Some((packet[4], payload[0], payload[1..].to_vec()))
+}
 
-    Ok(10 + param_length)
+/// Status packet of `id` without error or parameters
+pub(super) fn ok_status_v2(id: u8) -> Vec<u8> {
+    let mut buffer = [0u8; 11];
+    let len = encode_instruction_v2(&mut buffer, id, OPCODE_STATUS, &[0]);
+    buffer[..len].to_vec()
 }
 
-fn ping(port: &mut dyn SerialPort, id: u8) -> Result<()> {
+/// Decode status packet of `id` into unstuffed parameters, returning their
+/// count. A status of another device, or our own instruction echoed back,
+/// is a bad packet.
+fn decode_status_v2(buffer: &[u8], id: u8, params: &mut [u8]) -> Result<usize> {
+    decode_status_masked_v2(buffer, id, params, 0xFF)
+}
+
+/// `decode_status_v2` failing only on the bits of the error byte in `errors`
+fn decode_status_masked_v2(buffer: &[u8], id: u8, params: &mut [u8], errors: u8) -> Result<usize> {
+    let payload = status_payload_v2(buffer)?;
+    if buffer[4] != id || payload[0] != OPCODE_STATUS {
+        return Err(ProtocolError::BadPacket.into());
+    }
+
+    if payload[1] & errors != 0 {
+        return Err(ProtocolError::StatusError(StatusByte {
+            version: ProtocolVersion::V2,
+            value: payload[1],
+        })
+        .into());
+    }
+
+    let param_length = payload.len() - 2;
+    params[..param_length].copy_from_slice(&payload[2..]);
+
+    Ok(param_length)
+}
+
+fn ping(port: &mut dyn SerialPort, tx: TxOptions, id: u8) -> Result<PingResult> {
     let mut buffer = [0u8; 65535];
     let mut params = [0u8; 65535];
 
     let len_write = encode_instruction_v2(&mut buffer, id, OPCODE_PING, &[]);
-    let len_read = 14;
 
     debug!("ping {}", id);
     debug!("send {:02X?}", &buffer[0..len_write]);
-    port.write_all(&buffer[0..len_write])?;
+    send(port, &buffer[0..len_write], tx)?;
+
+    let len_read = recv_status_v2(port, &mut buffer)?;
+
+    let param_length = decode_status_v2(&buffer[0..len_read], id, &mut params)?;
+    if param_length < 3 {
+        return Err(ProtocolError::BadPacket.into());
+    }
+
+    Ok(PingResult {
+        model: Some(u16::from_le_bytes([params[0], params[1]])),
+        firmware: Some(params[2]),
+    })
+}
+
+fn action1(port: &mut dyn SerialPort, tx: TxOptions, id: u8) -> Result<()> {
+    let mut buffer = [0u8; 65535];
+    let mut params = [0u8; 65535];
+
+    let len_write = encode_instruction_v2(&mut buffer, id, OPCODE_ACTION, &[]);
+
+    debug!("action {}", id);
+    debug!("send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
+
+    let len_read = recv_status_v2(port, &mut buffer)?;
+
+    decode_status_v2(&buffer[0..len_read], id, &mut params).map(|_| Ok(()))?
+}
+
+fn reboot1(port: &mut dyn SerialPort, tx: TxOptions, id: u8) -> Result<()> {
+    let mut buffer = [0u8; 65535];
+    let mut params = [0u8; 65535];
+
+    let len_write = encode_instruction_v2(&mut buffer, id, OPCODE_REBOOT, &[]);
+
+    debug!("reboot {}", id);
+    debug!("send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
+
+    let len_read = recv_status_v2(port, &mut buffer)?;
+
+    decode_status_v2(&buffer[0..len_read], id, &mut params).map(|_| Ok(()))?
+}
+
+// Fixed parameters selecting the multi-turn clear
+const CLEAR_MULTITURN: [u8; 5] = [0x01, 0x44, 0x58, 0x4C, 0x22];
+
+fn clear1(port: &mut dyn SerialPort, tx: TxOptions, id: u8) -> Result<()> {
+    let mut buffer = [0u8; 65535];
+    let mut params = [0u8; 65535];
+
+    let len_write = encode_instruction_v2(&mut buffer, id, OPCODE_CLEAR, &CLEAR_MULTITURN);
+
+    debug!("clear {}", id);
+    debug!("send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
+
+    let len_read = recv_status_v2(port, &mut buffer)?;
+
+    decode_status_v2(&buffer[0..len_read], id, &mut params).map(|_| Ok(()))?
+}
+
+// Store (0x01) or restore (0x02), followed by "CTRL"
+const BACKUP_STORE: [u8; 5] = [0x01, 0x43, 0x54, 0x52, 0x4C];
+const BACKUP_RESTORE: [u8; 5] = [0x02, 0x43, 0x54, 0x52, 0x4C];
+
+// Writing flash takes a while, the status comes late
+const BACKUP_TIMEOUT: Duration = Duration::from_secs(1);
+
+fn backup1(port: &mut dyn SerialPort, tx: TxOptions, id: u8, restore: bool) -> Result<()> {
+    let mut buffer = [0u8; 65535];
+    let mut params = [0u8; 65535];
+
+    let len_write = encode_instruction_v2(
+        &mut buffer,
+        id,
+        OPCODE_BACKUP,
+        if restore {
+            &BACKUP_RESTORE
+        } else {
+            &BACKUP_STORE
+        },
+    );
+
+    debug!("backup {} {}", id, restore);
+    debug!("send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
+
+    let timeout = port.timeout();
+    port.set_timeout(timeout.max(BACKUP_TIMEOUT))?;
+    let res = recv_status_v2(port, &mut buffer);
+    port.set_timeout(timeout)?;
+    let len_read = res?;
+
+    decode_status_v2(&buffer[0..len_read], id, &mut params).map(|_| Ok(()))?
+}
+
+fn factory_reset1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    id: u8,
+    scope: ResetScope,
+) -> Result<()> {
+    let mut buffer = [0u8; 65535];
+    let mut params = [0u8; 65535];
+
+    let len_write = encode_instruction_v2(&mut buffer, id, OPCODE_FACTORY_RESET, &[scope as u8]);
+
+    debug!("factory_reset {} {:?}", id, scope);
+    debug!("send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
 
-    port.read_exact(&mut buffer[0..len_read])?;
-    debug!("recv {:02X?}", &buffer[0..len_read]);
+    let len_read = recv_status_v2(port, &mut buffer)?;
 
-    decode_status_v2(&buffer, &mut params).map(|_| Ok(()))?
+    decode_status_v2(&buffer[0..len_read], id, &mut params).map(|_| Ok(()))?
 }
 
-fn read1(port: &mut dyn SerialPort, id: u8, address: u16, count: u16) -> Result<Vec<u8>> {
+/// Read of `id`, failing on the bits of the status error byte in `errors`
+fn read1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    id: u8,
+    address: u16,
+    count: u16,
+    errors: u8,
+) -> Result<Vec<u8>> {
     let mut buffer = [0u8; 65535];
     let mut params = [0u8; 65535];
 
@@ -176,37 +503,51 @@ fn read1(port: &mut dyn SerialPort, id: u8, address: u16, count: u16) -> Result<
 
     debug!("read1 {} {} {}", id, address, count);
     debug!("send {:02X?}", &buffer[0..len_write]);
-    port.write_all(&buffer[0..len_write])?;
+    send(port, &buffer[0..len_write], tx)?;
 
-    let len_read = (11 + count) as usize;
-    port.read_exact(&mut buffer[0..len_read])?;
-    debug!("recv {:02X?}", &buffer[0..len_read]);
+    let len_read = recv_status_v2(port, &mut buffer)?;
 
-    decode_status_v2(&buffer, &mut params).map(|_| Ok(params[0..count.into()].to_vec()))?
+    decode_status_masked_v2(&buffer[0..len_read], id, &mut params, errors)
+        .map(|_| Ok(params[0..count.into()].to_vec()))?
 }
 
-fn write1(port: &mut dyn SerialPort, id: u8, address: u16, data: &[u8]) -> Result<()> {
+// Plain or staged (`OPCODE_REG_WRITE`) write
+fn write1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    instruction: u8,
+    id: u8,
+    address: u16,
+    data: &[u8],
+) -> Result<()> {
     let mut buffer: [u8; 65535] = [0; 65535];
     let mut params: [u8; 65535] = [0; 65535];
 
     params[0..2].clone_from_slice(&address.to_le_bytes());
     params[2..2 + data.len()].copy_from_slice(data);
 
-    let len_write = encode_instruction_v2(&mut buffer, id, OPCODE_WRITE, &params[..2 + data.len()]);
+    let len_write = encode_instruction_v2(&mut buffer, id, instruction, &params[..2 + data.len()]);
 
-    debug!("write1 {} {} {:02X?}", id, address, data);
+    debug!("write1 {} {} {} {:02X?}", instruction, id, address, data);
     debug!("send {:02X?}", &buffer[0..len_write]);
-    port.write_all(&buffer[0..len_write])?;
+    send(port, &buffer[0..len_write], tx)?;
 
-    let len_read = 11;
+    if id == BROADCAST_ID {
+        return Ok(());
+    }
 
-    port.read_exact(&mut buffer[0..len_read])?;
-    debug!("recv {:02X?}", &buffer[0..len_read]);
+    let len_read = recv_status_v2(port, &mut buffer)?;
 
-    decode_status_v2(&buffer, &mut params).map(|_| Ok(()))?
+    decode_status_v2(&buffer[0..len_read], id, &mut params).map(|_| Ok(()))?
 }
 
-fn sync_write1(port: &mut dyn SerialPort, ids: &[u8], address: u16, data: &[&[u8]]) -> Result<()> {
+fn sync_write1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    ids: &[u8],
+    address: u16,
+    data: &[&[u8]],
+) -> Result<()> {
     let mut buffer: [u8; 65535] = [0; 65535];
     let mut params: [u8; 65535] = [0; 65535];
 
@@ -232,19 +573,23 @@ fn sync_write1(port: &mut dyn SerialPort, ids: &[u8], address: u16, data: &[&[u8
         &params[..n_params as usize],
     );
     debug!("sync_write: send {:02X?}", &buffer[0..len_write]);
-    Ok(port.write_all(&buffer[0..len_write])?)
+    send(port, &buffer[0..len_write], tx)
 }
 
+/// Sync read, matching statuses to ids like bulk read. Ids without a
+/// status before a timeout get a timeout error, a status error only fails
+/// its own id.
 fn sync_read1(
     port: &mut dyn SerialPort,
+    tx: TxOptions,
     ids: &[u8],
     address: u16,
     count: u16,
-) -> Result<Vec<Vec<u8>>> {
+) -> Result<Vec<(u8, Result<Vec<u8>>)>> {
     let mut buffer: [u8; 65535] = [0; 65535];
     let mut params: [u8; 65535] = [0; 65535];
     let mut req = Cursor::new(params.as_mut_slice());
-    let mut result = Vec::new();
+    let mut result: Vec<Option<Result<Vec<u8>>>> = ids.iter().map(|_| None).collect();
 
     req.write_all(&address.to_le_bytes())?;
     req.write_all(&count.to_le_bytes())?;
@@ -261,16 +606,335 @@ fn sync_read1(
         &params[..n_params as usize],
     );
     debug!("sync_read: send {:02X?}", &buffer[0..len_write]);
-    port.write_all(&buffer[0..len_write])?;
-
-    let len_read = (11 + count) as usize;
+    send(port, &buffer[0..len_write], tx)?;
 
     for _ in ids {
-        port.read_exact(&mut buffer[0..len_read])?;
-        debug!("recv {:02X?}", &buffer[0..len_read]);
-        result
-            .push(decode_status_v2(&buffer, &mut params).map(|_| params[0..count.into()].to_vec())?)
+        let len_read = match recv_status_v2(port, &mut buffer) {
+            Ok(len_read) => len_read,
+            // A missing device, later ones may still answer
+            Err(e) if matches!(e.downcast_ref(), Some(ProtocolError::TimedOut)) => continue,
+            Err(e) => return Err(e),
+        };
+
+        let slot = ids
+            .iter()
+            .position(|&id| id == buffer[4])
+            .ok_or(ProtocolError::BadPacket)?;
+        if result[slot].is_some() {
+            return Err(ProtocolError::BadPacket.into());
+        }
+        result[slot] = Some(
+            decode_status_v2(&buffer[0..len_read], ids[slot], &mut params).and_then(
+                |param_length| {
+                    if param_length < count.into() {
+                        return Err(ProtocolError::BadPacket.into());
+                    }
+                    Ok(params[0..count.into()].to_vec())
+                },
+            ),
+        );
     }
 
-    Ok(result)
+    Ok(ids
+        .iter()
+        .zip(result)
+        .map(|(&id, data)| {
+            (
+                id,
+                data.unwrap_or_else(|| Err(ProtocolError::TimedOut.into())),
+            )
+        })
+        .collect())
+}
+
+fn bulk_read1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    requests: &[(u8, u16, u16)],
+) -> Result<Vec<Vec<u8>>> {
+    let mut buffer: [u8; 65535] = [0; 65535];
+    let mut params: [u8; 65535] = [0; 65535];
+    let mut req = Cursor::new(params.as_mut_slice());
+
+    check_bulk_ids(&requests.iter().map(|r| r.0).collect::<Vec<_>>())?;
+
+    for (id, address, count) in requests {
+        req.write_all(&id.to_le_bytes())?;
+        req.write_all(&address.to_le_bytes())?;
+        req.write_all(&count.to_le_bytes())?;
+    }
+
+    let n_params = req.position();
+    let len_write = encode_instruction_v2(
+        &mut buffer,
+        BROADCAST_ID,
+        OPCODE_BULK_READ,
+        &params[..n_params as usize],
+    );
+    debug!("bulk_read: send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    collect_bulk_status(requests, || {
+        let len_read = recv_status_v2(port, &mut buffer)?;
+        // Any requested id, matched against the requests by the caller
+        let param_length = decode_status_v2(&buffer[0..len_read], buffer[4], &mut params)?;
+        Ok((buffer[4], params[..param_length].to_vec()))
+    })
+}
+
+fn bulk_write1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    writes: &[(u8, u16, &[u8])],
+) -> Result<()> {
+    let mut buffer: [u8; 65535] = [0; 65535];
+    let mut params = Vec::new();
+
+    check_bulk_ids(&writes.iter().map(|w| w.0).collect::<Vec<_>>())?;
+
+    for (id, address, data) in writes {
+        params.push(*id);
+        params.extend(address.to_le_bytes());
+        params.extend((data.len() as u16).to_le_bytes());
+        params.extend(*data);
+    }
+
+    // Room for the worst case of byte stuffing
+    if 10 + params.len() + params.len() / 3 > buffer.len() {
+        return Err(ProtocolError::InvalidCount.into());
+    }
+
+    let len_write = encode_instruction_v2(&mut buffer, BROADCAST_ID, OPCODE_BULK_WRITE, &params);
+    debug!("bulk_write: send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)
+}
+
+fn fast_sync_read1(
+    port: &mut dyn SerialPort,
+    tx: TxOptions,
+    ids: &[u8],
+    address: u16,
+    count: u16,
+) -> Result<Vec<std::result::Result<Vec<u8>, u8>>> {
+    let mut buffer: [u8; 65535] = [0; 65535];
+    let params = [&address.to_le_bytes(), &count.to_le_bytes(), ids].concat();
+
+    let len_write =
+        encode_instruction_v2(&mut buffer, BROADCAST_ID, OPCODE_FAST_SYNC_READ, &params);
+    debug!("fast_sync_read: send {:02X?}", &buffer[0..len_write]);
+    send(port, &buffer[0..len_write], tx)?;
+
+    let len_read = recv_status_v2(port, &mut buffer)?;
+    let payload = status_payload_v2(&buffer[0..len_read])?;
+
+    // Instruction, then error, id and data of each device. Devices but the
+    // last append a CRC, the last one's is the packet CRC.
+    let stride = count as usize + 4;
+    if ids.is_empty() || payload.len() != 1 + ids.len() * stride - 2 {
+        return Err(ProtocolError::BadPacket.into());
+    }
+
+    let mut result = vec![None; ids.len()];
+    for entry in payload[1..].chunks(stride) {
+        let slot = ids
+            .iter()
+            .position(|&id| id == entry[1])
+            .filter(|&i| result[i].is_none())
+            .ok_or(ProtocolError::BadPacket)?;
+        result[slot] = Some(match entry[0] {
+            0 => Ok(entry[2..2 + count as usize].to_vec()),
+            error => Err(error),
+        });
+    }
+
+    Ok(result.into_iter().map(Option::unwrap).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mock::MockPort;
+    use super::*;
+
+    /// Status packet of `id` with `error` and `params`, stuffed as sent
+    fn status(id: u8, error: u8, params: &[u8]) -> Vec<u8> {
+        let mut buffer = [0u8; 64];
+        let payload: Vec<u8> = std::iter::once(error)
+            .chain(params.iter().copied())
+            .collect();
+        let len = encode_instruction_v2(&mut buffer, id, OPCODE_STATUS, &payload);
+        buffer[..len].to_vec()
+    }
+
+    #[test]
+    fn stuffed_sequence_is_not_a_header() {
+        assert!(is_header(&[0xFF, 0xFF, 0xFD, 0x00]));
+        assert!(!is_header(&[0xFF, 0xFF, 0xFD, 0xFD]));
+        assert!(!is_header(&[0xFF, 0xFF, 0xFD]));
+    }
+
+    #[test]
+    fn stuffed_status_is_decoded() {
+        let packet = status(1, 0, &[0xFF, 0xFF, 0xFD, 0x01]);
+        // FD stuffed after the header sequence in the parameters
+        assert_eq!(packet[9..14], [0xFF, 0xFF, 0xFD, 0xFD, 0x01]);
+
+        let mut params = [0u8; 64];
+        assert_eq!(decode_status_v2(&packet, 1, &mut params).unwrap(), 4);
+        assert_eq!(params[..4], [0xFF, 0xFF, 0xFD, 0x01]);
+    }
+
+    #[test]
+    fn status_starting_with_stuffed_sequence_is_bad_packet() {
+        let mut packet = status(1, 0, &[0x20, 0x02]);
+        packet[3] = STUFFING;
+
+        let mut params = [0u8; 64];
+        let err = decode_status_v2(&packet, 1, &mut params).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ProtocolError>(),
+            Some(ProtocolError::BadPacket)
+        ));
+    }
+
+    #[test]
+    fn alerted_read_ignores_only_the_alert() {
+        let mut port = MockPort::new(|packet| status(packet[4], ALERT, &[0x24]));
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        let err = proto.read(1, 70, 1).unwrap_err();
+        match err.downcast_ref::<ProtocolError>() {
+            Some(ProtocolError::StatusError(status)) => assert!(status.is_alert()),
+            other => panic!("expected a status error, got {:?}", other),
+        }
+        assert_eq!(proto.read_alerted(1, 70, 1).unwrap(), [0x24]);
+
+        let mut port = MockPort::new(|packet| status(packet[4], ALERT | 0x07, &[]));
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+        assert!(proto.read_alerted(1, 70, 1).is_err());
+    }
+
+    #[test]
+    fn factory_reset_is_encoded() {
+        let mut port = MockPort::new(|packet| ok_status_v2(packet[4]));
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        proto.factory_reset(1, ResetScope::All).unwrap();
+        proto.factory_reset(1, ResetScope::KeepId).unwrap();
+        proto.factory_reset(1, ResetScope::KeepIdBaudrate).unwrap();
+        assert_eq!(
+            port.packets(),
+            [
+                vec![0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x04, 0x00, 0x06, 0xFF, 0xA6, 0x64],
+                vec![0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x04, 0x00, 0x06, 0x01, 0xA1, 0xE6],
+                vec![0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x04, 0x00, 0x06, 0x02, 0xAB, 0xE6],
+            ]
+        );
+    }
+
+    #[test]
+    fn deferred_write_is_encoded() {
+        // ROBOTIS examples: stage goal velocity 200 on id 1, then apply it
+        let mut port = MockPort::new(|packet| match packet[4] {
+            BROADCAST_ID => Vec::new(),
+            id => ok_status_v2(id),
+        });
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        proto.reg_write(1, 104, &[0xC8, 0x00, 0x00, 0x00]).unwrap();
+        proto.action(1).unwrap();
+        proto.action(BROADCAST_ID).unwrap();
+        assert_eq!(
+            port.packets(),
+            [
+                vec![
+                    0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x09, 0x00, 0x04, 0x68, 0x00, 0xC8, 0x00, 0x00,
+                    0x00, 0xAE, 0x8E
+                ],
+                vec![0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x03, 0x00, 0x05, 0x02, 0xCE],
+                vec![0xFF, 0xFF, 0xFD, 0x00, 0xFE, 0x03, 0x00, 0x05, 0x2A, 0xC2],
+            ]
+        );
+    }
+
+    #[test]
+    fn bulk_write_is_encoded() {
+        // Goal position 150 on id 1 and 1000 on id 2, broadcast without
+        // status
+        let mut port = MockPort::new(|_| Vec::new());
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        proto
+            .bulk_write(&[
+                (1, 116, &[0x96, 0x00, 0x00, 0x00]),
+                (2, 116, &[0xE8, 0x03, 0x00, 0x00]),
+            ])
+            .unwrap();
+        assert_eq!(
+            port.packets(),
+            [vec![
+                0xFF, 0xFF, 0xFD, 0x00, 0xFE, 0x15, 0x00, 0x93, 0x01, 0x74, 0x00, 0x04, 0x00, 0x96,
+                0x00, 0x00, 0x00, 0x02, 0x74, 0x00, 0x04, 0x00, 0xE8, 0x03, 0x00, 0x00, 0x2D, 0x46
+            ]]
+        );
+    }
+
+    #[test]
+    fn bulk_write_rejects_bad_requests() {
+        let mut port = MockPort::new(|_| Vec::new());
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+        let data = [0u8; 4];
+        let large = [0u8; 40000];
+
+        let duplicate = proto.bulk_write(&[(1, 116, &data), (1, 120, &data)]);
+        assert!(matches!(
+            duplicate.unwrap_err().downcast_ref::<ProtocolError>(),
+            Some(ProtocolError::InvalidArg)
+        ));
+        let broadcast = proto.bulk_write(&[(BROADCAST_ID, 116, &data)]);
+        assert!(matches!(
+            broadcast.unwrap_err().downcast_ref::<ProtocolError>(),
+            Some(ProtocolError::InvalidArg)
+        ));
+        let too_long = proto.bulk_write(&[(1, 0, &large), (2, 0, &large)]);
+        assert!(matches!(
+            too_long.unwrap_err().downcast_ref::<ProtocolError>(),
+            Some(ProtocolError::InvalidCount)
+        ));
+        assert!(port.packets().is_empty());
+    }
+
+    #[test]
+    fn scan_reaches_id_253() {
+        // XM430-W350, firmware 45
+        let mut port = MockPort::new(|packet| match split_instruction_v2(packet) {
+            Some((253, OPCODE_PING, _)) => status(253, 0, &[0x06, 0x04, 0x2D]),
+            _ => Vec::new(),
+        });
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        assert_eq!(proto.scan(0, BROADCAST_ID).unwrap(), [253]);
+        // The broadcast id is not pinged
+        assert_eq!(port.packets().len(), 254);
+    }
+
+    #[test]
+    fn stale_bytes_are_dropped() {
+        let mut port = MockPort::new(|packet| match split_instruction_v2(packet) {
+            Some((1, OPCODE_PING, _)) => status(1, 0, &[0x06, 0x04, 0x2D]),
+            Some((1, OPCODE_READ, _)) => status(1, 0, &[0x00, 0x08]),
+            _ => Vec::new(),
+        });
+        // The tail of a status that timed out, then noise
+        port.inject(&[0x00, 0x08, 0xE1, 0x5B, 0xFF, 0xFF]);
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+
+        let ping = proto.ping(1).unwrap();
+        assert_eq!(ping.model, Some(1030));
+        assert_eq!(ping.firmware, Some(45));
+
+        port.inject(&[0xFF, 0xFF, 0xFD, 0x00]);
+        let mut proto = ProtocolV2::new(&mut port, 0, Duration::ZERO, TxOptions::default());
+        assert_eq!(proto.read(1, 132, 2).unwrap(), [0x00, 0x08]);
+        assert_eq!(proto.retry_count(), 0);
+    }
 }
@@ -35,6 +35,112 @@ impl FromStr for ProtocolVersion {
     }
 }
 
+/// Error byte of a status packet. Protocol 1 reports a set of flags,
+/// protocol 2 an error number plus an alert bit that points at the
+/// HardwareErrorStatus register.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusByte {
+    pub version: ProtocolVersion,
+    pub value: u8,
+}
+
+static V1_STATUS_FLAGS: &[(u8, &str)] = &[
+    (0x01, "input voltage"),
+    (0x02, "angle limit"),
+    (0x04, "overheat"),
+    (0x08, "range"),
+    (0x10, "checksum"),
+    (0x20, "overload"),
+    (0x40, "instruction"),
+];
+
+static V2_STATUS_ERRORS: &[&str] = &[
+    "result fail",
+    "instruction",
+    "crc",
+    "data range",
+    "data length",
+    "data limit",
+    "access",
+];
+
+static HARDWARE_ERROR_FLAGS: &[(u8, &str)] = &[
+    (0x01, "input voltage"),
+    (0x04, "overheat"),
+    (0x08, "motor encoder"),
+    (0x10, "electrical shock"),
+    (0x20, "overload"),
+];
+
+impl StatusByte {
+    /// Protocol 2 alert bit, the device has errors in HardwareErrorStatus
+    pub fn is_alert(&self) -> bool {
+        self.version == ProtocolVersion::V2 && self.value & 0x80 != 0
+    }
+
+    /// Names of the reported errors
+    pub fn errors(&self) -> Vec<&'static str> {
+        match self.version {
+            ProtocolVersion::V1 => V1_STATUS_FLAGS
+                .iter()
+                .filter(|(bit, _)| self.value & bit != 0)
+                .map(|(_, name)| *name)
+                .collect(),
+            ProtocolVersion::V2 => {
+                let mut errors = Vec::new();
+                if let Some(name) = (self.value & 0x7F)
+                    .checked_sub(1)
+                    .and_then(|i| V2_STATUS_ERRORS.get(i as usize))
+                {
+                    errors.push(*name);
+                }
+                if self.value & 0x80 != 0 {
+                    errors.push("hardware alert");
+                }
+                errors
+            }
+        }
+    }
+}
+
+impl Display for StatusByte {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors = self.errors();
+        if errors.is_empty() {
+            write!(f, "{:#04X}", self.value)
+        } else {
+            write!(f, "{:#04X} ({})", self.value, errors.join(" | "))
+        }
+    }
+}
+
+/// Value of the HardwareErrorStatus register of protocol 2 devices, the
+/// errors behind the alert bit of their status
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HardwareErrorStatus(pub u8);
+
+impl HardwareErrorStatus {
+    /// Names of the reported errors
+    pub fn errors(&self) -> Vec<&'static str> {
+        HARDWARE_ERROR_FLAGS
+            .iter()
+            .filter(|(bit, _)| self.0 & bit != 0)
+            .map(|(_, name)| *name)
+            .collect()
+    }
+}
+
+impl Display for HardwareErrorStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors = self.errors();
+        if errors.is_empty() {
+            write!(f, "{:#04X}", self.0)
+        } else {
+            write!(f, "{:#04X} ({})", self.0, errors.join(" | "))
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ProtocolError {
     #[error("corrupted status packet")]
@@ -44,7 +150,7 @@ pub enum ProtocolError {
     #[error("invalid byte count for chosen protocol")]
     InvalidCount,
     #[error("dynamixel status error {0}")]
-    StatusError(u8),
+    StatusError(StatusByte),
     #[error("operation not supported for this protocol")]
     NotSupported,
     #[error("invalid argument")]
@@ -52,3 +158,61 @@ pub enum ProtocolError {
     #[error("io timed out")]
     TimedOut,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(version: ProtocolVersion, value: u8) -> StatusByte {
+        StatusByte { version, value }
+    }
+
+    #[test]
+    fn v1_status_names_flags() {
+        let status = status(ProtocolVersion::V1, 0x24);
+        assert_eq!(status.errors(), ["overheat", "overload"]);
+        assert!(!status.is_alert());
+        assert_eq!(status.to_string(), "0x24 (overheat | overload)");
+        assert_eq!(
+            ProtocolError::StatusError(status).to_string(),
+            "dynamixel status error 0x24 (overheat | overload)"
+        );
+    }
+
+    #[test]
+    fn v2_status_names_error_number_and_alert() {
+        let status = status(ProtocolVersion::V2, 0x04);
+        assert_eq!(status.errors(), ["data range"]);
+        assert!(!status.is_alert());
+        assert_eq!(status.to_string(), "0x04 (data range)");
+
+        let status = StatusByte {
+            value: 0x87,
+            ..status
+        };
+        assert_eq!(status.errors(), ["access", "hardware alert"]);
+        assert!(status.is_alert());
+        assert_eq!(status.to_string(), "0x87 (access | hardware alert)");
+
+        // Not a flag set in protocol 2
+        let status = StatusByte {
+            value: 0x24,
+            ..status
+        };
+        assert!(status.errors().is_empty());
+        assert_eq!(status.to_string(), "0x24");
+    }
+
+    #[test]
+    fn hardware_error_status_names_flags() {
+        let status = HardwareErrorStatus(0x24);
+        assert_eq!(status.errors(), ["overheat", "overload"]);
+        assert_eq!(status.to_string(), "0x24 (overheat | overload)");
+
+        assert_eq!(
+            HardwareErrorStatus(0x19).to_string(),
+            "0x19 (input voltage | motor encoder | electrical shock)"
+        );
+        assert_eq!(HardwareErrorStatus(0x02).to_string(), "0x02");
+    }
+}
@@ -1,6 +1,8 @@
 mod v1;
 mod v2;
 
+use std::time::Duration;
+
 use async_trait::async_trait;
 use num_derive::{FromPrimitive, ToPrimitive};
 use tokio_serial::SerialStream;
@@ -43,12 +45,18 @@ pub trait AsyncProtocol {
     async fn send_status(&mut self, id: u8, status: u8, params: &[u8]) -> Result<()>;
 }
 
+/// Upper bound of the delay between polls of an idle bus
+const MAX_IDLE_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Create slave protocol instance. `timeout` limits the wait for the rest of
+/// a packet once its first bytes are received.
 pub fn make_async_protocol<'a>(
     version: ProtocolVersion,
     port: &'a mut SerialStream,
+    timeout: Duration,
 ) -> Box<dyn AsyncProtocol + 'a> {
     match version {
-        ProtocolVersion::V1 => Box::new(v1::ProtocolV1::new(port)),
-        ProtocolVersion::V2 => Box::new(v2::ProtocolV2::new(port)),
+        ProtocolVersion::V1 => Box::new(v1::ProtocolV1::new(port, timeout)),
+        ProtocolVersion::V2 => Box::new(v2::ProtocolV2::new(port, timeout)),
     }
 }
@@ -5,23 +5,28 @@ use crc::{self, Crc, CRC_16_UMTS};
 use itertools::enumerate;
 use log::debug;
 use num_traits::FromPrimitive;
-use tokio::{io::AsyncReadExt, time::timeout};
+use tokio::{
+    io::AsyncReadExt,
+    time::{sleep, timeout},
+};
 use tokio_serial::SerialStream;
 
-use super::{AsyncProtocol, Opcode, RawInstruction};
-use crate::protocol::{ProtocolVersion::V2, Result, ProtocolError};
+use super::{AsyncProtocol, Opcode, RawInstruction, MAX_IDLE_BACKOFF};
+use crate::protocol::{ProtocolError, ProtocolVersion::V2, Result};
 
 pub struct ProtocolV2<'a> {
     port: &'a mut SerialStream,
     deq: VecDeque<u8>,
+    timeout: Duration,
     buf: [u8; 65536],
 }
 
 impl<'a> ProtocolV2<'a> {
-    pub fn new(port: &'a mut SerialStream) -> Self {
+    pub fn new(port: &'a mut SerialStream, timeout: Duration) -> Self {
         Self {
             port,
             deq: VecDeque::new(),
+            timeout,
             buf: [0u8; 65536],
         }
     }
@@ -33,7 +38,7 @@ impl<'a> ProtocolV2<'a> {
         let to_read = n - self.deq.len();
         let buf = &mut self.buf[0..to_read];
 
-        let res = timeout(Duration::from_millis(100), self.port.read(buf)).await;
+        let res = timeout(self.timeout, self.port.read(buf)).await;
 
         match res {
             Ok(Ok(bytes_read)) if bytes_read == to_read => {
@@ -47,13 +52,23 @@ impl<'a> ProtocolV2<'a> {
             }
         }
     }
+
+    /// Wait until `n` bytes are buffered, backing off while the bus is idle
+    async fn wait_buffer(&mut self, n: usize) {
+        let mut backoff = Duration::from_millis(1);
+
+        while self.ensure_buffer(n).await.is_err() {
+            sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_IDLE_BACKOFF);
+        }
+    }
 }
 
 #[async_trait]
 impl<'a> AsyncProtocol for ProtocolV2<'a> {
     async fn recv_instruction(&mut self) -> Result<RawInstruction> {
         loop {
-            while self.ensure_buffer(7).await.is_err() {}
+            self.wait_buffer(7).await;
             debug!("recv loop start");
 
             if self.deq[0] != 0xFF {
@@ -1,5 +1,9 @@
 use super::{Access::*, ProtocolVersion::*, Reg, RegSize::*};
 
+// Degrees per position tick
+const AX_DEG: f64 = 300.0 / 1024.0;
+const MX_DEG: f64 = 360.0 / 4096.0;
+
 pub static REGS: &[Reg] = &[
     // AX-12A 1
     Reg::new("AX-12A", V1, "ModelNumber", 0, Half, R),
@@ -7,11 +11,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("AX-12A", V1, "ID", 3, Byte, RW),
     Reg::new("AX-12A", V1, "BaudRate", 4, Byte, RW),
     Reg::new("AX-12A", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("AX-12A", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("AX-12A", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("AX-12A", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("AX-12A", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("AX-12A", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("AX-12A", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("AX-12A", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("AX-12A", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("AX-12A", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("AX-12A", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("AX-12A", V1, "MaxTorque", 14, Half, RW),
     Reg::new("AX-12A", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("AX-12A", V1, "AlarmLED", 17, Byte, RW),
@@ -22,14 +26,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("AX-12A", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("AX-12A", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("AX-12A", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("AX-12A", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("AX-12A", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("AX-12A", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("AX-12A", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("AX-12A", V1, "PresentPosition", 36, Half, R),
+    Reg::new("AX-12A", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("AX-12A", V1, "PresentSpeed", 38, Half, R),
     Reg::new("AX-12A", V1, "PresentLoad", 40, Half, R),
-    Reg::new("AX-12A", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("AX-12A", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("AX-12A", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("AX-12A", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("AX-12A", V1, "Registered", 44, Byte, R),
     Reg::new("AX-12A", V1, "Moving", 46, Byte, R),
     Reg::new("AX-12A", V1, "Lock", 47, Byte, RW),
@@ -40,11 +44,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("AX-12W", V1, "ID", 3, Byte, RW),
     Reg::new("AX-12W", V1, "BaudRate", 4, Byte, RW),
     Reg::new("AX-12W", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("AX-12W", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("AX-12W", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("AX-12W", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("AX-12W", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("AX-12W", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("AX-12W", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("AX-12W", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("AX-12W", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("AX-12W", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("AX-12W", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("AX-12W", V1, "MaxTorque", 14, Half, RW),
     Reg::new("AX-12W", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("AX-12W", V1, "AlarmLED", 17, Byte, RW),
@@ -55,14 +59,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("AX-12W", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("AX-12W", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("AX-12W", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("AX-12W", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("AX-12W", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("AX-12W", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("AX-12W", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("AX-12W", V1, "PresentPosition", 36, Half, R),
+    Reg::new("AX-12W", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("AX-12W", V1, "PresentSpeed", 38, Half, R),
     Reg::new("AX-12W", V1, "PresentLoad", 40, Half, R),
-    Reg::new("AX-12W", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("AX-12W", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("AX-12W", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("AX-12W", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("AX-12W", V1, "Registered", 44, Byte, R),
     Reg::new("AX-12W", V1, "Moving", 46, Byte, R),
     Reg::new("AX-12W", V1, "Lock", 47, Byte, RW),
@@ -73,11 +77,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("AX-18A", V1, "ID", 3, Byte, RW),
     Reg::new("AX-18A", V1, "BaudRate", 4, Byte, RW),
     Reg::new("AX-18A", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("AX-18A", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("AX-18A", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("AX-18A", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("AX-18A", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("AX-18A", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("AX-18A", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("AX-18A", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("AX-18A", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("AX-18A", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("AX-18A", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("AX-18A", V1, "MaxTorque", 14, Half, RW),
     Reg::new("AX-18A", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("AX-18A", V1, "AlarmLED", 17, Byte, RW),
@@ -88,14 +92,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("AX-18A", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("AX-18A", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("AX-18A", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("AX-18A", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("AX-18A", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("AX-18A", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("AX-18A", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("AX-18A", V1, "PresentPosition", 36, Half, R),
+    Reg::new("AX-18A", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("AX-18A", V1, "PresentSpeed", 38, Half, R),
     Reg::new("AX-18A", V1, "PresentLoad", 40, Half, R),
-    Reg::new("AX-18A", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("AX-18A", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("AX-18A", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("AX-18A", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("AX-18A", V1, "Registered", 44, Byte, R),
     Reg::new("AX-18A", V1, "Moving", 46, Byte, R),
     Reg::new("AX-18A", V1, "Lock", 47, Byte, RW),
@@ -106,11 +110,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("DX-113", V1, "ID", 3, Byte, RW),
     Reg::new("DX-113", V1, "BaudRate", 4, Byte, RW),
     Reg::new("DX-113", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("DX-113", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("DX-113", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("DX-113", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("DX-113", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("DX-113", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("DX-113", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("DX-113", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("DX-113", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("DX-113", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("DX-113", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("DX-113", V1, "MaxTorque", 14, Half, RW),
     Reg::new("DX-113", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("DX-113", V1, "AlarmLED", 17, Byte, RW),
@@ -121,14 +125,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("DX-113", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("DX-113", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("DX-113", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("DX-113", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("DX-113", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("DX-113", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("DX-113", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("DX-113", V1, "PresentPosition", 36, Half, R),
+    Reg::new("DX-113", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("DX-113", V1, "PresentSpeed", 38, Half, R),
     Reg::new("DX-113", V1, "PresentLoad", 40, Half, R),
-    Reg::new("DX-113", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("DX-113", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("DX-113", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("DX-113", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("DX-113", V1, "Registered", 44, Byte, R),
     Reg::new("DX-113", V1, "Moving", 46, Byte, R),
     Reg::new("DX-113", V1, "Lock", 47, Byte, RW),
@@ -139,11 +143,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("DX-116", V1, "ID", 3, Byte, RW),
     Reg::new("DX-116", V1, "BaudRate", 4, Byte, RW),
     Reg::new("DX-116", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("DX-116", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("DX-116", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("DX-116", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("DX-116", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("DX-116", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("DX-116", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("DX-116", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("DX-116", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("DX-116", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("DX-116", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("DX-116", V1, "MaxTorque", 14, Half, RW),
     Reg::new("DX-116", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("DX-116", V1, "AlarmLED", 17, Byte, RW),
@@ -154,14 +158,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("DX-116", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("DX-116", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("DX-116", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("DX-116", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("DX-116", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("DX-116", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("DX-116", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("DX-116", V1, "PresentPosition", 36, Half, R),
+    Reg::new("DX-116", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("DX-116", V1, "PresentSpeed", 38, Half, R),
     Reg::new("DX-116", V1, "PresentLoad", 40, Half, R),
-    Reg::new("DX-116", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("DX-116", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("DX-116", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("DX-116", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("DX-116", V1, "Registered", 44, Byte, R),
     Reg::new("DX-116", V1, "Moving", 46, Byte, R),
     Reg::new("DX-116", V1, "Lock", 47, Byte, RW),
@@ -172,11 +176,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("DX-117", V1, "ID", 3, Byte, RW),
     Reg::new("DX-117", V1, "BaudRate", 4, Byte, RW),
     Reg::new("DX-117", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("DX-117", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("DX-117", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("DX-117", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("DX-117", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("DX-117", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("DX-117", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("DX-117", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("DX-117", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("DX-117", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("DX-117", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("DX-117", V1, "MaxTorque", 14, Half, RW),
     Reg::new("DX-117", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("DX-117", V1, "AlarmLED", 17, Byte, RW),
@@ -187,14 +191,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("DX-117", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("DX-117", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("DX-117", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("DX-117", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("DX-117", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("DX-117", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("DX-117", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("DX-117", V1, "PresentPosition", 36, Half, R),
+    Reg::new("DX-117", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("DX-117", V1, "PresentSpeed", 38, Half, R),
     Reg::new("DX-117", V1, "PresentLoad", 40, Half, R),
-    Reg::new("DX-117", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("DX-117", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("DX-117", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("DX-117", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("DX-117", V1, "Registered", 44, Byte, R),
     Reg::new("DX-117", V1, "Moving", 46, Byte, R),
     Reg::new("DX-117", V1, "Lock", 47, Byte, RW),
@@ -205,12 +209,12 @@ pub static REGS: &[Reg] = &[
     Reg::new("EX-106+", V1, "ID", 3, Byte, RW),
     Reg::new("EX-106+", V1, "BaudRate", 4, Byte, RW),
     Reg::new("EX-106+", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("EX-106+", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("EX-106+", V1, "CCWAngleLimit", 8, Half, RW),
+    Reg::new("EX-106+", V1, "CWAngleLimit", 6, Half, RW).unit("deg", 250.92 / 4096.0, 0.0),
+    Reg::new("EX-106+", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", 250.92 / 4096.0, 0.0),
     Reg::new("EX-106+", V1, "DriveMode", 10, Byte, RW),
-    Reg::new("EX-106+", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("EX-106+", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("EX-106+", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("EX-106+", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("EX-106+", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("EX-106+", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("EX-106+", V1, "MaxTorque", 14, Half, RW),
     Reg::new("EX-106+", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("EX-106+", V1, "AlarmLED", 17, Byte, RW),
@@ -221,14 +225,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("EX-106+", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("EX-106+", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("EX-106+", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("EX-106+", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("EX-106+", V1, "GoalPosition", 30, Half, RW).unit("deg", 250.92 / 4096.0, 0.0),
     Reg::new("EX-106+", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("EX-106+", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("EX-106+", V1, "PresentPosition", 36, Half, R),
+    Reg::new("EX-106+", V1, "PresentPosition", 36, Half, R).unit("deg", 250.92 / 4096.0, 0.0),
     Reg::new("EX-106+", V1, "PresentSpeed", 38, Half, R),
     Reg::new("EX-106+", V1, "PresentLoad", 40, Half, R),
-    Reg::new("EX-106+", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("EX-106+", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("EX-106+", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("EX-106+", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("EX-106+", V1, "Registered", 44, Byte, R),
     Reg::new("EX-106+", V1, "Moving", 46, Byte, R),
     Reg::new("EX-106+", V1, "Lock", 47, Byte, RW),
@@ -245,17 +249,19 @@ pub static REGS: &[Reg] = &[
     Reg::new("MX-106", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("MX-106", V2, "Secondary", 12, Byte, RW),
     Reg::new("MX-106", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("MX-106", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("MX-106", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-106", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("MX-106", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("MX-106", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("MX-106", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("MX-106", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("MX-106", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("MX-106", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("MX-106", V2, "PWMLimit", 36, Half, RW),
     Reg::new("MX-106", V2, "CurrentLimit", 38, Half, RW),
     Reg::new("MX-106", V2, "AccelerationLimit", 40, Word, RW),
-    Reg::new("MX-106", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("MX-106", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("MX-106", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("MX-106", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("MX-106", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-106", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-106", V2, "Shutdown", 63, Byte, RW),
     Reg::new("MX-106", V2, "TorqueEnable", 64, Byte, RW),
     Reg::new("MX-106", V2, "LED", 65, Byte, RW),
@@ -270,54 +276,64 @@ pub static REGS: &[Reg] = &[
     Reg::new("MX-106", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("MX-106", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("MX-106", V2, "BUSWatchdog", 98, Byte, RW),
-    Reg::new("MX-106", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("MX-106", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("MX-106", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("MX-106", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("MX-106", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("MX-106", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("MX-106", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("MX-106", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("MX-106", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("MX-106", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-106", V2, "RealtimeTick", 120, Half, R),
     Reg::new("MX-106", V2, "Moving", 122, Byte, R),
     Reg::new("MX-106", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("MX-106", V2, "PresentPWM", 124, Half, R),
-    Reg::new("MX-106", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("MX-106", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("MX-106", V2, "PresentPosition", 132, Word, R),
-    Reg::new("MX-106", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("MX-106", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("MX-106", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("MX-106", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("MX-106", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("MX-106", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("MX-106", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("MX-106", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-106", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("MX-106", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("MX-106", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("MX-106", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     // MX-106 1
     Reg::new("MX-106", V1, "ModelNumber", 0, Half, R),
     Reg::new("MX-106", V1, "FirmwareVersion", 2, Byte, R),
     Reg::new("MX-106", V1, "ID", 3, Byte, RW),
     Reg::new("MX-106", V1, "BaudRate", 4, Byte, RW),
     Reg::new("MX-106", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("MX-106", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("MX-106", V1, "CCWAngleLimit", 8, Half, RW),
+    Reg::new("MX-106", V1, "CWAngleLimit", 6, Half, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-106", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-106", V1, "DriveMode", 10, Byte, RW),
-    Reg::new("MX-106", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("MX-106", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("MX-106", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("MX-106", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("MX-106", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("MX-106", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("MX-106", V1, "MaxTorque", 14, Half, RW),
     Reg::new("MX-106", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("MX-106", V1, "AlarmLED", 17, Byte, RW),
     Reg::new("MX-106", V1, "Shutdown", 18, Byte, RW),
-    Reg::new("MX-106", V1, "MultiTurnOffset", 20, Half, RW),
+    Reg::new("MX-106", V1, "MultiTurnOffset", 20, Half, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-106", V1, "ResolutionDivider", 22, Byte, RW),
     Reg::new("MX-106", V1, "TorqueEnable", 24, Byte, RW),
     Reg::new("MX-106", V1, "LED", 25, Byte, RW),
     Reg::new("MX-106", V1, "DGain", 26, Byte, RW),
     Reg::new("MX-106", V1, "IGain", 27, Byte, RW),
     Reg::new("MX-106", V1, "PGain", 28, Byte, RW),
-    Reg::new("MX-106", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("MX-106", V1, "GoalPosition", 30, Half, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-106", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("MX-106", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("MX-106", V1, "PresentPosition", 36, Half, R),
+    Reg::new("MX-106", V1, "PresentPosition", 36, Half, R).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-106", V1, "PresentSpeed", 38, Half, R),
     Reg::new("MX-106", V1, "PresentLoad", 40, Half, R),
-    Reg::new("MX-106", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("MX-106", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("MX-106", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("MX-106", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("MX-106", V1, "Registered", 44, Byte, R),
     Reg::new("MX-106", V1, "Moving", 46, Byte, R),
     Reg::new("MX-106", V1, "Lock", 47, Byte, RW),
@@ -333,30 +349,32 @@ pub static REGS: &[Reg] = &[
     Reg::new("MX-12W", V1, "ID", 3, Byte, RW),
     Reg::new("MX-12W", V1, "BaudRate", 4, Byte, RW),
     Reg::new("MX-12W", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("MX-12W", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("MX-12W", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("MX-12W", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("MX-12W", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("MX-12W", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("MX-12W", V1, "CWAngleLimit", 6, Half, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-12W", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-12W", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("MX-12W", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("MX-12W", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("MX-12W", V1, "MaxTorque", 14, Half, RW),
     Reg::new("MX-12W", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("MX-12W", V1, "AlarmLED", 17, Byte, RW),
     Reg::new("MX-12W", V1, "Shutdown", 18, Byte, RW),
-    Reg::new("MX-12W", V1, "MultiTurnOffset", 20, Half, RW),
+    Reg::new("MX-12W", V1, "MultiTurnOffset", 20, Half, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-12W", V1, "ResolutionDivider", 22, Byte, RW),
     Reg::new("MX-12W", V1, "TorqueEnable", 24, Byte, RW),
     Reg::new("MX-12W", V1, "LED", 25, Byte, RW),
     Reg::new("MX-12W", V1, "DGain", 26, Byte, RW),
     Reg::new("MX-12W", V1, "IGain", 27, Byte, RW),
     Reg::new("MX-12W", V1, "PGain", 28, Byte, RW),
-    Reg::new("MX-12W", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("MX-12W", V1, "GoalPosition", 30, Half, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-12W", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("MX-12W", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("MX-12W", V1, "PresentPosition", 36, Half, R),
+    Reg::new("MX-12W", V1, "PresentPosition", 36, Half, R).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-12W", V1, "PresentSpeed", 38, Half, R),
     Reg::new("MX-12W", V1, "PresentLoad", 40, Half, R),
-    Reg::new("MX-12W", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("MX-12W", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("MX-12W", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("MX-12W", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("MX-12W", V1, "Registered", 44, Byte, R),
     Reg::new("MX-12W", V1, "Moving", 46, Byte, R),
     Reg::new("MX-12W", V1, "Lock", 47, Byte, RW),
@@ -374,16 +392,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("MX-28", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("MX-28", V2, "Secondary", 12, Byte, RW),
     Reg::new("MX-28", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("MX-28", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("MX-28", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-28", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("MX-28", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("MX-28", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("MX-28", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("MX-28", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("MX-28", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("MX-28", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("MX-28", V2, "PWMLimit", 36, Half, RW),
     Reg::new("MX-28", V2, "AccelerationLimit", 40, Word, RW),
-    Reg::new("MX-28", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("MX-28", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("MX-28", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("MX-28", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("MX-28", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-28", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-28", V2, "Shutdown", 63, Byte, RW),
     Reg::new("MX-28", V2, "TorqueEnable", 64, Byte, RW),
     Reg::new("MX-28", V2, "LED", 65, Byte, RW),
@@ -398,52 +418,62 @@ pub static REGS: &[Reg] = &[
     Reg::new("MX-28", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("MX-28", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("MX-28", V2, "BUSWatchdog", 98, Byte, RW),
-    Reg::new("MX-28", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("MX-28", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("MX-28", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("MX-28", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("MX-28", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("MX-28", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("MX-28", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("MX-28", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-28", V2, "RealtimeTick", 120, Half, R),
     Reg::new("MX-28", V2, "Moving", 122, Byte, R),
     Reg::new("MX-28", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("MX-28", V2, "PresentPWM", 124, Half, R),
-    Reg::new("MX-28", V2, "PresentLoad", 126, Half, R),
-    Reg::new("MX-28", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("MX-28", V2, "PresentPosition", 132, Word, R),
-    Reg::new("MX-28", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("MX-28", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("MX-28", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("MX-28", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("MX-28", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("MX-28", V2, "PresentLoad", 126, Half, R).signed(),
+    Reg::new("MX-28", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("MX-28", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-28", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("MX-28", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("MX-28", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("MX-28", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     // MX-28 1
     Reg::new("MX-28", V1, "ModelNumber", 0, Half, R),
     Reg::new("MX-28", V1, "FirmwareVersion", 2, Byte, R),
     Reg::new("MX-28", V1, "ID", 3, Byte, RW),
     Reg::new("MX-28", V1, "BaudRate", 4, Byte, RW),
     Reg::new("MX-28", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("MX-28", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("MX-28", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("MX-28", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("MX-28", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("MX-28", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("MX-28", V1, "CWAngleLimit", 6, Half, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-28", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-28", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("MX-28", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("MX-28", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("MX-28", V1, "MaxTorque", 14, Half, RW),
     Reg::new("MX-28", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("MX-28", V1, "AlarmLED", 17, Byte, RW),
     Reg::new("MX-28", V1, "Shutdown", 18, Byte, RW),
-    Reg::new("MX-28", V1, "MultiTurnOffset", 20, Half, RW),
+    Reg::new("MX-28", V1, "MultiTurnOffset", 20, Half, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-28", V1, "ResolutionDivider", 22, Byte, RW),
     Reg::new("MX-28", V1, "TorqueEnable", 24, Byte, RW),
     Reg::new("MX-28", V1, "LED", 25, Byte, RW),
     Reg::new("MX-28", V1, "DGain", 26, Byte, RW),
     Reg::new("MX-28", V1, "IGain", 27, Byte, RW),
     Reg::new("MX-28", V1, "PGain", 28, Byte, RW),
-    Reg::new("MX-28", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("MX-28", V1, "GoalPosition", 30, Half, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-28", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("MX-28", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("MX-28", V1, "PresentPosition", 36, Half, R),
+    Reg::new("MX-28", V1, "PresentPosition", 36, Half, R).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-28", V1, "PresentSpeed", 38, Half, R),
     Reg::new("MX-28", V1, "PresentLoad", 40, Half, R),
-    Reg::new("MX-28", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("MX-28", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("MX-28", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("MX-28", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("MX-28", V1, "Registered", 44, Byte, R),
     Reg::new("MX-28", V1, "Moving", 46, Byte, R),
     Reg::new("MX-28", V1, "Lock", 47, Byte, RW),
@@ -461,17 +491,19 @@ pub static REGS: &[Reg] = &[
     Reg::new("MX-64", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("MX-64", V2, "Secondary", 12, Byte, RW),
     Reg::new("MX-64", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("MX-64", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("MX-64", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-64", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("MX-64", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("MX-64", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("MX-64", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("MX-64", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("MX-64", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("MX-64", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("MX-64", V2, "PWMLimit", 36, Half, RW),
     Reg::new("MX-64", V2, "CurrentLimit", 38, Half, RW),
     Reg::new("MX-64", V2, "AccelerationLimit", 40, Word, RW),
-    Reg::new("MX-64", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("MX-64", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("MX-64", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("MX-64", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("MX-64", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-64", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-64", V2, "Shutdown", 63, Byte, RW),
     Reg::new("MX-64", V2, "TorqueEnable", 64, Byte, RW),
     Reg::new("MX-64", V2, "LED", 65, Byte, RW),
@@ -486,53 +518,63 @@ pub static REGS: &[Reg] = &[
     Reg::new("MX-64", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("MX-64", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("MX-64", V2, "BUSWatchdog", 98, Byte, RW),
-    Reg::new("MX-64", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("MX-64", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("MX-64", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("MX-64", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("MX-64", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("MX-64", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("MX-64", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("MX-64", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("MX-64", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("MX-64", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-64", V2, "RealtimeTick", 120, Half, R),
     Reg::new("MX-64", V2, "Moving", 122, Byte, R),
     Reg::new("MX-64", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("MX-64", V2, "PresentPWM", 124, Half, R),
-    Reg::new("MX-64", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("MX-64", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("MX-64", V2, "PresentPosition", 132, Word, R),
-    Reg::new("MX-64", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("MX-64", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("MX-64", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("MX-64", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("MX-64", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("MX-64", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("MX-64", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("MX-64", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-64", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("MX-64", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("MX-64", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("MX-64", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     // MX-64 1
     Reg::new("MX-64", V1, "ModelNumber", 0, Half, R),
     Reg::new("MX-64", V1, "FirmwareVersion", 2, Byte, R),
     Reg::new("MX-64", V1, "ID", 3, Byte, RW),
     Reg::new("MX-64", V1, "BaudRate", 4, Byte, RW),
     Reg::new("MX-64", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("MX-64", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("MX-64", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("MX-64", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("MX-64", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("MX-64", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("MX-64", V1, "CWAngleLimit", 6, Half, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-64", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("MX-64", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("MX-64", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("MX-64", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("MX-64", V1, "MaxTorque", 14, Half, RW),
     Reg::new("MX-64", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("MX-64", V1, "AlarmLED", 17, Byte, RW),
     Reg::new("MX-64", V1, "Shutdown", 18, Byte, RW),
-    Reg::new("MX-64", V1, "MultiTurnOffset", 20, Half, RW),
+    Reg::new("MX-64", V1, "MultiTurnOffset", 20, Half, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("MX-64", V1, "ResolutionDivider", 22, Byte, RW),
     Reg::new("MX-64", V1, "TorqueEnable", 24, Byte, RW),
     Reg::new("MX-64", V1, "LED", 25, Byte, RW),
     Reg::new("MX-64", V1, "DGain", 26, Byte, RW),
     Reg::new("MX-64", V1, "IGain", 27, Byte, RW),
     Reg::new("MX-64", V1, "PGain", 28, Byte, RW),
-    Reg::new("MX-64", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("MX-64", V1, "GoalPosition", 30, Half, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-64", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("MX-64", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("MX-64", V1, "PresentPosition", 36, Half, R),
+    Reg::new("MX-64", V1, "PresentPosition", 36, Half, R).unit("deg", MX_DEG, 0.0),
     Reg::new("MX-64", V1, "PresentSpeed", 38, Half, R),
     Reg::new("MX-64", V1, "PresentLoad", 40, Half, R),
-    Reg::new("MX-64", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("MX-64", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("MX-64", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("MX-64", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("MX-64", V1, "Registered", 44, Byte, R),
     Reg::new("MX-64", V1, "Moving", 46, Byte, R),
     Reg::new("MX-64", V1, "Lock", 47, Byte, RW),
@@ -553,17 +595,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PH42-020-S300-R", V1, "OperatingMode", 11, Byte, RW),
     Reg::new("PH42-020-S300-R", V1, "Secondary", 12, Byte, RW),
     Reg::new("PH42-020-S300-R", V1, "ProtocolType", 13, Byte, RW),
-    Reg::new("PH42-020-S300-R", V1, "HomingOffset", 20, Word, RW),
+    Reg::new("PH42-020-S300-R", V1, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 607500.0, 0.0),
     Reg::new("PH42-020-S300-R", V1, "MovingThreshold", 24, Word, RW),
-    Reg::new("PH42-020-S300-R", V1, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("PH42-020-S300-R", V1, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("PH42-020-S300-R", V1, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("PH42-020-S300-R", V1, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("PH42-020-S300-R", V1, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("PH42-020-S300-R", V1, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("PH42-020-S300-R", V1, "PWMLimit", 36, Half, RW),
     Reg::new("PH42-020-S300-R", V1, "CurrentLimit", 38, Half, RW),
     Reg::new("PH42-020-S300-R", V1, "AccelerationLimit", 40, Word, RW),
     Reg::new("PH42-020-S300-R", V1, "VelocityLimit", 44, Word, RW),
-    Reg::new("PH42-020-S300-R", V1, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("PH42-020-S300-R", V1, "MinPositionLimit", 52, Word, RW),
+    Reg::new("PH42-020-S300-R", V1, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 607500.0,
+        0.0,
+    ),
+    Reg::new("PH42-020-S300-R", V1, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 607500.0,
+        0.0,
+    ),
     Reg::new("PH42-020-S300-R", V1, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("PH42-020-S300-R", V1, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("PH42-020-S300-R", V1, "ExternalPortMode3", 58, Byte, RW),
@@ -585,23 +637,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PH42-020-S300-R", V1, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("PH42-020-S300-R", V1, "Feedforward1stGain", 538, Half, RW),
     Reg::new("PH42-020-S300-R", V1, "BusWatchdog", 546, Byte, RW),
-    Reg::new("PH42-020-S300-R", V1, "GoalPWM", 548, Half, RW),
-    Reg::new("PH42-020-S300-R", V1, "GoalCurrent", 550, Half, RW),
-    Reg::new("PH42-020-S300-R", V1, "GoalVelocity", 552, Word, RW),
+    Reg::new("PH42-020-S300-R", V1, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("PH42-020-S300-R", V1, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("PH42-020-S300-R", V1, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("PH42-020-S300-R", V1, "ProfileAcceleration", 556, Word, RW),
     Reg::new("PH42-020-S300-R", V1, "ProfileVelocity", 560, Word, RW),
-    Reg::new("PH42-020-S300-R", V1, "GoalPosition", 564, Word, RW),
+    Reg::new("PH42-020-S300-R", V1, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 607500.0, 0.0),
     Reg::new("PH42-020-S300-R", V1, "RealtimeTick", 568, Half, R),
     Reg::new("PH42-020-S300-R", V1, "Moving", 570, Byte, R),
     Reg::new("PH42-020-S300-R", V1, "MovingStatus", 571, Byte, R),
-    Reg::new("PH42-020-S300-R", V1, "PresentPWM", 572, Half, R),
-    Reg::new("PH42-020-S300-R", V1, "PresentCurrent", 574, Half, R),
-    Reg::new("PH42-020-S300-R", V1, "PresentVelocity", 576, Word, R),
-    Reg::new("PH42-020-S300-R", V1, "PresentPosition", 580, Word, R),
-    Reg::new("PH42-020-S300-R", V1, "VelocityTrajectory", 584, Word, R),
-    Reg::new("PH42-020-S300-R", V1, "PositionTrajectory", 588, Word, R),
-    Reg::new("PH42-020-S300-R", V1, "PresentInputVoltage", 592, Half, R),
-    Reg::new("PH42-020-S300-R", V1, "PresentTemperature", 594, Byte, R),
+    Reg::new("PH42-020-S300-R", V1, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("PH42-020-S300-R", V1, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("PH42-020-S300-R", V1, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("PH42-020-S300-R", V1, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 607500.0, 0.0),
+    Reg::new("PH42-020-S300-R", V1, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("PH42-020-S300-R", V1, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("PH42-020-S300-R", V1, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("PH42-020-S300-R", V1, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("PH42-020-S300-R", V1, "ExternalPortData1", 600, Half, RW),
     Reg::new("PH42-020-S300-R", V1, "ExternalPortData2", 602, Half, RW),
     Reg::new("PH42-020-S300-R", V1, "ExternalPortData3", 604, Half, RW),
@@ -618,17 +674,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PH54-100-S500-R", V1, "OperatingMode", 11, Byte, RW),
     Reg::new("PH54-100-S500-R", V1, "ProtocolType", 13, Byte, RW),
     Reg::new("PH54-100-S500-R", V1, "Secondary", 12, Byte, RW),
-    Reg::new("PH54-100-S500-R", V1, "HomingOffset", 20, Word, RW),
+    Reg::new("PH54-100-S500-R", V1, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
     Reg::new("PH54-100-S500-R", V1, "MovingThreshold", 24, Word, RW),
-    Reg::new("PH54-100-S500-R", V1, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("PH54-100-S500-R", V1, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("PH54-100-S500-R", V1, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("PH54-100-S500-R", V1, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("PH54-100-S500-R", V1, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("PH54-100-S500-R", V1, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("PH54-100-S500-R", V1, "PWMLimit", 36, Half, RW),
     Reg::new("PH54-100-S500-R", V1, "CurrentLimit", 38, Half, RW),
     Reg::new("PH54-100-S500-R", V1, "AccelerationLimit", 40, Word, RW),
     Reg::new("PH54-100-S500-R", V1, "VelocityLimit", 44, Word, RW),
-    Reg::new("PH54-100-S500-R", V1, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("PH54-100-S500-R", V1, "MinPositionLimit", 52, Word, RW),
+    Reg::new("PH54-100-S500-R", V1, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 1003846.0,
+        0.0,
+    ),
+    Reg::new("PH54-100-S500-R", V1, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 1003846.0,
+        0.0,
+    ),
     Reg::new("PH54-100-S500-R", V1, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("PH54-100-S500-R", V1, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("PH54-100-S500-R", V1, "ExternalPortMode3", 58, Byte, RW),
@@ -650,23 +716,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PH54-100-S500-R", V1, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("PH54-100-S500-R", V1, "Feedforward1stGain", 538, Half, RW),
     Reg::new("PH54-100-S500-R", V1, "BusWatchdog", 546, Byte, RW),
-    Reg::new("PH54-100-S500-R", V1, "GoalPWM", 548, Half, RW),
-    Reg::new("PH54-100-S500-R", V1, "GoalCurrent", 550, Half, RW),
-    Reg::new("PH54-100-S500-R", V1, "GoalVelocity", 552, Word, RW),
+    Reg::new("PH54-100-S500-R", V1, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("PH54-100-S500-R", V1, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("PH54-100-S500-R", V1, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("PH54-100-S500-R", V1, "ProfileAcceleration", 556, Word, RW),
     Reg::new("PH54-100-S500-R", V1, "ProfileVelocity", 560, Word, RW),
-    Reg::new("PH54-100-S500-R", V1, "GoalPosition", 564, Word, RW),
+    Reg::new("PH54-100-S500-R", V1, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
     Reg::new("PH54-100-S500-R", V1, "RealtimeTick", 568, Half, R),
     Reg::new("PH54-100-S500-R", V1, "Moving", 570, Byte, R),
     Reg::new("PH54-100-S500-R", V1, "MovingStatus", 571, Byte, R),
-    Reg::new("PH54-100-S500-R", V1, "PresentPWM", 572, Half, R),
-    Reg::new("PH54-100-S500-R", V1, "PresentCurrent", 574, Half, R),
-    Reg::new("PH54-100-S500-R", V1, "PresentVelocity", 576, Word, R),
-    Reg::new("PH54-100-S500-R", V1, "PresentPosition", 580, Word, R),
-    Reg::new("PH54-100-S500-R", V1, "VelocityTrajectory", 584, Word, R),
-    Reg::new("PH54-100-S500-R", V1, "PositionTrajectory", 588, Word, R),
-    Reg::new("PH54-100-S500-R", V1, "PresentInputVoltage", 592, Half, R),
-    Reg::new("PH54-100-S500-R", V1, "PresentTemperature", 594, Byte, R),
+    Reg::new("PH54-100-S500-R", V1, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("PH54-100-S500-R", V1, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("PH54-100-S500-R", V1, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("PH54-100-S500-R", V1, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
+    Reg::new("PH54-100-S500-R", V1, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("PH54-100-S500-R", V1, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("PH54-100-S500-R", V1, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("PH54-100-S500-R", V1, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("PH54-100-S500-R", V1, "ExternalPortData1", 600, Half, RW),
     Reg::new("PH54-100-S500-R", V1, "ExternalPortData2", 602, Half, RW),
     Reg::new("PH54-100-S500-R", V1, "ExternalPortData3", 604, Half, RW),
@@ -683,17 +753,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PH54-200-S500-R", V1, "OperatingMode", 11, Byte, RW),
     Reg::new("PH54-200-S500-R", V1, "Secondary", 12, Byte, RW),
     Reg::new("PH54-200-S500-R", V1, "ProtocolType", 13, Byte, RW),
-    Reg::new("PH54-200-S500-R", V1, "HomingOffset", 20, Word, RW),
+    Reg::new("PH54-200-S500-R", V1, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
     Reg::new("PH54-200-S500-R", V1, "MovingThreshold", 24, Word, RW),
-    Reg::new("PH54-200-S500-R", V1, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("PH54-200-S500-R", V1, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("PH54-200-S500-R", V1, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("PH54-200-S500-R", V1, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("PH54-200-S500-R", V1, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("PH54-200-S500-R", V1, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("PH54-200-S500-R", V1, "PWMLimit", 36, Half, RW),
     Reg::new("PH54-200-S500-R", V1, "CurrentLimit", 38, Half, RW),
     Reg::new("PH54-200-S500-R", V1, "AccelerationLimit", 40, Word, RW),
     Reg::new("PH54-200-S500-R", V1, "VelocityLimit", 44, Word, RW),
-    Reg::new("PH54-200-S500-R", V1, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("PH54-200-S500-R", V1, "MinPositionLimit", 52, Word, RW),
+    Reg::new("PH54-200-S500-R", V1, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 1003846.0,
+        0.0,
+    ),
+    Reg::new("PH54-200-S500-R", V1, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 1003846.0,
+        0.0,
+    ),
     Reg::new("PH54-200-S500-R", V1, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("PH54-200-S500-R", V1, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("PH54-200-S500-R", V1, "ExternalPortMode3", 58, Byte, RW),
@@ -715,23 +795,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PH54-200-S500-R", V1, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("PH54-200-S500-R", V1, "Feedforward1stGain", 538, Half, RW),
     Reg::new("PH54-200-S500-R", V1, "BusWatchdog", 546, Byte, RW),
-    Reg::new("PH54-200-S500-R", V1, "GoalPWM", 548, Half, RW),
-    Reg::new("PH54-200-S500-R", V1, "GoalCurrent", 550, Half, RW),
-    Reg::new("PH54-200-S500-R", V1, "GoalVelocity", 552, Word, RW),
+    Reg::new("PH54-200-S500-R", V1, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("PH54-200-S500-R", V1, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("PH54-200-S500-R", V1, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("PH54-200-S500-R", V1, "ProfileAcceleration", 556, Word, RW),
     Reg::new("PH54-200-S500-R", V1, "ProfileVelocity", 560, Word, RW),
-    Reg::new("PH54-200-S500-R", V1, "GoalPosition", 564, Word, RW),
+    Reg::new("PH54-200-S500-R", V1, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
     Reg::new("PH54-200-S500-R", V1, "RealtimeTick", 568, Half, R),
     Reg::new("PH54-200-S500-R", V1, "Moving", 570, Byte, R),
     Reg::new("PH54-200-S500-R", V1, "MovingStatus", 571, Byte, R),
-    Reg::new("PH54-200-S500-R", V1, "PresentPWM", 572, Half, R),
-    Reg::new("PH54-200-S500-R", V1, "PresentCurrent", 574, Half, R),
-    Reg::new("PH54-200-S500-R", V1, "PresentVelocity", 576, Word, R),
-    Reg::new("PH54-200-S500-R", V1, "PresentPosition", 580, Word, R),
-    Reg::new("PH54-200-S500-R", V1, "VelocityTrajectory", 584, Word, R),
-    Reg::new("PH54-200-S500-R", V1, "PositionTrajectory", 588, Word, R),
-    Reg::new("PH54-200-S500-R", V1, "PresentInputVoltage", 592, Half, R),
-    Reg::new("PH54-200-S500-R", V1, "PresentTemperature", 594, Byte, R),
+    Reg::new("PH54-200-S500-R", V1, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("PH54-200-S500-R", V1, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("PH54-200-S500-R", V1, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("PH54-200-S500-R", V1, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
+    Reg::new("PH54-200-S500-R", V1, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("PH54-200-S500-R", V1, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("PH54-200-S500-R", V1, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("PH54-200-S500-R", V1, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("PH54-200-S500-R", V1, "ExternalPortData1", 600, Half, RW),
     Reg::new("PH54-200-S500-R", V1, "ExternalPortData2", 602, Half, RW),
     Reg::new("PH54-200-S500-R", V1, "ExternalPortData3", 604, Half, RW),
@@ -748,17 +832,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PM42-010-S260-R", V1, "OperatingMode", 11, Byte, RW),
     Reg::new("PM42-010-S260-R", V1, "SecondaryID", 12, Byte, RW),
     Reg::new("PM42-010-S260-R", V1, "ProtocolType", 13, Byte, RW),
-    Reg::new("PM42-010-S260-R", V1, "HomingOffset", 20, Word, RW),
+    Reg::new("PM42-010-S260-R", V1, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 526374.0, 0.0),
     Reg::new("PM42-010-S260-R", V1, "MovingThreshold", 24, Word, RW),
-    Reg::new("PM42-010-S260-R", V1, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("PM42-010-S260-R", V1, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("PM42-010-S260-R", V1, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("PM42-010-S260-R", V1, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("PM42-010-S260-R", V1, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("PM42-010-S260-R", V1, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("PM42-010-S260-R", V1, "PWMLimit", 36, Half, RW),
     Reg::new("PM42-010-S260-R", V1, "CurrentLimit", 38, Half, RW),
     Reg::new("PM42-010-S260-R", V1, "AccelerationLimit", 40, Word, RW),
     Reg::new("PM42-010-S260-R", V1, "VelocityLimit", 44, Word, RW),
-    Reg::new("PM42-010-S260-R", V1, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("PM42-010-S260-R", V1, "MinPositionLimit", 52, Word, RW),
+    Reg::new("PM42-010-S260-R", V1, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 526374.0,
+        0.0,
+    ),
+    Reg::new("PM42-010-S260-R", V1, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 526374.0,
+        0.0,
+    ),
     Reg::new("PM42-010-S260-R", V1, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("PM42-010-S260-R", V1, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("PM42-010-S260-R", V1, "ExternalPortMode3", 58, Byte, RW),
@@ -780,23 +874,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PM42-010-S260-R", V1, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("PM42-010-S260-R", V1, "Feedforward1stGain", 538, Half, RW),
     Reg::new("PM42-010-S260-R", V1, "BusWatchdog", 546, Byte, RW),
-    Reg::new("PM42-010-S260-R", V1, "GoalPWM", 548, Half, RW),
-    Reg::new("PM42-010-S260-R", V1, "GoalCurrent", 550, Half, RW),
-    Reg::new("PM42-010-S260-R", V1, "GoalVelocity", 552, Word, RW),
+    Reg::new("PM42-010-S260-R", V1, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("PM42-010-S260-R", V1, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("PM42-010-S260-R", V1, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("PM42-010-S260-R", V1, "ProfileAcceleration", 556, Word, RW),
     Reg::new("PM42-010-S260-R", V1, "ProfileVelocity", 560, Word, RW),
-    Reg::new("PM42-010-S260-R", V1, "GoalPosition", 564, Word, RW),
+    Reg::new("PM42-010-S260-R", V1, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 526374.0, 0.0),
     Reg::new("PM42-010-S260-R", V1, "RealtimeTick", 568, Half, R),
     Reg::new("PM42-010-S260-R", V1, "Moving", 570, Byte, R),
     Reg::new("PM42-010-S260-R", V1, "MovingStatus", 571, Byte, R),
-    Reg::new("PM42-010-S260-R", V1, "PresentPWM", 572, Half, R),
-    Reg::new("PM42-010-S260-R", V1, "PresentCurrent", 574, Half, R),
-    Reg::new("PM42-010-S260-R", V1, "PresentVelocity", 576, Word, R),
-    Reg::new("PM42-010-S260-R", V1, "PresentPosition", 580, Word, R),
-    Reg::new("PM42-010-S260-R", V1, "VelocityTrajectory", 584, Word, R),
-    Reg::new("PM42-010-S260-R", V1, "PositionTrajectory", 588, Word, R),
-    Reg::new("PM42-010-S260-R", V1, "PresentInputVoltage", 592, Half, R),
-    Reg::new("PM42-010-S260-R", V1, "PresentTemperature", 594, Byte, R),
+    Reg::new("PM42-010-S260-R", V1, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("PM42-010-S260-R", V1, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("PM42-010-S260-R", V1, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("PM42-010-S260-R", V1, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 526374.0, 0.0),
+    Reg::new("PM42-010-S260-R", V1, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("PM42-010-S260-R", V1, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("PM42-010-S260-R", V1, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("PM42-010-S260-R", V1, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("PM42-010-S260-R", V1, "ExternalPortData1", 600, Half, RW),
     Reg::new("PM42-010-S260-R", V1, "ExternalPortData2", 602, Half, RW),
     Reg::new("PM42-010-S260-R", V1, "ExternalPortData3", 604, Half, RW),
@@ -813,17 +911,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PM54-040-S250-R", V1, "OperatingMode", 11, Byte, RW),
     Reg::new("PM54-040-S250-R", V1, "SecondaryID", 12, Byte, RW),
     Reg::new("PM54-040-S250-R", V1, "ProtocolType", 13, Byte, RW),
-    Reg::new("PM54-040-S250-R", V1, "HomingOffset", 20, Word, RW),
+    Reg::new("PM54-040-S250-R", V1, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
     Reg::new("PM54-040-S250-R", V1, "MovingThreshold", 24, Word, RW),
-    Reg::new("PM54-040-S250-R", V1, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("PM54-040-S250-R", V1, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("PM54-040-S250-R", V1, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("PM54-040-S250-R", V1, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("PM54-040-S250-R", V1, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("PM54-040-S250-R", V1, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("PM54-040-S250-R", V1, "PWMLimit", 36, Half, RW),
     Reg::new("PM54-040-S250-R", V1, "CurrentLimit", 38, Half, RW),
     Reg::new("PM54-040-S250-R", V1, "AccelerationLimit", 40, Word, RW),
     Reg::new("PM54-040-S250-R", V1, "VelocityLimit", 44, Word, RW),
-    Reg::new("PM54-040-S250-R", V1, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("PM54-040-S250-R", V1, "MinPositionLimit", 52, Word, RW),
+    Reg::new("PM54-040-S250-R", V1, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 1003846.0,
+        0.0,
+    ),
+    Reg::new("PM54-040-S250-R", V1, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 1003846.0,
+        0.0,
+    ),
     Reg::new("PM54-040-S250-R", V1, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("PM54-040-S250-R", V1, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("PM54-040-S250-R", V1, "ExternalPortMode3", 58, Byte, RW),
@@ -845,23 +953,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PM54-040-S250-R", V1, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("PM54-040-S250-R", V1, "Feedforward1stGain", 538, Half, RW),
     Reg::new("PM54-040-S250-R", V1, "BusWatchdog", 546, Byte, RW),
-    Reg::new("PM54-040-S250-R", V1, "GoalPWM", 548, Half, RW),
-    Reg::new("PM54-040-S250-R", V1, "GoalCurrent", 550, Half, RW),
-    Reg::new("PM54-040-S250-R", V1, "GoalVelocity", 552, Word, RW),
+    Reg::new("PM54-040-S250-R", V1, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("PM54-040-S250-R", V1, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("PM54-040-S250-R", V1, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("PM54-040-S250-R", V1, "ProfileAcceleration", 556, Word, RW),
     Reg::new("PM54-040-S250-R", V1, "ProfileVelocity", 560, Word, RW),
-    Reg::new("PM54-040-S250-R", V1, "GoalPosition", 564, Word, RW),
+    Reg::new("PM54-040-S250-R", V1, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
     Reg::new("PM54-040-S250-R", V1, "RealtimeTick", 568, Half, R),
     Reg::new("PM54-040-S250-R", V1, "Moving", 570, Byte, R),
     Reg::new("PM54-040-S250-R", V1, "MovingStatus", 571, Byte, R),
-    Reg::new("PM54-040-S250-R", V1, "PresentPWM", 572, Half, R),
-    Reg::new("PM54-040-S250-R", V1, "PresentCurrent", 574, Half, R),
-    Reg::new("PM54-040-S250-R", V1, "PresentVelocity", 576, Word, R),
-    Reg::new("PM54-040-S250-R", V1, "PresentPosition", 580, Word, R),
-    Reg::new("PM54-040-S250-R", V1, "VelocityTrajectory", 584, Word, R),
-    Reg::new("PM54-040-S250-R", V1, "PositionTrajectory", 588, Word, R),
-    Reg::new("PM54-040-S250-R", V1, "PresentInputVoltage", 592, Half, R),
-    Reg::new("PM54-040-S250-R", V1, "PresentTemperature", 594, Byte, R),
+    Reg::new("PM54-040-S250-R", V1, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("PM54-040-S250-R", V1, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("PM54-040-S250-R", V1, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("PM54-040-S250-R", V1, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
+    Reg::new("PM54-040-S250-R", V1, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("PM54-040-S250-R", V1, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("PM54-040-S250-R", V1, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("PM54-040-S250-R", V1, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("PM54-040-S250-R", V1, "ExternalPortData1", 600, Half, RW),
     Reg::new("PM54-040-S250-R", V1, "ExternalPortData2", 602, Half, RW),
     Reg::new("PM54-040-S250-R", V1, "ExternalPortData3", 604, Half, RW),
@@ -878,17 +990,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PM54-060-S250-R", V1, "OperatingMode", 11, Byte, RW),
     Reg::new("PM54-060-S250-R", V1, "SecondaryID", 12, Byte, RW),
     Reg::new("PM54-060-S250-R", V1, "ProtocolType", 13, Byte, RW),
-    Reg::new("PM54-060-S250-R", V1, "HomingOffset", 20, Word, RW),
+    Reg::new("PM54-060-S250-R", V1, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
     Reg::new("PM54-060-S250-R", V1, "MovingThreshold", 24, Word, RW),
-    Reg::new("PM54-060-S250-R", V1, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("PM54-060-S250-R", V1, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("PM54-060-S250-R", V1, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("PM54-060-S250-R", V1, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("PM54-060-S250-R", V1, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("PM54-060-S250-R", V1, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("PM54-060-S250-R", V1, "PWMLimit", 36, Half, RW),
     Reg::new("PM54-060-S250-R", V1, "CurrentLimit", 38, Half, RW),
     Reg::new("PM54-060-S250-R", V1, "AccelerationLimit", 40, Word, RW),
     Reg::new("PM54-060-S250-R", V1, "VelocityLimit", 44, Word, RW),
-    Reg::new("PM54-060-S250-R", V1, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("PM54-060-S250-R", V1, "MinPositionLimit", 52, Word, RW),
+    Reg::new("PM54-060-S250-R", V1, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 1003846.0,
+        0.0,
+    ),
+    Reg::new("PM54-060-S250-R", V1, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 1003846.0,
+        0.0,
+    ),
     Reg::new("PM54-060-S250-R", V1, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("PM54-060-S250-R", V1, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("PM54-060-S250-R", V1, "ExternalPortMode3", 58, Byte, RW),
@@ -910,23 +1032,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("PM54-060-S250-R", V1, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("PM54-060-S250-R", V1, "Feedforward1stGain", 538, Half, RW),
     Reg::new("PM54-060-S250-R", V1, "BusWatchdog", 546, Byte, RW),
-    Reg::new("PM54-060-S250-R", V1, "GoalPWM", 548, Half, RW),
-    Reg::new("PM54-060-S250-R", V1, "GoalCurrent", 550, Half, RW),
-    Reg::new("PM54-060-S250-R", V1, "GoalVelocity", 552, Word, RW),
+    Reg::new("PM54-060-S250-R", V1, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("PM54-060-S250-R", V1, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("PM54-060-S250-R", V1, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("PM54-060-S250-R", V1, "ProfileAcceleration", 556, Word, RW),
     Reg::new("PM54-060-S250-R", V1, "ProfileVelocity", 560, Word, RW),
-    Reg::new("PM54-060-S250-R", V1, "GoalPosition", 564, Word, RW),
+    Reg::new("PM54-060-S250-R", V1, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
     Reg::new("PM54-060-S250-R", V1, "RealtimeTick", 568, Half, R),
     Reg::new("PM54-060-S250-R", V1, "Moving", 570, Byte, R),
     Reg::new("PM54-060-S250-R", V1, "MovingStatus", 571, Byte, R),
-    Reg::new("PM54-060-S250-R", V1, "PresentPWM", 572, Half, R),
-    Reg::new("PM54-060-S250-R", V1, "PresentCurrent", 574, Half, R),
-    Reg::new("PM54-060-S250-R", V1, "PresentVelocity", 576, Word, R),
-    Reg::new("PM54-060-S250-R", V1, "PresentPosition", 580, Word, R),
-    Reg::new("PM54-060-S250-R", V1, "VelocityTrajectory", 584, Word, R),
-    Reg::new("PM54-060-S250-R", V1, "PositionTrajectory", 588, Word, R),
-    Reg::new("PM54-060-S250-R", V1, "PresentInputVoltage", 592, Half, R),
-    Reg::new("PM54-060-S250-R", V1, "PresentTemperature", 594, Byte, R),
+    Reg::new("PM54-060-S250-R", V1, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("PM54-060-S250-R", V1, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("PM54-060-S250-R", V1, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("PM54-060-S250-R", V1, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 1003846.0, 0.0),
+    Reg::new("PM54-060-S250-R", V1, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("PM54-060-S250-R", V1, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("PM54-060-S250-R", V1, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("PM54-060-S250-R", V1, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("PM54-060-S250-R", V1, "ExternalPortData1", 600, Half, RW),
     Reg::new("PM54-060-S250-R", V1, "ExternalPortData2", 602, Half, RW),
     Reg::new("PM54-060-S250-R", V1, "ExternalPortData3", 604, Half, RW),
@@ -940,16 +1066,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("H42-20-S300-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("H42-20-S300-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("H42-20-S300-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("H42-20-S300-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("H42-20-S300-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 303750.0, 0.0),
     Reg::new("H42-20-S300-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("H42-20-S300-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("H42-20-S300-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("H42-20-S300-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("H42-20-S300-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("H42-20-S300-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("H42-20-S300-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("H42-20-S300-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("H42-20-S300-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("H42-20-S300-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("H42-20-S300-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("H42-20-S300-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("H42-20-S300-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 303750.0,
+        0.0,
+    ),
+    Reg::new("H42-20-S300-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 303750.0,
+        0.0,
+    ),
     Reg::new("H42-20-S300-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("H42-20-S300-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("H42-20-S300-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -962,16 +1098,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("H42-20-S300-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("H42-20-S300-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("H42-20-S300-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("H42-20-S300-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("H42-20-S300-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("H42-20-S300-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("H42-20-S300-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 303750.0, 0.0),
+    Reg::new("H42-20-S300-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("H42-20-S300-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("H42-20-S300-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("H42-20-S300-R", V2, "Moving", 610, Byte, R),
-    Reg::new("H42-20-S300-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("H42-20-S300-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("H42-20-S300-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("H42-20-S300-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("H42-20-S300-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("H42-20-S300-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 303750.0, 0.0),
+    Reg::new("H42-20-S300-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("H42-20-S300-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("H42-20-S300-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("H42-20-S300-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("H42-20-S300-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("H42-20-S300-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("H42-20-S300-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -989,17 +1129,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("H42-20-S300-RA", V2, "DriveMode", 10, Byte, RW),
     Reg::new("H42-20-S300-RA", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("H42-20-S300-RA", V2, "SecondaryID", 12, Byte, RW),
-    Reg::new("H42-20-S300-RA", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("H42-20-S300-RA", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 303750.0, 0.0),
     Reg::new("H42-20-S300-RA", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("H42-20-S300-RA", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("H42-20-S300-RA", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("H42-20-S300-RA", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("H42-20-S300-RA", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("H42-20-S300-RA", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("H42-20-S300-RA", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("H42-20-S300-RA", V2, "PWMLimit", 36, Half, RW),
     Reg::new("H42-20-S300-RA", V2, "CurrentLimit", 38, Half, RW),
     Reg::new("H42-20-S300-RA", V2, "AccelerationLimit", 40, Word, RW),
     Reg::new("H42-20-S300-RA", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("H42-20-S300-RA", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("H42-20-S300-RA", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("H42-20-S300-RA", V2, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 303750.0,
+        0.0,
+    ),
+    Reg::new("H42-20-S300-RA", V2, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 303750.0,
+        0.0,
+    ),
     Reg::new("H42-20-S300-RA", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("H42-20-S300-RA", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("H42-20-S300-RA", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -1020,23 +1170,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("H42-20-S300-RA", V2, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("H42-20-S300-RA", V2, "Feedforward1stGain", 538, Half, RW),
     Reg::new("H42-20-S300-RA", V2, "BusWatchdog", 546, Byte, RW),
-    Reg::new("H42-20-S300-RA", V2, "GoalPWM", 548, Half, RW),
-    Reg::new("H42-20-S300-RA", V2, "GoalCurrent", 550, Half, RW),
-    Reg::new("H42-20-S300-RA", V2, "GoalVelocity", 552, Word, RW),
+    Reg::new("H42-20-S300-RA", V2, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("H42-20-S300-RA", V2, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("H42-20-S300-RA", V2, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("H42-20-S300-RA", V2, "ProfileAcceleration", 556, Word, RW),
     Reg::new("H42-20-S300-RA", V2, "ProfileVelocity", 560, Word, RW),
-    Reg::new("H42-20-S300-RA", V2, "GoalPosition", 564, Word, RW),
+    Reg::new("H42-20-S300-RA", V2, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 303750.0, 0.0),
     Reg::new("H42-20-S300-RA", V2, "RealtimeTick", 568, Half, R),
     Reg::new("H42-20-S300-RA", V2, "Moving", 570, Byte, R),
     Reg::new("H42-20-S300-RA", V2, "MovingStatus", 571, Byte, R),
-    Reg::new("H42-20-S300-RA", V2, "PresentPWM", 572, Half, R),
-    Reg::new("H42-20-S300-RA", V2, "PresentCurrent", 574, Half, R),
-    Reg::new("H42-20-S300-RA", V2, "PresentVelocity", 576, Word, R),
-    Reg::new("H42-20-S300-RA", V2, "PresentPosition", 580, Word, R),
-    Reg::new("H42-20-S300-RA", V2, "VelocityTrajectory", 584, Word, R),
-    Reg::new("H42-20-S300-RA", V2, "PositionTrajectory", 588, Word, R),
-    Reg::new("H42-20-S300-RA", V2, "PresentInputVoltage", 592, Half, R),
-    Reg::new("H42-20-S300-RA", V2, "PresentTemperature", 594, Byte, R),
+    Reg::new("H42-20-S300-RA", V2, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("H42-20-S300-RA", V2, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("H42-20-S300-RA", V2, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("H42-20-S300-RA", V2, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 303750.0, 0.0),
+    Reg::new("H42-20-S300-RA", V2, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("H42-20-S300-RA", V2, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("H42-20-S300-RA", V2, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("H42-20-S300-RA", V2, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("H42-20-S300-RA", V2, "ExternalPortData1", 600, Half, RW),
     Reg::new("H42-20-S300-RA", V2, "ExternalPortData2", 602, Half, RW),
     Reg::new("H42-20-S300-RA", V2, "ExternalPortData3", 604, Half, RW),
@@ -1049,16 +1203,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("H54-100-S500-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("H54-100-S500-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("H54-100-S500-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("H54-100-S500-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("H54-100-S500-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
     Reg::new("H54-100-S500-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("H54-100-S500-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("H54-100-S500-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("H54-100-S500-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("H54-100-S500-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("H54-100-S500-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("H54-100-S500-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("H54-100-S500-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("H54-100-S500-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("H54-100-S500-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("H54-100-S500-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("H54-100-S500-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("H54-100-S500-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 501923.0,
+        0.0,
+    ),
+    Reg::new("H54-100-S500-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 501923.0,
+        0.0,
+    ),
     Reg::new("H54-100-S500-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("H54-100-S500-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("H54-100-S500-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1071,16 +1235,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("H54-100-S500-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("H54-100-S500-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("H54-100-S500-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("H54-100-S500-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("H54-100-S500-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("H54-100-S500-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("H54-100-S500-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
+    Reg::new("H54-100-S500-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("H54-100-S500-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("H54-100-S500-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("H54-100-S500-R", V2, "Moving", 610, Byte, R),
-    Reg::new("H54-100-S500-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("H54-100-S500-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("H54-100-S500-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("H54-100-S500-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("H54-100-S500-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("H54-100-S500-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
+    Reg::new("H54-100-S500-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("H54-100-S500-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("H54-100-S500-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("H54-100-S500-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("H54-100-S500-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("H54-100-S500-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("H54-100-S500-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1098,17 +1266,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("H54-100-S500-RA", V2, "DriveMode", 10, Byte, RW),
     Reg::new("H54-100-S500-RA", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("H54-100-S500-RA", V2, "SecondaryID", 12, Byte, RW),
-    Reg::new("H54-100-S500-RA", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("H54-100-S500-RA", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
     Reg::new("H54-100-S500-RA", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("H54-100-S500-RA", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("H54-100-S500-RA", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("H54-100-S500-RA", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("H54-100-S500-RA", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("H54-100-S500-RA", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("H54-100-S500-RA", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("H54-100-S500-RA", V2, "PWMLimit", 36, Half, RW),
     Reg::new("H54-100-S500-RA", V2, "CurrentLimit", 38, Half, RW),
     Reg::new("H54-100-S500-RA", V2, "AccelerationLimit", 40, Word, RW),
     Reg::new("H54-100-S500-RA", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("H54-100-S500-RA", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("H54-100-S500-RA", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("H54-100-S500-RA", V2, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 501923.0,
+        0.0,
+    ),
+    Reg::new("H54-100-S500-RA", V2, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 501923.0,
+        0.0,
+    ),
     Reg::new("H54-100-S500-RA", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("H54-100-S500-RA", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("H54-100-S500-RA", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -1129,23 +1307,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("H54-100-S500-RA", V2, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("H54-100-S500-RA", V2, "Feedforward1stGain", 538, Half, RW),
     Reg::new("H54-100-S500-RA", V2, "BusWatchdog", 546, Byte, RW),
-    Reg::new("H54-100-S500-RA", V2, "GoalPWM", 548, Half, RW),
-    Reg::new("H54-100-S500-RA", V2, "GoalCurrent", 550, Half, RW),
-    Reg::new("H54-100-S500-RA", V2, "GoalVelocity", 552, Word, RW),
+    Reg::new("H54-100-S500-RA", V2, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("H54-100-S500-RA", V2, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("H54-100-S500-RA", V2, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("H54-100-S500-RA", V2, "ProfileAcceleration", 556, Word, RW),
     Reg::new("H54-100-S500-RA", V2, "ProfileVelocity", 560, Word, RW),
-    Reg::new("H54-100-S500-RA", V2, "GoalPosition", 564, Word, RW),
+    Reg::new("H54-100-S500-RA", V2, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
     Reg::new("H54-100-S500-RA", V2, "RealtimeTick", 568, Half, R),
     Reg::new("H54-100-S500-RA", V2, "Moving", 570, Byte, R),
     Reg::new("H54-100-S500-RA", V2, "MovingStatus", 571, Byte, R),
-    Reg::new("H54-100-S500-RA", V2, "PresentPWM", 572, Half, R),
-    Reg::new("H54-100-S500-RA", V2, "PresentCurrent", 574, Half, R),
-    Reg::new("H54-100-S500-RA", V2, "PresentVelocity", 576, Word, R),
-    Reg::new("H54-100-S500-RA", V2, "PresentPosition", 580, Word, R),
-    Reg::new("H54-100-S500-RA", V2, "VelocityTrajectory", 584, Word, R),
-    Reg::new("H54-100-S500-RA", V2, "PositionTrajectory", 588, Word, R),
-    Reg::new("H54-100-S500-RA", V2, "PresentInputVoltage", 592, Half, R),
-    Reg::new("H54-100-S500-RA", V2, "PresentTemperature", 594, Byte, R),
+    Reg::new("H54-100-S500-RA", V2, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("H54-100-S500-RA", V2, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("H54-100-S500-RA", V2, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("H54-100-S500-RA", V2, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
+    Reg::new("H54-100-S500-RA", V2, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("H54-100-S500-RA", V2, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("H54-100-S500-RA", V2, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("H54-100-S500-RA", V2, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("H54-100-S500-RA", V2, "ExternalPortData1", 600, Half, RW),
     Reg::new("H54-100-S500-RA", V2, "ExternalPortData2", 602, Half, RW),
     Reg::new("H54-100-S500-RA", V2, "ExternalPortData3", 604, Half, RW),
@@ -1158,16 +1340,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("H54-200-S500-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("H54-200-S500-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("H54-200-S500-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("H54-200-S500-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("H54-200-S500-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
     Reg::new("H54-200-S500-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("H54-200-S500-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("H54-200-S500-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("H54-200-S500-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("H54-200-S500-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("H54-200-S500-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("H54-200-S500-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("H54-200-S500-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("H54-200-S500-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("H54-200-S500-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("H54-200-S500-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("H54-200-S500-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("H54-200-S500-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 501923.0,
+        0.0,
+    ),
+    Reg::new("H54-200-S500-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 501923.0,
+        0.0,
+    ),
     Reg::new("H54-200-S500-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("H54-200-S500-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("H54-200-S500-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1180,16 +1372,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("H54-200-S500-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("H54-200-S500-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("H54-200-S500-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("H54-200-S500-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("H54-200-S500-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("H54-200-S500-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("H54-200-S500-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
+    Reg::new("H54-200-S500-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("H54-200-S500-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("H54-200-S500-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("H54-200-S500-R", V2, "Moving", 610, Byte, R),
-    Reg::new("H54-200-S500-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("H54-200-S500-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("H54-200-S500-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("H54-200-S500-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("H54-200-S500-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("H54-200-S500-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
+    Reg::new("H54-200-S500-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("H54-200-S500-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("H54-200-S500-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("H54-200-S500-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("H54-200-S500-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("H54-200-S500-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("H54-200-S500-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1207,17 +1403,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("H54-200-S500-RA", V2, "DriveMode", 10, Byte, RW),
     Reg::new("H54-200-S500-RA", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("H54-200-S500-RA", V2, "SecondaryID", 12, Byte, RW),
-    Reg::new("H54-200-S500-RA", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("H54-200-S500-RA", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
     Reg::new("H54-200-S500-RA", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("H54-200-S500-RA", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("H54-200-S500-RA", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("H54-200-S500-RA", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("H54-200-S500-RA", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("H54-200-S500-RA", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("H54-200-S500-RA", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("H54-200-S500-RA", V2, "PWMLimit", 36, Half, RW),
     Reg::new("H54-200-S500-RA", V2, "CurrentLimit", 38, Half, RW),
     Reg::new("H54-200-S500-RA", V2, "AccelerationLimit", 40, Word, RW),
     Reg::new("H54-200-S500-RA", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("H54-200-S500-RA", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("H54-200-S500-RA", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("H54-200-S500-RA", V2, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 501923.0,
+        0.0,
+    ),
+    Reg::new("H54-200-S500-RA", V2, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 501923.0,
+        0.0,
+    ),
     Reg::new("H54-200-S500-RA", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("H54-200-S500-RA", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("H54-200-S500-RA", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -1238,23 +1444,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("H54-200-S500-RA", V2, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("H54-200-S500-RA", V2, "Feedforward1stGain", 538, Half, RW),
     Reg::new("H54-200-S500-RA", V2, "BusWatchdog", 546, Byte, RW),
-    Reg::new("H54-200-S500-RA", V2, "GoalPWM", 548, Half, RW),
-    Reg::new("H54-200-S500-RA", V2, "GoalCurrent", 550, Half, RW),
-    Reg::new("H54-200-S500-RA", V2, "GoalVelocity", 552, Word, RW),
+    Reg::new("H54-200-S500-RA", V2, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("H54-200-S500-RA", V2, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("H54-200-S500-RA", V2, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("H54-200-S500-RA", V2, "ProfileAcceleration", 556, Word, RW),
     Reg::new("H54-200-S500-RA", V2, "ProfileVelocity", 560, Word, RW),
-    Reg::new("H54-200-S500-RA", V2, "GoalPosition", 564, Word, RW),
+    Reg::new("H54-200-S500-RA", V2, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
     Reg::new("H54-200-S500-RA", V2, "RealtimeTick", 568, Half, R),
     Reg::new("H54-200-S500-RA", V2, "Moving", 570, Byte, R),
     Reg::new("H54-200-S500-RA", V2, "MovingStatus", 571, Byte, R),
-    Reg::new("H54-200-S500-RA", V2, "PresentPWM", 572, Half, R),
-    Reg::new("H54-200-S500-RA", V2, "PresentCurrent", 574, Half, R),
-    Reg::new("H54-200-S500-RA", V2, "PresentVelocity", 576, Word, R),
-    Reg::new("H54-200-S500-RA", V2, "PresentPosition", 580, Word, R),
-    Reg::new("H54-200-S500-RA", V2, "VelocityTrajectory", 584, Word, R),
-    Reg::new("H54-200-S500-RA", V2, "PositionTrajectory", 588, Word, R),
-    Reg::new("H54-200-S500-RA", V2, "PresentInputVoltage", 592, Half, R),
-    Reg::new("H54-200-S500-RA", V2, "PresentTemperature", 594, Byte, R),
+    Reg::new("H54-200-S500-RA", V2, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("H54-200-S500-RA", V2, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("H54-200-S500-RA", V2, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("H54-200-S500-RA", V2, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 501923.0, 0.0),
+    Reg::new("H54-200-S500-RA", V2, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("H54-200-S500-RA", V2, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("H54-200-S500-RA", V2, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("H54-200-S500-RA", V2, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("H54-200-S500-RA", V2, "ExternalPortData1", 600, Half, RW),
     Reg::new("H54-200-S500-RA", V2, "ExternalPortData2", 602, Half, RW),
     Reg::new("H54-200-S500-RA", V2, "ExternalPortData3", 604, Half, RW),
@@ -1267,16 +1477,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("L42-10-S300-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("L42-10-S300-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("L42-10-S300-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("L42-10-S300-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("L42-10-S300-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("L42-10-S300-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("L42-10-S300-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("L42-10-S300-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("L42-10-S300-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("L42-10-S300-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("L42-10-S300-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("L42-10-S300-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("L42-10-S300-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("L42-10-S300-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("L42-10-S300-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("L42-10-S300-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("L42-10-S300-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("L42-10-S300-R", V2, "MaxPositionLimit", 36, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("L42-10-S300-R", V2, "MinPositionLimit", 40, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("L42-10-S300-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("L42-10-S300-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("L42-10-S300-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1289,16 +1501,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("L42-10-S300-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("L42-10-S300-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("L42-10-S300-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("L42-10-S300-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("L42-10-S300-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("L42-10-S300-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("L42-10-S300-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("L42-10-S300-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("L42-10-S300-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("L42-10-S300-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("L42-10-S300-R", V2, "Moving", 610, Byte, R),
-    Reg::new("L42-10-S300-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("L42-10-S300-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("L42-10-S300-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("L42-10-S300-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("L42-10-S300-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("L42-10-S300-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("L42-10-S300-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("L42-10-S300-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("L42-10-S300-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("L42-10-S300-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("L42-10-S300-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("L42-10-S300-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("L42-10-S300-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1314,16 +1530,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("L54-30-S400-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("L54-30-S400-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("L54-30-S400-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("L54-30-S400-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("L54-30-S400-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 288360.0, 0.0),
     Reg::new("L54-30-S400-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("L54-30-S400-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("L54-30-S400-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("L54-30-S400-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("L54-30-S400-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("L54-30-S400-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("L54-30-S400-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("L54-30-S400-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("L54-30-S400-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("L54-30-S400-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("L54-30-S400-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("L54-30-S400-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("L54-30-S400-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 288360.0,
+        0.0,
+    ),
+    Reg::new("L54-30-S400-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 288360.0,
+        0.0,
+    ),
     Reg::new("L54-30-S400-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("L54-30-S400-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("L54-30-S400-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1336,16 +1562,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("L54-30-S400-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("L54-30-S400-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("L54-30-S400-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("L54-30-S400-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("L54-30-S400-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("L54-30-S400-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("L54-30-S400-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 288360.0, 0.0),
+    Reg::new("L54-30-S400-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("L54-30-S400-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("L54-30-S400-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("L54-30-S400-R", V2, "Moving", 610, Byte, R),
-    Reg::new("L54-30-S400-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("L54-30-S400-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("L54-30-S400-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("L54-30-S400-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("L54-30-S400-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("L54-30-S400-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 288360.0, 0.0),
+    Reg::new("L54-30-S400-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("L54-30-S400-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("L54-30-S400-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("L54-30-S400-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("L54-30-S400-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("L54-30-S400-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("L54-30-S400-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1361,16 +1591,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("L54-30-S500-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("L54-30-S500-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("L54-30-S500-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("L54-30-S500-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("L54-30-S500-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 361384.0, 0.0),
     Reg::new("L54-30-S500-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("L54-30-S500-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("L54-30-S500-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("L54-30-S500-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("L54-30-S500-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("L54-30-S500-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("L54-30-S500-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("L54-30-S500-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("L54-30-S500-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("L54-30-S500-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("L54-30-S500-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("L54-30-S500-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("L54-30-S500-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 361384.0,
+        0.0,
+    ),
+    Reg::new("L54-30-S500-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 361384.0,
+        0.0,
+    ),
     Reg::new("L54-30-S500-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("L54-30-S500-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("L54-30-S500-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1383,16 +1623,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("L54-30-S500-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("L54-30-S500-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("L54-30-S500-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("L54-30-S500-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("L54-30-S500-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("L54-30-S500-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("L54-30-S500-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 361384.0, 0.0),
+    Reg::new("L54-30-S500-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("L54-30-S500-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("L54-30-S500-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("L54-30-S500-R", V2, "Moving", 610, Byte, R),
-    Reg::new("L54-30-S500-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("L54-30-S500-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("L54-30-S500-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("L54-30-S500-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("L54-30-S500-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("L54-30-S500-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 361384.0, 0.0),
+    Reg::new("L54-30-S500-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("L54-30-S500-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("L54-30-S500-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("L54-30-S500-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("L54-30-S500-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("L54-30-S500-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("L54-30-S500-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1408,16 +1652,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("L54-50-S290-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("L54-50-S290-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("L54-50-S290-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("L54-50-S290-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("L54-50-S290-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 207692.0, 0.0),
     Reg::new("L54-50-S290-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("L54-50-S290-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("L54-50-S290-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("L54-50-S290-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("L54-50-S290-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("L54-50-S290-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("L54-50-S290-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("L54-50-S290-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("L54-50-S290-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("L54-50-S290-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("L54-50-S290-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("L54-50-S290-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("L54-50-S290-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 207692.0,
+        0.0,
+    ),
+    Reg::new("L54-50-S290-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 207692.0,
+        0.0,
+    ),
     Reg::new("L54-50-S290-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("L54-50-S290-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("L54-50-S290-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1430,16 +1684,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("L54-50-S290-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("L54-50-S290-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("L54-50-S290-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("L54-50-S290-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("L54-50-S290-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("L54-50-S290-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("L54-50-S290-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 207692.0, 0.0),
+    Reg::new("L54-50-S290-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("L54-50-S290-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("L54-50-S290-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("L54-50-S290-R", V2, "Moving", 610, Byte, R),
-    Reg::new("L54-50-S290-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("L54-50-S290-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("L54-50-S290-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("L54-50-S290-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("L54-50-S290-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("L54-50-S290-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 207692.0, 0.0),
+    Reg::new("L54-50-S290-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("L54-50-S290-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("L54-50-S290-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("L54-50-S290-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("L54-50-S290-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("L54-50-S290-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("L54-50-S290-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1455,16 +1713,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("L54-50-S500-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("L54-50-S500-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("L54-50-S500-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("L54-50-S500-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("L54-50-S500-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 361384.0, 0.0),
     Reg::new("L54-50-S500-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("L54-50-S500-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("L54-50-S500-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("L54-50-S500-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("L54-50-S500-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("L54-50-S500-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("L54-50-S500-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("L54-50-S500-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("L54-50-S500-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("L54-50-S500-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("L54-50-S500-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("L54-50-S500-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("L54-50-S500-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 361384.0,
+        0.0,
+    ),
+    Reg::new("L54-50-S500-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 361384.0,
+        0.0,
+    ),
     Reg::new("L54-50-S500-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("L54-50-S500-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("L54-50-S500-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1477,16 +1745,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("L54-50-S500-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("L54-50-S500-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("L54-50-S500-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("L54-50-S500-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("L54-50-S500-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("L54-50-S500-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("L54-50-S500-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 361384.0, 0.0),
+    Reg::new("L54-50-S500-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("L54-50-S500-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("L54-50-S500-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("L54-50-S500-R", V2, "Moving", 610, Byte, R),
-    Reg::new("L54-50-S500-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("L54-50-S500-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("L54-50-S500-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("L54-50-S500-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("L54-50-S500-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("L54-50-S500-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 361384.0, 0.0),
+    Reg::new("L54-50-S500-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("L54-50-S500-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("L54-50-S500-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("L54-50-S500-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("L54-50-S500-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("L54-50-S500-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("L54-50-S500-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1502,16 +1774,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("M42-10-S260-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("M42-10-S260-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("M42-10-S260-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("M42-10-S260-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("M42-10-S260-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 263187.0, 0.0),
     Reg::new("M42-10-S260-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("M42-10-S260-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("M42-10-S260-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("M42-10-S260-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("M42-10-S260-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("M42-10-S260-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("M42-10-S260-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("M42-10-S260-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("M42-10-S260-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("M42-10-S260-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("M42-10-S260-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("M42-10-S260-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("M42-10-S260-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 263187.0,
+        0.0,
+    ),
+    Reg::new("M42-10-S260-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 263187.0,
+        0.0,
+    ),
     Reg::new("M42-10-S260-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("M42-10-S260-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("M42-10-S260-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1524,16 +1806,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("M42-10-S260-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("M42-10-S260-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("M42-10-S260-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("M42-10-S260-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("M42-10-S260-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("M42-10-S260-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("M42-10-S260-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 263187.0, 0.0),
+    Reg::new("M42-10-S260-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("M42-10-S260-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("M42-10-S260-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("M42-10-S260-R", V2, "Moving", 610, Byte, R),
-    Reg::new("M42-10-S260-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("M42-10-S260-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("M42-10-S260-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("M42-10-S260-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("M42-10-S260-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("M42-10-S260-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 263187.0, 0.0),
+    Reg::new("M42-10-S260-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("M42-10-S260-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("M42-10-S260-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("M42-10-S260-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("M42-10-S260-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("M42-10-S260-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("M42-10-S260-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1551,17 +1837,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("M42-10-S260-RA", V2, "DriveMode", 10, Byte, RW),
     Reg::new("M42-10-S260-RA", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("M42-10-S260-RA", V2, "SecondaryID", 12, Byte, RW),
-    Reg::new("M42-10-S260-RA", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("M42-10-S260-RA", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 263187.0, 0.0),
     Reg::new("M42-10-S260-RA", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("M42-10-S260-RA", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("M42-10-S260-RA", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("M42-10-S260-RA", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("M42-10-S260-RA", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("M42-10-S260-RA", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("M42-10-S260-RA", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("M42-10-S260-RA", V2, "PWMLimit", 36, Half, RW),
     Reg::new("M42-10-S260-RA", V2, "CurrentLimit", 38, Half, RW),
     Reg::new("M42-10-S260-RA", V2, "AccelerationLimit", 40, Word, RW),
     Reg::new("M42-10-S260-RA", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("M42-10-S260-RA", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("M42-10-S260-RA", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("M42-10-S260-RA", V2, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 263187.0,
+        0.0,
+    ),
+    Reg::new("M42-10-S260-RA", V2, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 263187.0,
+        0.0,
+    ),
     Reg::new("M42-10-S260-RA", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("M42-10-S260-RA", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("M42-10-S260-RA", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -1582,23 +1878,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("M42-10-S260-RA", V2, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("M42-10-S260-RA", V2, "Feedforward1stGain", 538, Half, RW),
     Reg::new("M42-10-S260-RA", V2, "BusWatchdog", 546, Byte, RW),
-    Reg::new("M42-10-S260-RA", V2, "GoalPWM", 548, Half, RW),
-    Reg::new("M42-10-S260-RA", V2, "GoalCurrent", 550, Half, RW),
-    Reg::new("M42-10-S260-RA", V2, "GoalVelocity", 552, Word, RW),
+    Reg::new("M42-10-S260-RA", V2, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("M42-10-S260-RA", V2, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("M42-10-S260-RA", V2, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("M42-10-S260-RA", V2, "ProfileAcceleration", 556, Word, RW),
     Reg::new("M42-10-S260-RA", V2, "ProfileVelocity", 560, Word, RW),
-    Reg::new("M42-10-S260-RA", V2, "GoalPosition", 564, Word, RW),
+    Reg::new("M42-10-S260-RA", V2, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 263187.0, 0.0),
     Reg::new("M42-10-S260-RA", V2, "RealtimeTick", 568, Half, R),
     Reg::new("M42-10-S260-RA", V2, "Moving", 570, Byte, R),
     Reg::new("M42-10-S260-RA", V2, "MovingStatus", 571, Byte, R),
-    Reg::new("M42-10-S260-RA", V2, "PresentPWM", 572, Half, R),
-    Reg::new("M42-10-S260-RA", V2, "PresentCurrent", 574, Half, R),
-    Reg::new("M42-10-S260-RA", V2, "PresentVelocity", 576, Word, R),
-    Reg::new("M42-10-S260-RA", V2, "PresentPosition", 580, Word, R),
-    Reg::new("M42-10-S260-RA", V2, "VelocityTrajectory", 584, Word, R),
-    Reg::new("M42-10-S260-RA", V2, "PositionTrajectory", 588, Word, R),
-    Reg::new("M42-10-S260-RA", V2, "PresentInputVoltage", 592, Half, R),
-    Reg::new("M42-10-S260-RA", V2, "PresentTemperature", 594, Byte, R),
+    Reg::new("M42-10-S260-RA", V2, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("M42-10-S260-RA", V2, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("M42-10-S260-RA", V2, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("M42-10-S260-RA", V2, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 263187.0, 0.0),
+    Reg::new("M42-10-S260-RA", V2, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("M42-10-S260-RA", V2, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("M42-10-S260-RA", V2, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("M42-10-S260-RA", V2, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("M42-10-S260-RA", V2, "ExternalPortData1", 600, Half, RW),
     Reg::new("M42-10-S260-RA", V2, "ExternalPortData2", 602, Half, RW),
     Reg::new("M42-10-S260-RA", V2, "ExternalPortData3", 604, Half, RW),
@@ -1611,16 +1911,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("M54-40-S250-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("M54-40-S250-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("M54-40-S250-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("M54-40-S250-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("M54-40-S250-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
     Reg::new("M54-40-S250-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("M54-40-S250-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("M54-40-S250-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("M54-40-S250-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("M54-40-S250-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("M54-40-S250-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("M54-40-S250-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("M54-40-S250-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("M54-40-S250-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("M54-40-S250-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("M54-40-S250-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("M54-40-S250-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("M54-40-S250-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 502834.0,
+        0.0,
+    ),
+    Reg::new("M54-40-S250-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 502834.0,
+        0.0,
+    ),
     Reg::new("M54-40-S250-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("M54-40-S250-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("M54-40-S250-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1633,16 +1943,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("M54-40-S250-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("M54-40-S250-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("M54-40-S250-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("M54-40-S250-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("M54-40-S250-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("M54-40-S250-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("M54-40-S250-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
+    Reg::new("M54-40-S250-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("M54-40-S250-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("M54-40-S250-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("M54-40-S250-R", V2, "Moving", 610, Byte, R),
-    Reg::new("M54-40-S250-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("M54-40-S250-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("M54-40-S250-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("M54-40-S250-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("M54-40-S250-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("M54-40-S250-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
+    Reg::new("M54-40-S250-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("M54-40-S250-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("M54-40-S250-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("M54-40-S250-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("M54-40-S250-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("M54-40-S250-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("M54-40-S250-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1660,17 +1974,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("M54-40-S250-RA", V2, "DriveMode", 10, Byte, RW),
     Reg::new("M54-40-S250-RA", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("M54-40-S250-RA", V2, "SecondaryID", 12, Byte, RW),
-    Reg::new("M54-40-S250-RA", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("M54-40-S250-RA", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
     Reg::new("M54-40-S250-RA", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("M54-40-S250-RA", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("M54-40-S250-RA", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("M54-40-S250-RA", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("M54-40-S250-RA", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("M54-40-S250-RA", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("M54-40-S250-RA", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("M54-40-S250-RA", V2, "PWMLimit", 36, Half, RW),
     Reg::new("M54-40-S250-RA", V2, "CurrentLimit", 38, Half, RW),
     Reg::new("M54-40-S250-RA", V2, "AccelerationLimit", 40, Word, RW),
     Reg::new("M54-40-S250-RA", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("M54-40-S250-RA", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("M54-40-S250-RA", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("M54-40-S250-RA", V2, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 502834.0,
+        0.0,
+    ),
+    Reg::new("M54-40-S250-RA", V2, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 502834.0,
+        0.0,
+    ),
     Reg::new("M54-40-S250-RA", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("M54-40-S250-RA", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("M54-40-S250-RA", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -1691,23 +2015,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("M54-40-S250-RA", V2, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("M54-40-S250-RA", V2, "Feedforward1stGain", 538, Half, RW),
     Reg::new("M54-40-S250-RA", V2, "BusWatchdog", 546, Byte, RW),
-    Reg::new("M54-40-S250-RA", V2, "GoalPWM", 548, Half, RW),
-    Reg::new("M54-40-S250-RA", V2, "GoalCurrent", 550, Half, RW),
-    Reg::new("M54-40-S250-RA", V2, "GoalVelocity", 552, Word, RW),
+    Reg::new("M54-40-S250-RA", V2, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("M54-40-S250-RA", V2, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("M54-40-S250-RA", V2, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("M54-40-S250-RA", V2, "ProfileAcceleration", 556, Word, RW),
     Reg::new("M54-40-S250-RA", V2, "ProfileVelocity", 560, Word, RW),
-    Reg::new("M54-40-S250-RA", V2, "GoalPosition", 564, Word, RW),
+    Reg::new("M54-40-S250-RA", V2, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
     Reg::new("M54-40-S250-RA", V2, "RealtimeTick", 568, Half, R),
     Reg::new("M54-40-S250-RA", V2, "Moving", 570, Byte, R),
     Reg::new("M54-40-S250-RA", V2, "MovingStatus", 571, Byte, R),
-    Reg::new("M54-40-S250-RA", V2, "PresentPWM", 572, Half, R),
-    Reg::new("M54-40-S250-RA", V2, "PresentCurrent", 574, Half, R),
-    Reg::new("M54-40-S250-RA", V2, "PresentVelocity", 576, Word, R),
-    Reg::new("M54-40-S250-RA", V2, "PresentPosition", 580, Word, R),
-    Reg::new("M54-40-S250-RA", V2, "VelocityTrajectory", 584, Word, R),
-    Reg::new("M54-40-S250-RA", V2, "PositionTrajectory", 588, Word, R),
-    Reg::new("M54-40-S250-RA", V2, "PresentInputVoltage", 592, Half, R),
-    Reg::new("M54-40-S250-RA", V2, "PresentTemperature", 594, Byte, R),
+    Reg::new("M54-40-S250-RA", V2, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("M54-40-S250-RA", V2, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("M54-40-S250-RA", V2, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("M54-40-S250-RA", V2, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
+    Reg::new("M54-40-S250-RA", V2, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("M54-40-S250-RA", V2, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("M54-40-S250-RA", V2, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("M54-40-S250-RA", V2, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("M54-40-S250-RA", V2, "ExternalPortData1", 600, Half, RW),
     Reg::new("M54-40-S250-RA", V2, "ExternalPortData2", 602, Half, RW),
     Reg::new("M54-40-S250-RA", V2, "ExternalPortData3", 604, Half, RW),
@@ -1720,16 +2048,26 @@ pub static REGS: &[Reg] = &[
     Reg::new("M54-60-S250-R", V2, "BaudRate", 8, Byte, RW),
     Reg::new("M54-60-S250-R", V2, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("M54-60-S250-R", V2, "OperatingMode", 11, Byte, RW),
-    Reg::new("M54-60-S250-R", V2, "HomingOffset", 13, Word, RW),
+    Reg::new("M54-60-S250-R", V2, "HomingOffset", 13, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
     Reg::new("M54-60-S250-R", V2, "MovingThreshold", 17, Word, RW),
-    Reg::new("M54-60-S250-R", V2, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("M54-60-S250-R", V2, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("M54-60-S250-R", V2, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("M54-60-S250-R", V2, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("M54-60-S250-R", V2, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("M54-60-S250-R", V2, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("M54-60-S250-R", V2, "AccelerationLimit", 26, Word, RW),
     Reg::new("M54-60-S250-R", V2, "TorqueLimit", 30, Half, RW),
     Reg::new("M54-60-S250-R", V2, "VelocityLimit", 32, Word, RW),
-    Reg::new("M54-60-S250-R", V2, "MaxPositionLimit", 36, Word, RW),
-    Reg::new("M54-60-S250-R", V2, "MinPositionLimit", 40, Word, RW),
+    Reg::new("M54-60-S250-R", V2, "MaxPositionLimit", 36, Word, RW).unit(
+        "deg",
+        360.0 / 502834.0,
+        0.0,
+    ),
+    Reg::new("M54-60-S250-R", V2, "MinPositionLimit", 40, Word, RW).unit(
+        "deg",
+        360.0 / 502834.0,
+        0.0,
+    ),
     Reg::new("M54-60-S250-R", V2, "ExternalPortMode1", 44, Byte, RW),
     Reg::new("M54-60-S250-R", V2, "ExternalPortMode2", 45, Byte, RW),
     Reg::new("M54-60-S250-R", V2, "ExternalPortMode3", 46, Byte, RW),
@@ -1742,16 +2080,20 @@ pub static REGS: &[Reg] = &[
     Reg::new("M54-60-S250-R", V2, "VelocityIGain", 586, Half, RW),
     Reg::new("M54-60-S250-R", V2, "VelocityPGain", 588, Half, RW),
     Reg::new("M54-60-S250-R", V2, "PositionPGain", 594, Half, RW),
-    Reg::new("M54-60-S250-R", V2, "GoalPosition", 596, Word, RW),
-    Reg::new("M54-60-S250-R", V2, "GoalVelocity", 600, Word, RW),
-    Reg::new("M54-60-S250-R", V2, "GoalTorque", 604, Half, RW),
+    Reg::new("M54-60-S250-R", V2, "GoalPosition", 596, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
+    Reg::new("M54-60-S250-R", V2, "GoalVelocity", 600, Word, RW).signed(),
+    Reg::new("M54-60-S250-R", V2, "GoalTorque", 604, Half, RW).signed(),
     Reg::new("M54-60-S250-R", V2, "GoalAcceleration", 606, Word, RW),
     Reg::new("M54-60-S250-R", V2, "Moving", 610, Byte, R),
-    Reg::new("M54-60-S250-R", V2, "PresentPosition", 611, Word, R),
-    Reg::new("M54-60-S250-R", V2, "PresentVelocity", 615, Word, R),
-    Reg::new("M54-60-S250-R", V2, "PresentCurrent", 621, Half, R),
-    Reg::new("M54-60-S250-R", V2, "PresentInputVoltage", 623, Half, R),
-    Reg::new("M54-60-S250-R", V2, "PresentTemperature", 625, Byte, R),
+    Reg::new("M54-60-S250-R", V2, "PresentPosition", 611, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
+    Reg::new("M54-60-S250-R", V2, "PresentVelocity", 615, Word, R).signed(),
+    Reg::new("M54-60-S250-R", V2, "PresentCurrent", 621, Half, R).signed(),
+    Reg::new("M54-60-S250-R", V2, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("M54-60-S250-R", V2, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("M54-60-S250-R", V2, "ExternalPortData1", 626, Half, RW),
     Reg::new("M54-60-S250-R", V2, "ExternalPortData2", 628, Half, RW),
     Reg::new("M54-60-S250-R", V2, "ExternalPortData3", 630, Half, RW),
@@ -1769,17 +2111,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("M54-60-S250-RA", V2, "DriveMode", 10, Byte, RW),
     Reg::new("M54-60-S250-RA", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("M54-60-S250-RA", V2, "SecondaryID", 12, Byte, RW),
-    Reg::new("M54-60-S250-RA", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("M54-60-S250-RA", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
     Reg::new("M54-60-S250-RA", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("M54-60-S250-RA", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("M54-60-S250-RA", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("M54-60-S250-RA", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("M54-60-S250-RA", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("M54-60-S250-RA", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("M54-60-S250-RA", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("M54-60-S250-RA", V2, "PWMLimit", 36, Half, RW),
     Reg::new("M54-60-S250-RA", V2, "CurrentLimit", 38, Half, RW),
     Reg::new("M54-60-S250-RA", V2, "AccelerationLimit", 40, Word, RW),
     Reg::new("M54-60-S250-RA", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("M54-60-S250-RA", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("M54-60-S250-RA", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("M54-60-S250-RA", V2, "MaxPositionLimit", 48, Word, RW).unit(
+        "deg",
+        360.0 / 502834.0,
+        0.0,
+    ),
+    Reg::new("M54-60-S250-RA", V2, "MinPositionLimit", 52, Word, RW).unit(
+        "deg",
+        360.0 / 502834.0,
+        0.0,
+    ),
     Reg::new("M54-60-S250-RA", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("M54-60-S250-RA", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("M54-60-S250-RA", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -1800,23 +2152,27 @@ pub static REGS: &[Reg] = &[
     Reg::new("M54-60-S250-RA", V2, "Feedforward2ndGain", 536, Half, RW),
     Reg::new("M54-60-S250-RA", V2, "Feedforward1stGain", 538, Half, RW),
     Reg::new("M54-60-S250-RA", V2, "BusWatchdog", 546, Byte, RW),
-    Reg::new("M54-60-S250-RA", V2, "GoalPWM", 548, Half, RW),
-    Reg::new("M54-60-S250-RA", V2, "GoalCurrent", 550, Half, RW),
-    Reg::new("M54-60-S250-RA", V2, "GoalVelocity", 552, Word, RW),
+    Reg::new("M54-60-S250-RA", V2, "GoalPWM", 548, Half, RW).signed(),
+    Reg::new("M54-60-S250-RA", V2, "GoalCurrent", 550, Half, RW).signed(),
+    Reg::new("M54-60-S250-RA", V2, "GoalVelocity", 552, Word, RW).signed(),
     Reg::new("M54-60-S250-RA", V2, "ProfileAcceleration", 556, Word, RW),
     Reg::new("M54-60-S250-RA", V2, "ProfileVelocity", 560, Word, RW),
-    Reg::new("M54-60-S250-RA", V2, "GoalPosition", 564, Word, RW),
+    Reg::new("M54-60-S250-RA", V2, "GoalPosition", 564, Word, RW)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
     Reg::new("M54-60-S250-RA", V2, "RealtimeTick", 568, Half, R),
     Reg::new("M54-60-S250-RA", V2, "Moving", 570, Byte, R),
     Reg::new("M54-60-S250-RA", V2, "MovingStatus", 571, Byte, R),
-    Reg::new("M54-60-S250-RA", V2, "PresentPWM", 572, Half, R),
-    Reg::new("M54-60-S250-RA", V2, "PresentCurrent", 574, Half, R),
-    Reg::new("M54-60-S250-RA", V2, "PresentVelocity", 576, Word, R),
-    Reg::new("M54-60-S250-RA", V2, "PresentPosition", 580, Word, R),
-    Reg::new("M54-60-S250-RA", V2, "VelocityTrajectory", 584, Word, R),
-    Reg::new("M54-60-S250-RA", V2, "PositionTrajectory", 588, Word, R),
-    Reg::new("M54-60-S250-RA", V2, "PresentInputVoltage", 592, Half, R),
-    Reg::new("M54-60-S250-RA", V2, "PresentTemperature", 594, Byte, R),
+    Reg::new("M54-60-S250-RA", V2, "PresentPWM", 572, Half, R).signed(),
+    Reg::new("M54-60-S250-RA", V2, "PresentCurrent", 574, Half, R).signed(),
+    Reg::new("M54-60-S250-RA", V2, "PresentVelocity", 576, Word, R).signed(),
+    Reg::new("M54-60-S250-RA", V2, "PresentPosition", 580, Word, R)
+        .signed()
+        .unit("deg", 360.0 / 502834.0, 0.0),
+    Reg::new("M54-60-S250-RA", V2, "VelocityTrajectory", 584, Word, R).signed(),
+    Reg::new("M54-60-S250-RA", V2, "PositionTrajectory", 588, Word, R).signed(),
+    Reg::new("M54-60-S250-RA", V2, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("M54-60-S250-RA", V2, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("M54-60-S250-RA", V2, "ExternalPortData1", 600, Half, RW),
     Reg::new("M54-60-S250-RA", V2, "ExternalPortData2", 602, Half, RW),
     Reg::new("M54-60-S250-RA", V2, "ExternalPortData3", 604, Half, RW),
@@ -1827,11 +2183,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("RX-10", V1, "ID", 3, Byte, RW),
     Reg::new("RX-10", V1, "BaudRate", 4, Byte, RW),
     Reg::new("RX-10", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("RX-10", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("RX-10", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("RX-10", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("RX-10", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("RX-10", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("RX-10", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("RX-10", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("RX-10", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("RX-10", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("RX-10", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("RX-10", V1, "MaxTorque", 14, Half, RW),
     Reg::new("RX-10", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("RX-10", V1, "AlarmLED", 17, Byte, RW),
@@ -1842,14 +2198,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("RX-10", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("RX-10", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("RX-10", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("RX-10", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("RX-10", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("RX-10", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("RX-10", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("RX-10", V1, "PresentPosition", 36, Half, R),
+    Reg::new("RX-10", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("RX-10", V1, "PresentSpeed", 38, Half, R),
     Reg::new("RX-10", V1, "PresentLoad", 40, Half, R),
-    Reg::new("RX-10", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("RX-10", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("RX-10", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("RX-10", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("RX-10", V1, "Registered", 44, Byte, R),
     Reg::new("RX-10", V1, "Moving", 46, Byte, R),
     Reg::new("RX-10", V1, "Lock", 47, Byte, RW),
@@ -1860,11 +2216,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("RX-24F", V1, "ID", 3, Byte, RW),
     Reg::new("RX-24F", V1, "BaudRate", 4, Byte, RW),
     Reg::new("RX-24F", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("RX-24F", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("RX-24F", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("RX-24F", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("RX-24F", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("RX-24F", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("RX-24F", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("RX-24F", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("RX-24F", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("RX-24F", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("RX-24F", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("RX-24F", V1, "MaxTorque", 14, Half, RW),
     Reg::new("RX-24F", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("RX-24F", V1, "AlarmLED", 17, Byte, RW),
@@ -1875,14 +2231,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("RX-24F", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("RX-24F", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("RX-24F", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("RX-24F", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("RX-24F", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("RX-24F", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("RX-24F", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("RX-24F", V1, "PresentPosition", 36, Half, R),
+    Reg::new("RX-24F", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("RX-24F", V1, "PresentSpeed", 38, Half, R),
     Reg::new("RX-24F", V1, "PresentLoad", 40, Half, R),
-    Reg::new("RX-24F", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("RX-24F", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("RX-24F", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("RX-24F", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("RX-24F", V1, "Registered", 44, Byte, R),
     Reg::new("RX-24F", V1, "Moving", 46, Byte, R),
     Reg::new("RX-24F", V1, "Lock", 47, Byte, RW),
@@ -1893,11 +2249,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("RX-28", V1, "ID", 3, Byte, RW),
     Reg::new("RX-28", V1, "BaudRate", 4, Byte, RW),
     Reg::new("RX-28", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("RX-28", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("RX-28", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("RX-28", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("RX-28", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("RX-28", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("RX-28", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("RX-28", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("RX-28", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("RX-28", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("RX-28", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("RX-28", V1, "MaxTorque", 14, Half, RW),
     Reg::new("RX-28", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("RX-28", V1, "AlarmLED", 17, Byte, RW),
@@ -1908,14 +2264,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("RX-28", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("RX-28", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("RX-28", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("RX-28", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("RX-28", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("RX-28", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("RX-28", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("RX-28", V1, "PresentPosition", 36, Half, R),
+    Reg::new("RX-28", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("RX-28", V1, "PresentSpeed", 38, Half, R),
     Reg::new("RX-28", V1, "PresentLoad", 40, Half, R),
-    Reg::new("RX-28", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("RX-28", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("RX-28", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("RX-28", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("RX-28", V1, "Registered", 44, Byte, R),
     Reg::new("RX-28", V1, "Moving", 46, Byte, R),
     Reg::new("RX-28", V1, "Lock", 47, Byte, RW),
@@ -1926,11 +2282,11 @@ pub static REGS: &[Reg] = &[
     Reg::new("RX-64", V1, "ID", 3, Byte, RW),
     Reg::new("RX-64", V1, "BaudRate", 4, Byte, RW),
     Reg::new("RX-64", V1, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("RX-64", V1, "CWAngleLimit", 6, Half, RW),
-    Reg::new("RX-64", V1, "CCWAngleLimit", 8, Half, RW),
-    Reg::new("RX-64", V1, "TemperatureLimit", 11, Byte, RW),
-    Reg::new("RX-64", V1, "MinVoltageLimit", 12, Byte, RW),
-    Reg::new("RX-64", V1, "MaxVoltageLimit", 13, Byte, RW),
+    Reg::new("RX-64", V1, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("RX-64", V1, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("RX-64", V1, "TemperatureLimit", 11, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("RX-64", V1, "MinVoltageLimit", 12, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("RX-64", V1, "MaxVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("RX-64", V1, "MaxTorque", 14, Half, RW),
     Reg::new("RX-64", V1, "StatusReturnLevel", 16, Byte, RW),
     Reg::new("RX-64", V1, "AlarmLED", 17, Byte, RW),
@@ -1941,14 +2297,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("RX-64", V1, "CCWComplianceMargin", 27, Byte, RW),
     Reg::new("RX-64", V1, "CWComplianceSlope", 28, Byte, RW),
     Reg::new("RX-64", V1, "CCWComplianceSlope", 29, Byte, RW),
-    Reg::new("RX-64", V1, "GoalPosition", 30, Half, RW),
+    Reg::new("RX-64", V1, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("RX-64", V1, "MovingSpeed", 32, Half, RW),
     Reg::new("RX-64", V1, "TorqueLimit", 34, Half, RW),
-    Reg::new("RX-64", V1, "PresentPosition", 36, Half, R),
+    Reg::new("RX-64", V1, "PresentPosition", 36, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("RX-64", V1, "PresentSpeed", 38, Half, R),
     Reg::new("RX-64", V1, "PresentLoad", 40, Half, R),
-    Reg::new("RX-64", V1, "PresentVoltage", 42, Byte, R),
-    Reg::new("RX-64", V1, "PresentTemperature", 43, Byte, R),
+    Reg::new("RX-64", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("RX-64", V1, "PresentTemperature", 43, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("RX-64", V1, "Registered", 44, Byte, R),
     Reg::new("RX-64", V1, "Moving", 46, Byte, R),
     Reg::new("RX-64", V1, "Lock", 47, Byte, RW),
@@ -1964,15 +2320,17 @@ pub static REGS: &[Reg] = &[
     Reg::new("2XC430-W250", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("2XC430-W250", V2, "Secondary", 12, Byte, RW),
     Reg::new("2XC430-W250", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("2XC430-W250", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("2XC430-W250", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("2XC430-W250", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("2XC430-W250", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("2XC430-W250", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("2XC430-W250", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("2XC430-W250", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("2XC430-W250", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("2XC430-W250", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("2XC430-W250", V2, "PWMLimit", 36, Half, RW),
-    Reg::new("2XC430-W250", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("2XC430-W250", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("2XC430-W250", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("2XC430-W250", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("2XC430-W250", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("2XC430-W250", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("2XC430-W250", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("2XC430-W250", V2, "Shutdown", 63, Byte, RW),
     Reg::new("2XC430-W250", V2, "TorqueEnable", 64, Byte, RW),
@@ -1988,22 +2346,30 @@ pub static REGS: &[Reg] = &[
     Reg::new("2XC430-W250", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("2XC430-W250", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("2XC430-W250", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("2XC430-W250", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("2XC430-W250", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("2XC430-W250", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("2XC430-W250", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("2XC430-W250", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("2XC430-W250", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("2XC430-W250", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("2XC430-W250", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("2XC430-W250", V2, "RealtimeTick", 120, Half, R),
     Reg::new("2XC430-W250", V2, "Moving", 122, Byte, R),
     Reg::new("2XC430-W250", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("2XC430-W250", V2, "PresentPWM", 124, Half, R),
-    Reg::new("2XC430-W250", V2, "PresentLoad", 126, Half, R),
-    Reg::new("2XC430-W250", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("2XC430-W250", V2, "PresentPosition", 132, Word, R),
-    Reg::new("2XC430-W250", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("2XC430-W250", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("2XC430-W250", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("2XC430-W250", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("2XC430-W250", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("2XC430-W250", V2, "PresentLoad", 126, Half, R).signed(),
+    Reg::new("2XC430-W250", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("2XC430-W250", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("2XC430-W250", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("2XC430-W250", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("2XC430-W250", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("2XC430-W250", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("2XC430-W250", V2, "BackupReady", 147, Byte, R),
     // 2XL430-W250 2
     Reg::new("2XL430-W250", V2, "ModelNumber", 0, Half, R),
@@ -2016,15 +2382,17 @@ pub static REGS: &[Reg] = &[
     Reg::new("2XL430-W250", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("2XL430-W250", V2, "Secondary", 12, Byte, RW),
     Reg::new("2XL430-W250", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("2XL430-W250", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("2XL430-W250", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("2XL430-W250", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("2XL430-W250", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("2XL430-W250", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("2XL430-W250", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("2XL430-W250", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("2XL430-W250", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("2XL430-W250", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("2XL430-W250", V2, "PWMLimit", 36, Half, RW),
-    Reg::new("2XL430-W250", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("2XL430-W250", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("2XL430-W250", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("2XL430-W250", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("2XL430-W250", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("2XL430-W250", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("2XL430-W250", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("2XL430-W250", V2, "Shutdown", 63, Byte, RW),
     Reg::new("2XL430-W250", V2, "TorqueEnable", 64, Byte, RW),
@@ -2040,22 +2408,30 @@ pub static REGS: &[Reg] = &[
     Reg::new("2XL430-W250", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("2XL430-W250", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("2XL430-W250", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("2XL430-W250", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("2XL430-W250", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("2XL430-W250", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("2XL430-W250", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("2XL430-W250", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("2XL430-W250", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("2XL430-W250", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("2XL430-W250", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("2XL430-W250", V2, "RealtimeTick", 120, Half, R),
     Reg::new("2XL430-W250", V2, "Moving", 122, Byte, R),
     Reg::new("2XL430-W250", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("2XL430-W250", V2, "PresentPWM", 124, Half, R),
-    Reg::new("2XL430-W250", V2, "PresentLoad", 126, Half, R),
-    Reg::new("2XL430-W250", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("2XL430-W250", V2, "PresentPosition", 132, Word, R),
-    Reg::new("2XL430-W250", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("2XL430-W250", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("2XL430-W250", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("2XL430-W250", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("2XL430-W250", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("2XL430-W250", V2, "PresentLoad", 126, Half, R).signed(),
+    Reg::new("2XL430-W250", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("2XL430-W250", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("2XL430-W250", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("2XL430-W250", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("2XL430-W250", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("2XL430-W250", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("2XL430-W250", V2, "BackupReady", 147, Byte, R),
     // XC330-M181 2
     Reg::new("XC330-M181", V2, "ModelNumber", 0, Half, R),
@@ -2068,16 +2444,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC330-M181", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XC330-M181", V2, "Secondary", 12, Byte, RW),
     Reg::new("XC330-M181", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XC330-M181", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XC330-M181", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-M181", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XC330-M181", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XC330-M181", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XC330-M181", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XC330-M181", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XC330-M181", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XC330-M181", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XC330-M181", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XC330-M181", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XC330-M181", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XC330-M181", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XC330-M181", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XC330-M181", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XC330-M181", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XC330-M181", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-M181", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XC330-M181", V2, "PWMSlope", 62, Byte, RW),
     Reg::new("XC330-M181", V2, "Shutdown", 63, Byte, RW),
@@ -2094,23 +2472,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC330-M181", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XC330-M181", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XC330-M181", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XC330-M181", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XC330-M181", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XC330-M181", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XC330-M181", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XC330-M181", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XC330-M181", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XC330-M181", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XC330-M181", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XC330-M181", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XC330-M181", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-M181", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XC330-M181", V2, "Moving", 122, Byte, R),
     Reg::new("XC330-M181", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XC330-M181", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XC330-M181", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XC330-M181", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XC330-M181", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XC330-M181", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XC330-M181", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XC330-M181", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XC330-M181", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XC330-M181", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XC330-M181", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XC330-M181", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XC330-M181", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XC330-M181", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XC330-M181", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XC330-M181", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XC330-M181", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XC330-M181", V2, "BackupReady", 147, Byte, R),
     // XC330-M288 2
     Reg::new("XC330-M288", V2, "ModelNumber", 0, Half, R),
@@ -2123,16 +2509,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC330-M288", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XC330-M288", V2, "Secondary", 12, Byte, RW),
     Reg::new("XC330-M288", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XC330-M288", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XC330-M288", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-M288", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XC330-M288", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XC330-M288", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XC330-M288", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XC330-M288", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XC330-M288", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XC330-M288", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XC330-M288", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XC330-M288", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XC330-M288", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XC330-M288", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XC330-M288", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XC330-M288", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XC330-M288", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XC330-M288", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-M288", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XC330-M288", V2, "PWMSlope", 62, Byte, RW),
     Reg::new("XC330-M288", V2, "Shutdown", 63, Byte, RW),
@@ -2149,23 +2537,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC330-M288", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XC330-M288", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XC330-M288", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XC330-M288", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XC330-M288", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XC330-M288", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XC330-M288", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XC330-M288", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XC330-M288", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XC330-M288", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XC330-M288", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XC330-M288", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XC330-M288", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-M288", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XC330-M288", V2, "Moving", 122, Byte, R),
     Reg::new("XC330-M288", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XC330-M288", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XC330-M288", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XC330-M288", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XC330-M288", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XC330-M288", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XC330-M288", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XC330-M288", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XC330-M288", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XC330-M288", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XC330-M288", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XC330-M288", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XC330-M288", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XC330-M288", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XC330-M288", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XC330-M288", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XC330-M288", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XC330-M288", V2, "BackupReady", 147, Byte, R),
     // XC330-T181 2
     Reg::new("XC330-T181", V2, "ModelNumber", 0, Half, R),
@@ -2178,16 +2574,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC330-T181", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XC330-T181", V2, "Secondary", 12, Byte, RW),
     Reg::new("XC330-T181", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XC330-T181", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XC330-T181", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-T181", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XC330-T181", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XC330-T181", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XC330-T181", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XC330-T181", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XC330-T181", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XC330-T181", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XC330-T181", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XC330-T181", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XC330-T181", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XC330-T181", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XC330-T181", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XC330-T181", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XC330-T181", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XC330-T181", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-T181", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XC330-T181", V2, "PWMSlope", 62, Byte, RW),
     Reg::new("XC330-T181", V2, "Shutdown", 63, Byte, RW),
@@ -2204,23 +2602,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC330-T181", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XC330-T181", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XC330-T181", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XC330-T181", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XC330-T181", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XC330-T181", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XC330-T181", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XC330-T181", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XC330-T181", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XC330-T181", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XC330-T181", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XC330-T181", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XC330-T181", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-T181", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XC330-T181", V2, "Moving", 122, Byte, R),
     Reg::new("XC330-T181", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XC330-T181", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XC330-T181", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XC330-T181", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XC330-T181", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XC330-T181", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XC330-T181", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XC330-T181", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XC330-T181", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XC330-T181", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XC330-T181", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XC330-T181", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XC330-T181", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XC330-T181", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XC330-T181", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XC330-T181", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XC330-T181", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XC330-T181", V2, "BackupReady", 147, Byte, R),
     // XC330-T288 2
     Reg::new("XC330-T288", V2, "ModelNumber", 0, Half, R),
@@ -2233,16 +2639,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC330-T288", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XC330-T288", V2, "Secondary", 12, Byte, RW),
     Reg::new("XC330-T288", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XC330-T288", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XC330-T288", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-T288", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XC330-T288", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XC330-T288", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XC330-T288", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XC330-T288", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XC330-T288", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XC330-T288", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XC330-T288", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XC330-T288", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XC330-T288", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XC330-T288", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XC330-T288", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XC330-T288", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XC330-T288", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XC330-T288", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-T288", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XC330-T288", V2, "PWMSlope", 62, Byte, RW),
     Reg::new("XC330-T288", V2, "Shutdown", 63, Byte, RW),
@@ -2259,23 +2667,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC330-T288", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XC330-T288", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XC330-T288", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XC330-T288", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XC330-T288", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XC330-T288", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XC330-T288", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XC330-T288", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XC330-T288", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XC330-T288", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XC330-T288", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XC330-T288", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XC330-T288", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC330-T288", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XC330-T288", V2, "Moving", 122, Byte, R),
     Reg::new("XC330-T288", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XC330-T288", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XC330-T288", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XC330-T288", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XC330-T288", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XC330-T288", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XC330-T288", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XC330-T288", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XC330-T288", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XC330-T288", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XC330-T288", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XC330-T288", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XC330-T288", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XC330-T288", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XC330-T288", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XC330-T288", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XC330-T288", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XC330-T288", V2, "BackupReady", 147, Byte, R),
     // XC430-W150 2
     Reg::new("XC430-W150", V2, "ModelNumber", 0, Half, R),
@@ -2288,15 +2704,17 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC430-W150", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XC430-W150", V2, "Secondary", 12, Byte, RW),
     Reg::new("XC430-W150", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XC430-W150", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XC430-W150", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC430-W150", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XC430-W150", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XC430-W150", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XC430-W150", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XC430-W150", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XC430-W150", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XC430-W150", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XC430-W150", V2, "PWMLimit", 36, Half, RW),
-    Reg::new("XC430-W150", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XC430-W150", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XC430-W150", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XC430-W150", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XC430-W150", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XC430-W150", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XC430-W150", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XC430-W150", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XC430-W150", V2, "TorqueEnable", 64, Byte, RW),
@@ -2312,22 +2730,30 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC430-W150", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XC430-W150", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XC430-W150", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XC430-W150", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XC430-W150", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XC430-W150", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XC430-W150", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XC430-W150", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XC430-W150", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XC430-W150", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XC430-W150", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC430-W150", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XC430-W150", V2, "Moving", 122, Byte, R),
     Reg::new("XC430-W150", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XC430-W150", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XC430-W150", V2, "PresentLoad", 126, Half, R),
-    Reg::new("XC430-W150", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XC430-W150", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XC430-W150", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XC430-W150", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XC430-W150", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XC430-W150", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XC430-W150", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XC430-W150", V2, "PresentLoad", 126, Half, R).signed(),
+    Reg::new("XC430-W150", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XC430-W150", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XC430-W150", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XC430-W150", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XC430-W150", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XC430-W150", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XC430-W150", V2, "BackupReady", 147, Byte, R),
     // XC430-W240 2
     Reg::new("XC430-W240", V2, "ModelNumber", 0, Half, R),
@@ -2340,15 +2766,17 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC430-W240", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XC430-W240", V2, "Secondary", 12, Byte, RW),
     Reg::new("XC430-W240", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XC430-W240", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XC430-W240", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC430-W240", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XC430-W240", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XC430-W240", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XC430-W240", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XC430-W240", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XC430-W240", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XC430-W240", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XC430-W240", V2, "PWMLimit", 36, Half, RW),
-    Reg::new("XC430-W240", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XC430-W240", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XC430-W240", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XC430-W240", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XC430-W240", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XC430-W240", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XC430-W240", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XC430-W240", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XC430-W240", V2, "TorqueEnable", 64, Byte, RW),
@@ -2364,22 +2792,30 @@ pub static REGS: &[Reg] = &[
     Reg::new("XC430-W240", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XC430-W240", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XC430-W240", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XC430-W240", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XC430-W240", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XC430-W240", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XC430-W240", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XC430-W240", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XC430-W240", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XC430-W240", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XC430-W240", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XC430-W240", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XC430-W240", V2, "Moving", 122, Byte, R),
     Reg::new("XC430-W240", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XC430-W240", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XC430-W240", V2, "PresentLoad", 126, Half, R),
-    Reg::new("XC430-W240", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XC430-W240", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XC430-W240", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XC430-W240", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XC430-W240", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XC430-W240", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XC430-W240", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XC430-W240", V2, "PresentLoad", 126, Half, R).signed(),
+    Reg::new("XC430-W240", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XC430-W240", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XC430-W240", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XC430-W240", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XC430-W240", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XC430-W240", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XC430-W240", V2, "BackupReady", 147, Byte, R),
     // XD430-T210 2
     Reg::new("XD430-T210", V2, "ModelNumber", 0, Half, R),
@@ -2392,16 +2828,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XD430-T210", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XD430-T210", V2, "Secondary", 12, Byte, RW),
     Reg::new("XD430-T210", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XD430-T210", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XD430-T210", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XD430-T210", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XD430-T210", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XD430-T210", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XD430-T210", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XD430-T210", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XD430-T210", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XD430-T210", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XD430-T210", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XD430-T210", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XD430-T210", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XD430-T210", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XD430-T210", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XD430-T210", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XD430-T210", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XD430-T210", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XD430-T210", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XD430-T210", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XD430-T210", V2, "TorqueEnable", 64, Byte, RW),
@@ -2417,23 +2855,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XD430-T210", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XD430-T210", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XD430-T210", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XD430-T210", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XD430-T210", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XD430-T210", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XD430-T210", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XD430-T210", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XD430-T210", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XD430-T210", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XD430-T210", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XD430-T210", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XD430-T210", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XD430-T210", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XD430-T210", V2, "Moving", 122, Byte, R),
     Reg::new("XD430-T210", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XD430-T210", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XD430-T210", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XD430-T210", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XD430-T210", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XD430-T210", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XD430-T210", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XD430-T210", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XD430-T210", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XD430-T210", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XD430-T210", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XD430-T210", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XD430-T210", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XD430-T210", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XD430-T210", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XD430-T210", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XD430-T210", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XD430-T210", V2, "BackupReady", 147, Byte, R),
     // XD430-T350 2
     Reg::new("XD430-T350", V2, "ModelNumber", 0, Half, R),
@@ -2446,16 +2892,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XD430-T350", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XD430-T350", V2, "Secondary", 12, Byte, RW),
     Reg::new("XD430-T350", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XD430-T350", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XD430-T350", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XD430-T350", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XD430-T350", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XD430-T350", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XD430-T350", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XD430-T350", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XD430-T350", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XD430-T350", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XD430-T350", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XD430-T350", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XD430-T350", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XD430-T350", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XD430-T350", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XD430-T350", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XD430-T350", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XD430-T350", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XD430-T350", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XD430-T350", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XD430-T350", V2, "TorqueEnable", 64, Byte, RW),
@@ -2471,23 +2919,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XD430-T350", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XD430-T350", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XD430-T350", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XD430-T350", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XD430-T350", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XD430-T350", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XD430-T350", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XD430-T350", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XD430-T350", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XD430-T350", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XD430-T350", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XD430-T350", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XD430-T350", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XD430-T350", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XD430-T350", V2, "Moving", 122, Byte, R),
     Reg::new("XD430-T350", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XD430-T350", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XD430-T350", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XD430-T350", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XD430-T350", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XD430-T350", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XD430-T350", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XD430-T350", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XD430-T350", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XD430-T350", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XD430-T350", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XD430-T350", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XD430-T350", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XD430-T350", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XD430-T350", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XD430-T350", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XD430-T350", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XD430-T350", V2, "BackupReady", 147, Byte, R),
     // XD540-T150 2
     Reg::new("XD540-T150", V2, "ModelNumber", 0, Half, R),
@@ -2500,16 +2956,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XD540-T150", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XD540-T150", V2, "Secondary", 12, Byte, RW),
     Reg::new("XD540-T150", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XD540-T150", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XD540-T150", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XD540-T150", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XD540-T150", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XD540-T150", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XD540-T150", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XD540-T150", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XD540-T150", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XD540-T150", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XD540-T150", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XD540-T150", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XD540-T150", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XD540-T150", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XD540-T150", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XD540-T150", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XD540-T150", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XD540-T150", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XD540-T150", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("XD540-T150", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("XD540-T150", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -2528,23 +2986,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XD540-T150", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XD540-T150", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XD540-T150", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XD540-T150", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XD540-T150", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XD540-T150", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XD540-T150", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XD540-T150", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XD540-T150", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XD540-T150", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XD540-T150", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XD540-T150", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XD540-T150", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XD540-T150", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XD540-T150", V2, "Moving", 122, Byte, R),
     Reg::new("XD540-T150", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XD540-T150", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XD540-T150", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XD540-T150", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XD540-T150", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XD540-T150", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XD540-T150", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XD540-T150", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XD540-T150", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XD540-T150", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XD540-T150", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XD540-T150", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XD540-T150", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XD540-T150", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XD540-T150", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XD540-T150", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XD540-T150", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XD540-T150", V2, "BackupReady", 147, Byte, R),
     Reg::new("XD540-T150", V2, "ExternalPortData1", 152, Half, RW),
     Reg::new("XD540-T150", V2, "ExternalPortData2", 154, Half, RW),
@@ -2560,16 +3026,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XD540-T270", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XD540-T270", V2, "Secondary", 12, Byte, RW),
     Reg::new("XD540-T270", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XD540-T270", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XD540-T270", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XD540-T270", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XD540-T270", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XD540-T270", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XD540-T270", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XD540-T270", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XD540-T270", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XD540-T270", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XD540-T270", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XD540-T270", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XD540-T270", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XD540-T270", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XD540-T270", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XD540-T270", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XD540-T270", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XD540-T270", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XD540-T270", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("XD540-T270", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("XD540-T270", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -2588,23 +3056,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XD540-T270", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XD540-T270", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XD540-T270", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XD540-T270", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XD540-T270", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XD540-T270", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XD540-T270", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XD540-T270", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XD540-T270", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XD540-T270", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XD540-T270", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XD540-T270", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XD540-T270", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XD540-T270", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XD540-T270", V2, "Moving", 122, Byte, R),
     Reg::new("XD540-T270", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XD540-T270", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XD540-T270", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XD540-T270", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XD540-T270", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XD540-T270", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XD540-T270", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XD540-T270", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XD540-T270", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XD540-T270", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XD540-T270", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XD540-T270", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XD540-T270", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XD540-T270", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XD540-T270", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XD540-T270", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XD540-T270", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XD540-T270", V2, "BackupReady", 147, Byte, R),
     Reg::new("XD540-T270", V2, "ExternalPortData1", 152, Half, RW),
     Reg::new("XD540-T270", V2, "ExternalPortData2", 154, Half, RW),
@@ -2620,16 +3096,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH430-V210", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XH430-V210", V2, "Secondary", 12, Byte, RW),
     Reg::new("XH430-V210", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XH430-V210", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XH430-V210", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-V210", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XH430-V210", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XH430-V210", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XH430-V210", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XH430-V210", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XH430-V210", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XH430-V210", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XH430-V210", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XH430-V210", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XH430-V210", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XH430-V210", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XH430-V210", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XH430-V210", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XH430-V210", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XH430-V210", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-V210", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XH430-V210", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XH430-V210", V2, "TorqueEnable", 64, Byte, RW),
@@ -2645,23 +3123,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH430-V210", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XH430-V210", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XH430-V210", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XH430-V210", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XH430-V210", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XH430-V210", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XH430-V210", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XH430-V210", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XH430-V210", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XH430-V210", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XH430-V210", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XH430-V210", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XH430-V210", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-V210", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XH430-V210", V2, "Moving", 122, Byte, R),
     Reg::new("XH430-V210", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XH430-V210", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XH430-V210", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XH430-V210", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XH430-V210", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XH430-V210", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XH430-V210", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XH430-V210", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XH430-V210", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XH430-V210", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XH430-V210", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XH430-V210", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XH430-V210", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XH430-V210", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XH430-V210", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XH430-V210", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XH430-V210", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XH430-V210", V2, "BackupReady", 147, Byte, R),
     // XH430-V350 2
     Reg::new("XH430-V350", V2, "ModelNumber", 0, Half, R),
@@ -2674,16 +3160,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH430-V350", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XH430-V350", V2, "Secondary", 12, Byte, RW),
     Reg::new("XH430-V350", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XH430-V350", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XH430-V350", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-V350", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XH430-V350", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XH430-V350", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XH430-V350", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XH430-V350", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XH430-V350", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XH430-V350", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XH430-V350", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XH430-V350", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XH430-V350", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XH430-V350", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XH430-V350", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XH430-V350", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XH430-V350", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XH430-V350", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-V350", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XH430-V350", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XH430-V350", V2, "TorqueEnable", 64, Byte, RW),
@@ -2699,23 +3187,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH430-V350", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XH430-V350", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XH430-V350", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XH430-V350", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XH430-V350", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XH430-V350", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XH430-V350", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XH430-V350", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XH430-V350", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XH430-V350", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XH430-V350", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XH430-V350", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XH430-V350", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-V350", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XH430-V350", V2, "Moving", 122, Byte, R),
     Reg::new("XH430-V350", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XH430-V350", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XH430-V350", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XH430-V350", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XH430-V350", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XH430-V350", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XH430-V350", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XH430-V350", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XH430-V350", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XH430-V350", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XH430-V350", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XH430-V350", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XH430-V350", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XH430-V350", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XH430-V350", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XH430-V350", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XH430-V350", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XH430-V350", V2, "BackupReady", 147, Byte, R),
     // XH430-W210 2
     Reg::new("XH430-W210", V2, "ModelNumber", 0, Half, R),
@@ -2728,16 +3224,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH430-W210", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XH430-W210", V2, "Secondary", 12, Byte, RW),
     Reg::new("XH430-W210", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XH430-W210", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XH430-W210", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-W210", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XH430-W210", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XH430-W210", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XH430-W210", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XH430-W210", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XH430-W210", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XH430-W210", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XH430-W210", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XH430-W210", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XH430-W210", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XH430-W210", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XH430-W210", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XH430-W210", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XH430-W210", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XH430-W210", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-W210", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XH430-W210", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XH430-W210", V2, "TorqueEnable", 64, Byte, RW),
@@ -2753,23 +3251,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH430-W210", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XH430-W210", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XH430-W210", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XH430-W210", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XH430-W210", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XH430-W210", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XH430-W210", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XH430-W210", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XH430-W210", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XH430-W210", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XH430-W210", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XH430-W210", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XH430-W210", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-W210", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XH430-W210", V2, "Moving", 122, Byte, R),
     Reg::new("XH430-W210", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XH430-W210", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XH430-W210", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XH430-W210", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XH430-W210", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XH430-W210", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XH430-W210", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XH430-W210", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XH430-W210", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XH430-W210", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XH430-W210", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XH430-W210", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XH430-W210", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XH430-W210", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XH430-W210", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XH430-W210", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XH430-W210", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XH430-W210", V2, "BackupReady", 147, Byte, R),
     // XH430-W350 2
     Reg::new("XH430-W350", V2, "ModelNumber", 0, Half, R),
@@ -2782,16 +3288,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH430-W350", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XH430-W350", V2, "Secondary", 12, Byte, RW),
     Reg::new("XH430-W350", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XH430-W350", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XH430-W350", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-W350", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XH430-W350", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XH430-W350", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XH430-W350", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XH430-W350", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XH430-W350", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XH430-W350", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XH430-W350", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XH430-W350", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XH430-W350", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XH430-W350", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XH430-W350", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XH430-W350", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XH430-W350", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XH430-W350", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-W350", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XH430-W350", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XH430-W350", V2, "TorqueEnable", 64, Byte, RW),
@@ -2807,23 +3315,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH430-W350", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XH430-W350", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XH430-W350", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XH430-W350", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XH430-W350", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XH430-W350", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XH430-W350", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XH430-W350", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XH430-W350", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XH430-W350", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XH430-W350", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XH430-W350", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XH430-W350", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH430-W350", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XH430-W350", V2, "Moving", 122, Byte, R),
     Reg::new("XH430-W350", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XH430-W350", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XH430-W350", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XH430-W350", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XH430-W350", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XH430-W350", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XH430-W350", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XH430-W350", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XH430-W350", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XH430-W350", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XH430-W350", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XH430-W350", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XH430-W350", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XH430-W350", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XH430-W350", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XH430-W350", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XH430-W350", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XH430-W350", V2, "BackupReady", 147, Byte, R),
     // XH540-V150 2
     Reg::new("XH540-V150", V2, "ModelNumber", 0, Half, R),
@@ -2836,16 +3352,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH540-V150", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XH540-V150", V2, "Secondary", 12, Byte, RW),
     Reg::new("XH540-V150", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XH540-V150", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XH540-V150", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-V150", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XH540-V150", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XH540-V150", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XH540-V150", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XH540-V150", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XH540-V150", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XH540-V150", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XH540-V150", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XH540-V150", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XH540-V150", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XH540-V150", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XH540-V150", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XH540-V150", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XH540-V150", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XH540-V150", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-V150", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("XH540-V150", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("XH540-V150", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -2864,23 +3382,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH540-V150", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XH540-V150", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XH540-V150", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XH540-V150", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XH540-V150", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XH540-V150", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XH540-V150", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XH540-V150", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XH540-V150", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XH540-V150", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XH540-V150", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XH540-V150", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XH540-V150", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-V150", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XH540-V150", V2, "Moving", 122, Byte, R),
     Reg::new("XH540-V150", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XH540-V150", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XH540-V150", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XH540-V150", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XH540-V150", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XH540-V150", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XH540-V150", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XH540-V150", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XH540-V150", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XH540-V150", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XH540-V150", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XH540-V150", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XH540-V150", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XH540-V150", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XH540-V150", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XH540-V150", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XH540-V150", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XH540-V150", V2, "BackupReady", 147, Byte, R),
     Reg::new("XH540-V150", V2, "ExternalPortData1", 152, Half, RW),
     Reg::new("XH540-V150", V2, "ExternalPortData2", 154, Half, RW),
@@ -2896,16 +3422,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH540-V270", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XH540-V270", V2, "Secondary", 12, Byte, RW),
     Reg::new("XH540-V270", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XH540-V270", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XH540-V270", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-V270", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XH540-V270", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XH540-V270", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XH540-V270", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XH540-V270", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XH540-V270", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XH540-V270", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XH540-V270", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XH540-V270", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XH540-V270", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XH540-V270", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XH540-V270", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XH540-V270", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XH540-V270", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XH540-V270", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-V270", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("XH540-V270", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("XH540-V270", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -2924,23 +3452,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH540-V270", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XH540-V270", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XH540-V270", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XH540-V270", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XH540-V270", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XH540-V270", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XH540-V270", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XH540-V270", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XH540-V270", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XH540-V270", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XH540-V270", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XH540-V270", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XH540-V270", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-V270", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XH540-V270", V2, "Moving", 122, Byte, R),
     Reg::new("XH540-V270", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XH540-V270", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XH540-V270", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XH540-V270", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XH540-V270", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XH540-V270", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XH540-V270", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XH540-V270", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XH540-V270", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XH540-V270", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XH540-V270", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XH540-V270", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XH540-V270", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XH540-V270", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XH540-V270", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XH540-V270", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XH540-V270", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XH540-V270", V2, "BackupReady", 147, Byte, R),
     Reg::new("XH540-V270", V2, "ExternalPortData1", 152, Half, RW),
     Reg::new("XH540-V270", V2, "ExternalPortData2", 154, Half, RW),
@@ -2956,16 +3492,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH540-W150", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XH540-W150", V2, "Secondary", 12, Byte, RW),
     Reg::new("XH540-W150", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XH540-W150", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XH540-W150", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-W150", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XH540-W150", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XH540-W150", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XH540-W150", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XH540-W150", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XH540-W150", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XH540-W150", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XH540-W150", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XH540-W150", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XH540-W150", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XH540-W150", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XH540-W150", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XH540-W150", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XH540-W150", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XH540-W150", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-W150", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("XH540-W150", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("XH540-W150", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -2984,23 +3522,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH540-W150", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XH540-W150", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XH540-W150", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XH540-W150", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XH540-W150", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XH540-W150", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XH540-W150", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XH540-W150", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XH540-W150", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XH540-W150", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XH540-W150", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XH540-W150", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XH540-W150", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-W150", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XH540-W150", V2, "Moving", 122, Byte, R),
     Reg::new("XH540-W150", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XH540-W150", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XH540-W150", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XH540-W150", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XH540-W150", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XH540-W150", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XH540-W150", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XH540-W150", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XH540-W150", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XH540-W150", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XH540-W150", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XH540-W150", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XH540-W150", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XH540-W150", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XH540-W150", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XH540-W150", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XH540-W150", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XH540-W150", V2, "BackupReady", 147, Byte, R),
     Reg::new("XH540-W150", V2, "ExternalPortData1", 152, Half, RW),
     Reg::new("XH540-W150", V2, "ExternalPortData2", 154, Half, RW),
@@ -3016,16 +3562,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH540-W270", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XH540-W270", V2, "Secondary", 12, Byte, RW),
     Reg::new("XH540-W270", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XH540-W270", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XH540-W270", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-W270", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XH540-W270", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XH540-W270", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XH540-W270", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XH540-W270", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XH540-W270", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XH540-W270", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XH540-W270", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XH540-W270", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XH540-W270", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XH540-W270", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XH540-W270", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XH540-W270", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XH540-W270", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XH540-W270", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-W270", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("XH540-W270", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("XH540-W270", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -3044,23 +3592,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XH540-W270", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XH540-W270", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XH540-W270", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XH540-W270", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XH540-W270", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XH540-W270", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XH540-W270", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XH540-W270", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XH540-W270", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XH540-W270", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XH540-W270", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XH540-W270", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XH540-W270", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XH540-W270", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XH540-W270", V2, "Moving", 122, Byte, R),
     Reg::new("XH540-W270", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XH540-W270", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XH540-W270", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XH540-W270", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XH540-W270", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XH540-W270", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XH540-W270", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XH540-W270", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XH540-W270", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XH540-W270", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XH540-W270", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XH540-W270", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XH540-W270", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XH540-W270", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XH540-W270", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XH540-W270", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XH540-W270", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XH540-W270", V2, "BackupReady", 147, Byte, R),
     Reg::new("XH540-W270", V2, "ExternalPortData1", 152, Half, RW),
     Reg::new("XH540-W270", V2, "ExternalPortData2", 154, Half, RW),
@@ -3071,12 +3627,12 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL320", V2, "ID", 3, Byte, RW),
     Reg::new("XL320", V2, "BaudRate", 4, Byte, RW),
     Reg::new("XL320", V2, "ReturnDelayTime", 5, Byte, RW),
-    Reg::new("XL320", V2, "CWAngleLimit", 6, Half, RW),
-    Reg::new("XL320", V2, "CCWAngleLimit", 8, Half, RW),
+    Reg::new("XL320", V2, "CWAngleLimit", 6, Half, RW).unit("deg", AX_DEG, 0.0),
+    Reg::new("XL320", V2, "CCWAngleLimit", 8, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("XL320", V2, "ControlMode", 11, Byte, RW),
-    Reg::new("XL320", V2, "TemperatureLimit", 12, Byte, RW),
-    Reg::new("XL320", V2, "MinVoltageLimit", 13, Byte, RW),
-    Reg::new("XL320", V2, "MaxVoltageLimit", 14, Byte, RW),
+    Reg::new("XL320", V2, "TemperatureLimit", 12, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XL320", V2, "MinVoltageLimit", 13, Byte, RW).unit("V", 0.1, 0.0),
+    Reg::new("XL320", V2, "MaxVoltageLimit", 14, Byte, RW).unit("V", 0.1, 0.0),
     Reg::new("XL320", V2, "MaxTorque", 15, Half, RW),
     Reg::new("XL320", V2, "StatusReturnLevel", 17, Byte, RW),
     Reg::new("XL320", V2, "Shutdown", 18, Byte, RW),
@@ -3085,14 +3641,14 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL320", V2, "DGain", 27, Byte, RW),
     Reg::new("XL320", V2, "IGain", 28, Byte, RW),
     Reg::new("XL320", V2, "PGain", 29, Byte, RW),
-    Reg::new("XL320", V2, "GoalPosition", 30, Half, RW),
+    Reg::new("XL320", V2, "GoalPosition", 30, Half, RW).unit("deg", AX_DEG, 0.0),
     Reg::new("XL320", V2, "MovingSpeed", 32, Half, RW),
     Reg::new("XL320", V2, "TorqueLimit", 35, Half, RW),
-    Reg::new("XL320", V2, "PresentPosition", 37, Half, R),
+    Reg::new("XL320", V2, "PresentPosition", 37, Half, R).unit("deg", AX_DEG, 0.0),
     Reg::new("XL320", V2, "PresentSpeed", 39, Half, R),
     Reg::new("XL320", V2, "PresentLoad", 41, Half, R),
-    Reg::new("XL320", V2, "PresentVoltage", 45, Byte, R),
-    Reg::new("XL320", V2, "PresentTemperature", 46, Byte, R),
+    Reg::new("XL320", V2, "PresentVoltage", 45, Byte, R).unit("V", 0.1, 0.0),
+    Reg::new("XL320", V2, "PresentTemperature", 46, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XL320", V2, "RegisteredInstruction", 47, Byte, R),
     Reg::new("XL320", V2, "Moving", 49, Byte, R),
     Reg::new("XL320", V2, "HardwareErrorStatus", 50, Byte, R),
@@ -3108,16 +3664,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL330-M077", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XL330-M077", V2, "Secondary", 12, Byte, RW),
     Reg::new("XL330-M077", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XL330-M077", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XL330-M077", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XL330-M077", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XL330-M077", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XL330-M077", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XL330-M077", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XL330-M077", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XL330-M077", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XL330-M077", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XL330-M077", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XL330-M077", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XL330-M077", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XL330-M077", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XL330-M077", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XL330-M077", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XL330-M077", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XL330-M077", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XL330-M077", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XL330-M077", V2, "PWMSlope", 62, Byte, RW),
     Reg::new("XL330-M077", V2, "Shutdown", 63, Byte, RW),
@@ -3134,23 +3692,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL330-M077", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XL330-M077", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XL330-M077", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XL330-M077", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XL330-M077", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XL330-M077", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XL330-M077", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XL330-M077", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XL330-M077", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XL330-M077", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XL330-M077", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XL330-M077", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XL330-M077", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XL330-M077", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XL330-M077", V2, "Moving", 122, Byte, R),
     Reg::new("XL330-M077", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XL330-M077", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XL330-M077", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XL330-M077", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XL330-M077", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XL330-M077", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XL330-M077", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XL330-M077", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XL330-M077", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XL330-M077", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XL330-M077", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XL330-M077", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XL330-M077", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XL330-M077", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XL330-M077", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XL330-M077", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XL330-M077", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XL330-M077", V2, "BackupReady", 147, Byte, R),
     // XL330-M288 2
     Reg::new("XL330-M288", V2, "ModelNumber", 0, Half, R),
@@ -3163,16 +3729,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL330-M288", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XL330-M288", V2, "Secondary", 12, Byte, RW),
     Reg::new("XL330-M288", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XL330-M288", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XL330-M288", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XL330-M288", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XL330-M288", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XL330-M288", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XL330-M288", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XL330-M288", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XL330-M288", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XL330-M288", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XL330-M288", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XL330-M288", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XL330-M288", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XL330-M288", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XL330-M288", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XL330-M288", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XL330-M288", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XL330-M288", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XL330-M288", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XL330-M288", V2, "PWMSlope", 62, Byte, RW),
     Reg::new("XL330-M288", V2, "Shutdown", 63, Byte, RW),
@@ -3189,23 +3757,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL330-M288", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XL330-M288", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XL330-M288", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XL330-M288", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XL330-M288", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XL330-M288", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XL330-M288", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XL330-M288", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XL330-M288", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XL330-M288", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XL330-M288", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XL330-M288", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XL330-M288", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XL330-M288", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XL330-M288", V2, "Moving", 122, Byte, R),
     Reg::new("XL330-M288", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XL330-M288", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XL330-M288", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XL330-M288", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XL330-M288", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XL330-M288", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XL330-M288", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XL330-M288", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XL330-M288", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XL330-M288", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XL330-M288", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XL330-M288", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XL330-M288", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XL330-M288", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XL330-M288", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XL330-M288", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XL330-M288", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XL330-M288", V2, "BackupReady", 147, Byte, R),
     // XL430-W250 2
     Reg::new("XL430-W250", V2, "ModelNumber", 0, Half, R),
@@ -3218,15 +3794,17 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL430-W250", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XL430-W250", V2, "Secondary", 12, Byte, RW),
     Reg::new("XL430-W250", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XL430-W250", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XL430-W250", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XL430-W250", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XL430-W250", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XL430-W250", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XL430-W250", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XL430-W250", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XL430-W250", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XL430-W250", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XL430-W250", V2, "PWMLimit", 36, Half, RW),
-    Reg::new("XL430-W250", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XL430-W250", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XL430-W250", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XL430-W250", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XL430-W250", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XL430-W250", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XL430-W250", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XL430-W250", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XL430-W250", V2, "TorqueEnable", 64, Byte, RW),
@@ -3242,22 +3820,30 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL430-W250", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XL430-W250", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XL430-W250", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XL430-W250", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XL430-W250", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XL430-W250", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XL430-W250", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XL430-W250", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XL430-W250", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XL430-W250", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XL430-W250", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XL430-W250", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XL430-W250", V2, "Moving", 122, Byte, R),
     Reg::new("XL430-W250", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XL430-W250", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XL430-W250", V2, "PresentLoad", 126, Half, R),
-    Reg::new("XL430-W250", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XL430-W250", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XL430-W250", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XL430-W250", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XL430-W250", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XL430-W250", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XL430-W250", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XL430-W250", V2, "PresentLoad", 126, Half, R).signed(),
+    Reg::new("XL430-W250", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XL430-W250", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XL430-W250", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XL430-W250", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XL430-W250", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XL430-W250", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XL430-W250", V2, "BackupReady", 147, Byte, R),
     // XL430-W250-TEST 2
     Reg::new("XL430-W250-TEST", V2, "ModelNumber{:.popup}", 0, Half, R),
@@ -3363,22 +3949,22 @@ pub static REGS: &[Reg] = &[
     Reg::new("XL430-W250-TEST", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XL430-W250-TEST", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XL430-W250-TEST", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XL430-W250-TEST", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XL430-W250-TEST", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XL430-W250-TEST", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XL430-W250-TEST", V2, "GoalVelocity", 104, Word, RW).signed(),
     Reg::new("XL430-W250-TEST", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XL430-W250-TEST", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XL430-W250-TEST", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XL430-W250-TEST", V2, "GoalPosition", 116, Word, RW).signed(),
     Reg::new("XL430-W250-TEST", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XL430-W250-TEST", V2, "Moving", 122, Byte, R),
     Reg::new("XL430-W250-TEST", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XL430-W250-TEST", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XL430-W250-TEST", V2, "PresentLoad", 126, Half, R),
-    Reg::new("XL430-W250-TEST", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XL430-W250-TEST", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XL430-W250-TEST", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XL430-W250-TEST", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XL430-W250-TEST", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XL430-W250-TEST", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XL430-W250-TEST", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XL430-W250-TEST", V2, "PresentLoad", 126, Half, R).signed(),
+    Reg::new("XL430-W250-TEST", V2, "PresentVelocity", 128, Word, R).signed(),
+    Reg::new("XL430-W250-TEST", V2, "PresentPosition", 132, Word, R).signed(),
+    Reg::new("XL430-W250-TEST", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XL430-W250-TEST", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XL430-W250-TEST", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XL430-W250-TEST", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     // XM430-W210 2
     Reg::new("XM430-W210", V2, "ModelNumber", 0, Half, R),
     Reg::new("XM430-W210", V2, "ModelInformation", 2, Word, R),
@@ -3390,16 +3976,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XM430-W210", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XM430-W210", V2, "Secondary", 12, Byte, RW),
     Reg::new("XM430-W210", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XM430-W210", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XM430-W210", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XM430-W210", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XM430-W210", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XM430-W210", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XM430-W210", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XM430-W210", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XM430-W210", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XM430-W210", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XM430-W210", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XM430-W210", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XM430-W210", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XM430-W210", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XM430-W210", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XM430-W210", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XM430-W210", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XM430-W210", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XM430-W210", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XM430-W210", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XM430-W210", V2, "TorqueEnable", 64, Byte, RW),
@@ -3415,23 +4003,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XM430-W210", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XM430-W210", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XM430-W210", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XM430-W210", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XM430-W210", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XM430-W210", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XM430-W210", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XM430-W210", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XM430-W210", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XM430-W210", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XM430-W210", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XM430-W210", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XM430-W210", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XM430-W210", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XM430-W210", V2, "Moving", 122, Byte, R),
     Reg::new("XM430-W210", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XM430-W210", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XM430-W210", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XM430-W210", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XM430-W210", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XM430-W210", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XM430-W210", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XM430-W210", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XM430-W210", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XM430-W210", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XM430-W210", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XM430-W210", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XM430-W210", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XM430-W210", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XM430-W210", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XM430-W210", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XM430-W210", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XM430-W210", V2, "BackupReady", 147, Byte, R),
     // XM430-W350 2
     Reg::new("XM430-W350", V2, "ModelNumber", 0, Half, R),
@@ -3444,16 +4040,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XM430-W350", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XM430-W350", V2, "Secondary", 12, Byte, RW),
     Reg::new("XM430-W350", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XM430-W350", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XM430-W350", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XM430-W350", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XM430-W350", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XM430-W350", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XM430-W350", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XM430-W350", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XM430-W350", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XM430-W350", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XM430-W350", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XM430-W350", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XM430-W350", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XM430-W350", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XM430-W350", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XM430-W350", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XM430-W350", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XM430-W350", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XM430-W350", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XM430-W350", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XM430-W350", V2, "TorqueEnable", 64, Byte, RW),
@@ -3469,23 +4067,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XM430-W350", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XM430-W350", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XM430-W350", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XM430-W350", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XM430-W350", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XM430-W350", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XM430-W350", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XM430-W350", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XM430-W350", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XM430-W350", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XM430-W350", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XM430-W350", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XM430-W350", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XM430-W350", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XM430-W350", V2, "Moving", 122, Byte, R),
     Reg::new("XM430-W350", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XM430-W350", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XM430-W350", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XM430-W350", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XM430-W350", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XM430-W350", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XM430-W350", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XM430-W350", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XM430-W350", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XM430-W350", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XM430-W350", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XM430-W350", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XM430-W350", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XM430-W350", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XM430-W350", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XM430-W350", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XM430-W350", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XM430-W350", V2, "BackupReady", 147, Byte, R),
     // XM540-W150 2
     Reg::new("XM540-W150", V2, "ModelNumber", 0, Half, R),
@@ -3498,16 +4104,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XM540-W150", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XM540-W150", V2, "Secondary", 12, Byte, RW),
     Reg::new("XM540-W150", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XM540-W150", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XM540-W150", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XM540-W150", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XM540-W150", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XM540-W150", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XM540-W150", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XM540-W150", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XM540-W150", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XM540-W150", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XM540-W150", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XM540-W150", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XM540-W150", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XM540-W150", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XM540-W150", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XM540-W150", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XM540-W150", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XM540-W150", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XM540-W150", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("XM540-W150", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("XM540-W150", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -3526,23 +4134,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XM540-W150", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XM540-W150", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XM540-W150", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XM540-W150", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XM540-W150", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XM540-W150", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XM540-W150", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XM540-W150", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XM540-W150", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XM540-W150", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XM540-W150", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XM540-W150", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XM540-W150", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XM540-W150", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XM540-W150", V2, "Moving", 122, Byte, R),
     Reg::new("XM540-W150", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XM540-W150", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XM540-W150", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XM540-W150", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XM540-W150", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XM540-W150", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XM540-W150", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XM540-W150", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XM540-W150", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XM540-W150", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XM540-W150", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XM540-W150", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XM540-W150", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XM540-W150", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XM540-W150", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XM540-W150", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XM540-W150", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XM540-W150", V2, "BackupReady", 147, Byte, R),
     Reg::new("XM540-W150", V2, "ExternalPortData1", 152, Half, RW),
     Reg::new("XM540-W150", V2, "ExternalPortData2", 154, Half, RW),
@@ -3558,16 +4174,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XM540-W270", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XM540-W270", V2, "Secondary", 12, Byte, RW),
     Reg::new("XM540-W270", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XM540-W270", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XM540-W270", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XM540-W270", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XM540-W270", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XM540-W270", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XM540-W270", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XM540-W270", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XM540-W270", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XM540-W270", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XM540-W270", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XM540-W270", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XM540-W270", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XM540-W270", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XM540-W270", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XM540-W270", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XM540-W270", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XM540-W270", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XM540-W270", V2, "ExternalPortMode1", 56, Byte, RW),
     Reg::new("XM540-W270", V2, "ExternalPortMode2", 57, Byte, RW),
     Reg::new("XM540-W270", V2, "ExternalPortMode3", 58, Byte, RW),
@@ -3586,23 +4204,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XM540-W270", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XM540-W270", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XM540-W270", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XM540-W270", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XM540-W270", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XM540-W270", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XM540-W270", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XM540-W270", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XM540-W270", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XM540-W270", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XM540-W270", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XM540-W270", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XM540-W270", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XM540-W270", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XM540-W270", V2, "Moving", 122, Byte, R),
     Reg::new("XM540-W270", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XM540-W270", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XM540-W270", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XM540-W270", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XM540-W270", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XM540-W270", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XM540-W270", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XM540-W270", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XM540-W270", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XM540-W270", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XM540-W270", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XM540-W270", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XM540-W270", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XM540-W270", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XM540-W270", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XM540-W270", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XM540-W270", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XM540-W270", V2, "BackupReady", 147, Byte, R),
     Reg::new("XM540-W270", V2, "ExternalPortData1", 152, Half, RW),
     Reg::new("XM540-W270", V2, "ExternalPortData2", 154, Half, RW),
@@ -3618,16 +4244,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XW430-T200", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XW430-T200", V2, "Secondary", 12, Byte, RW),
     Reg::new("XW430-T200", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XW430-T200", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XW430-T200", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XW430-T200", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XW430-T200", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XW430-T200", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XW430-T200", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XW430-T200", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XW430-T200", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XW430-T200", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XW430-T200", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XW430-T200", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XW430-T200", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XW430-T200", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XW430-T200", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XW430-T200", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XW430-T200", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XW430-T200", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XW430-T200", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XW430-T200", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XW430-T200", V2, "TorqueEnable", 64, Byte, RW),
@@ -3642,23 +4270,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XW430-T200", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XW430-T200", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XW430-T200", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XW430-T200", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XW430-T200", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XW430-T200", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XW430-T200", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XW430-T200", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XW430-T200", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XW430-T200", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XW430-T200", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XW430-T200", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XW430-T200", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XW430-T200", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XW430-T200", V2, "Moving", 122, Byte, R),
     Reg::new("XW430-T200", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XW430-T200", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XW430-T200", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XW430-T200", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XW430-T200", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XW430-T200", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XW430-T200", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XW430-T200", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XW430-T200", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XW430-T200", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XW430-T200", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XW430-T200", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XW430-T200", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XW430-T200", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XW430-T200", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XW430-T200", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XW430-T200", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XW430-T200", V2, "BackupReady", 147, Byte, R),
     // XW430-T333 2
     Reg::new("XW430-T333", V2, "ModelNumber", 0, Half, R),
@@ -3671,16 +4307,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XW430-T333", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XW430-T333", V2, "Secondary", 12, Byte, RW),
     Reg::new("XW430-T333", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XW430-T333", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XW430-T333", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XW430-T333", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XW430-T333", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XW430-T333", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XW430-T333", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XW430-T333", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XW430-T333", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XW430-T333", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XW430-T333", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XW430-T333", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XW430-T333", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XW430-T333", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XW430-T333", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XW430-T333", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XW430-T333", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XW430-T333", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XW430-T333", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XW430-T333", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XW430-T333", V2, "TorqueEnable", 64, Byte, RW),
@@ -3695,23 +4333,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XW430-T333", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XW430-T333", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XW430-T333", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XW430-T333", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XW430-T333", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XW430-T333", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XW430-T333", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XW430-T333", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XW430-T333", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XW430-T333", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XW430-T333", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XW430-T333", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XW430-T333", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XW430-T333", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XW430-T333", V2, "Moving", 122, Byte, R),
     Reg::new("XW430-T333", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XW430-T333", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XW430-T333", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XW430-T333", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XW430-T333", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XW430-T333", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XW430-T333", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XW430-T333", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XW430-T333", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XW430-T333", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XW430-T333", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XW430-T333", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XW430-T333", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XW430-T333", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XW430-T333", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XW430-T333", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XW430-T333", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XW430-T333", V2, "BackupReady", 147, Byte, R),
     // XW540-T140 2
     Reg::new("XW540-T140", V2, "ModelNumber", 0, Half, R),
@@ -3724,16 +4370,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XW540-T140", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XW540-T140", V2, "Secondary", 12, Byte, RW),
     Reg::new("XW540-T140", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XW540-T140", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XW540-T140", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XW540-T140", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XW540-T140", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XW540-T140", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XW540-T140", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XW540-T140", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XW540-T140", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XW540-T140", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XW540-T140", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XW540-T140", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XW540-T140", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XW540-T140", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XW540-T140", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XW540-T140", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XW540-T140", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XW540-T140", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XW540-T140", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XW540-T140", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XW540-T140", V2, "TorqueEnable", 64, Byte, RW),
@@ -3748,23 +4396,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XW540-T140", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XW540-T140", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XW540-T140", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XW540-T140", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XW540-T140", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XW540-T140", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XW540-T140", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XW540-T140", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XW540-T140", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XW540-T140", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XW540-T140", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XW540-T140", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XW540-T140", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XW540-T140", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XW540-T140", V2, "Moving", 122, Byte, R),
     Reg::new("XW540-T140", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XW540-T140", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XW540-T140", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XW540-T140", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XW540-T140", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XW540-T140", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XW540-T140", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XW540-T140", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XW540-T140", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XW540-T140", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XW540-T140", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XW540-T140", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XW540-T140", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XW540-T140", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XW540-T140", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XW540-T140", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XW540-T140", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XW540-T140", V2, "BackupReady", 147, Byte, R),
     // XW540-T260 2
     Reg::new("XW540-T260", V2, "ModelNumber", 0, Half, R),
@@ -3777,16 +4433,18 @@ pub static REGS: &[Reg] = &[
     Reg::new("XW540-T260", V2, "OperatingMode", 11, Byte, RW),
     Reg::new("XW540-T260", V2, "Secondary", 12, Byte, RW),
     Reg::new("XW540-T260", V2, "ProtocolType", 13, Byte, RW),
-    Reg::new("XW540-T260", V2, "HomingOffset", 20, Word, RW),
+    Reg::new("XW540-T260", V2, "HomingOffset", 20, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XW540-T260", V2, "MovingThreshold", 24, Word, RW),
-    Reg::new("XW540-T260", V2, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("XW540-T260", V2, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("XW540-T260", V2, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("XW540-T260", V2, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("XW540-T260", V2, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("XW540-T260", V2, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("XW540-T260", V2, "PWMLimit", 36, Half, RW),
     Reg::new("XW540-T260", V2, "CurrentLimit", 38, Half, RW),
-    Reg::new("XW540-T260", V2, "VelocityLimit", 44, Word, RW),
-    Reg::new("XW540-T260", V2, "MaxPositionLimit", 48, Word, RW),
-    Reg::new("XW540-T260", V2, "MinPositionLimit", 52, Word, RW),
+    Reg::new("XW540-T260", V2, "VelocityLimit", 44, Word, RW).unit("rpm", 0.229, 0.0),
+    Reg::new("XW540-T260", V2, "MaxPositionLimit", 48, Word, RW).unit("deg", MX_DEG, 0.0),
+    Reg::new("XW540-T260", V2, "MinPositionLimit", 52, Word, RW).unit("deg", MX_DEG, 0.0),
     Reg::new("XW540-T260", V2, "StartupConfiguration", 60, Byte, RW),
     Reg::new("XW540-T260", V2, "Shutdown", 63, Byte, RW),
     Reg::new("XW540-T260", V2, "TorqueEnable", 64, Byte, RW),
@@ -3801,23 +4459,31 @@ pub static REGS: &[Reg] = &[
     Reg::new("XW540-T260", V2, "Feedforward2ndGain", 88, Half, RW),
     Reg::new("XW540-T260", V2, "Feedforward1stGain", 90, Half, RW),
     Reg::new("XW540-T260", V2, "BusWatchdog", 98, Byte, RW),
-    Reg::new("XW540-T260", V2, "GoalPWM", 100, Half, RW),
-    Reg::new("XW540-T260", V2, "GoalCurrent", 102, Half, RW),
-    Reg::new("XW540-T260", V2, "GoalVelocity", 104, Word, RW),
+    Reg::new("XW540-T260", V2, "GoalPWM", 100, Half, RW).signed(),
+    Reg::new("XW540-T260", V2, "GoalCurrent", 102, Half, RW).signed(),
+    Reg::new("XW540-T260", V2, "GoalVelocity", 104, Word, RW)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
     Reg::new("XW540-T260", V2, "ProfileAcceleration", 108, Word, RW),
     Reg::new("XW540-T260", V2, "ProfileVelocity", 112, Word, RW),
-    Reg::new("XW540-T260", V2, "GoalPosition", 116, Word, RW),
+    Reg::new("XW540-T260", V2, "GoalPosition", 116, Word, RW)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
     Reg::new("XW540-T260", V2, "RealtimeTick", 120, Half, R),
     Reg::new("XW540-T260", V2, "Moving", 122, Byte, R),
     Reg::new("XW540-T260", V2, "MovingStatus", 123, Byte, R),
-    Reg::new("XW540-T260", V2, "PresentPWM", 124, Half, R),
-    Reg::new("XW540-T260", V2, "PresentCurrent", 126, Half, R),
-    Reg::new("XW540-T260", V2, "PresentVelocity", 128, Word, R),
-    Reg::new("XW540-T260", V2, "PresentPosition", 132, Word, R),
-    Reg::new("XW540-T260", V2, "VelocityTrajectory", 136, Word, R),
-    Reg::new("XW540-T260", V2, "PositionTrajectory", 140, Word, R),
-    Reg::new("XW540-T260", V2, "PresentInputVoltage", 144, Half, R),
-    Reg::new("XW540-T260", V2, "PresentTemperature", 146, Byte, R),
+    Reg::new("XW540-T260", V2, "PresentPWM", 124, Half, R).signed(),
+    Reg::new("XW540-T260", V2, "PresentCurrent", 126, Half, R).signed(),
+    Reg::new("XW540-T260", V2, "PresentVelocity", 128, Word, R)
+        .signed()
+        .unit("rpm", 0.229, 0.0),
+    Reg::new("XW540-T260", V2, "PresentPosition", 132, Word, R)
+        .signed()
+        .unit("deg", MX_DEG, 0.0),
+    Reg::new("XW540-T260", V2, "VelocityTrajectory", 136, Word, R).signed(),
+    Reg::new("XW540-T260", V2, "PositionTrajectory", 140, Word, R).signed(),
+    Reg::new("XW540-T260", V2, "PresentInputVoltage", 144, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("XW540-T260", V2, "PresentTemperature", 146, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("XW540-T260", V2, "BackupReady", 147, Byte, R),
     // CM-150 2
     Reg::new("CM-150", V2, "ModelNumber", 0, Half, R),
@@ -4114,7 +4780,7 @@ pub static REGS: &[Reg] = &[
     Reg::new("CM-550", V2, "BaudRate", 12, Byte, RW),
     Reg::new("CM-550", V2, "BaudRate", 13, Byte, RW),
     Reg::new("CM-550", V2, "ContorllerDirection", 15, Byte, RW),
-    Reg::new("CM-550", V2, "TemperatureLimit", 16, Byte, RW),
+    Reg::new("CM-550", V2, "TemperatureLimit", 16, Byte, RW).unit("degC", 1.0, 0.0),
     Reg::new("CM-550", V2, "<br>BypassPort", 20, Byte, R),
     Reg::new("CM-550", V2, "ModeNumber", 21, Byte, RW),
     Reg::new("CM-550", V2, "DxlPowerSwitch", 22, Byte, RW),
@@ -4372,7 +5038,7 @@ pub static REGS: &[Reg] = &[
     Reg::new("OP", V1, "ACC_X", 44, Half, R),
     Reg::new("OP", V1, "ACC_Y", 46, Half, R),
     Reg::new("OP", V1, "ACC_Z", 48, Half, R),
-    Reg::new("OP", V1, "PresentVoltage", 50, Byte, R),
+    Reg::new("OP", V1, "PresentVoltage", 50, Byte, R).unit("V", 0.1, 0.0),
     Reg::new("OP", V1, "MIC1", 51, Half, R),
     Reg::new("OP", V1, "ADC2", 53, Half, R),
     Reg::new("OP", V1, "ADC3", 55, Half, R),
@@ -4401,7 +5067,7 @@ pub static REGS: &[Reg] = &[
     Reg::new("OP", V1, "FSR4", 32, Half, R),
     Reg::new("OP", V1, "FSR_Central_X", 34, Byte, R),
     Reg::new("OP", V1, "FSR_Central_Y", 35, Byte, R),
-    Reg::new("OP", V1, "PresentVoltage", 42, Byte, R),
+    Reg::new("OP", V1, "PresentVoltage", 42, Byte, R).unit("V", 0.1, 0.0),
     Reg::new("OP", V1, "Registered", 44, Byte, R),
     Reg::new("OP", V1, "Lock", 47, Byte, RW),
     // OP2 1
@@ -4422,7 +5088,7 @@ pub static REGS: &[Reg] = &[
     Reg::new("OP2", V1, "ACC_X", 44, Half, R),
     Reg::new("OP2", V1, "ACC_Y", 46, Half, R),
     Reg::new("OP2", V1, "ACC_Z", 48, Half, R),
-    Reg::new("OP2", V1, "PresentVoltage", 50, Byte, R),
+    Reg::new("OP2", V1, "PresentVoltage", 50, Byte, R).unit("V", 0.1, 0.0),
     Reg::new("OP2", V1, "MIC1", 51, Half, R),
     Reg::new("OP2", V1, "ADC2", 53, Half, R),
     Reg::new("OP2", V1, "ADC3", 55, Half, R),
@@ -4473,9 +5139,9 @@ pub static REGS: &[Reg] = &[
     Reg::new("RH-P12-RNA", V1, "ProtocolType", 13, Byte, RW),
     Reg::new("RH-P12-RNA", V1, "HomingOffset", 20, Word, RW),
     Reg::new("RH-P12-RNA", V1, "MovingThreshold", 24, Word, RW),
-    Reg::new("RH-P12-RNA", V1, "TemperatureLimit", 31, Byte, RW),
-    Reg::new("RH-P12-RNA", V1, "MaxVoltageLimit", 32, Half, RW),
-    Reg::new("RH-P12-RNA", V1, "MinVoltageLimit", 34, Half, RW),
+    Reg::new("RH-P12-RNA", V1, "TemperatureLimit", 31, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("RH-P12-RNA", V1, "MaxVoltageLimit", 32, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("RH-P12-RNA", V1, "MinVoltageLimit", 34, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("RH-P12-RNA", V1, "PWMLimit", 36, Half, RW),
     Reg::new("RH-P12-RNA", V1, "CurrentLimit", 38, Half, RW),
     Reg::new("RH-P12-RNA", V1, "AccelerationLimit", 40, Word, RW),
@@ -4517,8 +5183,8 @@ pub static REGS: &[Reg] = &[
     Reg::new("RH-P12-RNA", V1, "PresentPosition", 580, Word, R),
     Reg::new("RH-P12-RNA", V1, "VelocityTrajectory", 584, Word, R),
     Reg::new("RH-P12-RNA", V1, "PositionTrajectory", 588, Word, R),
-    Reg::new("RH-P12-RNA", V1, "PresentInputVoltage", 592, Half, R),
-    Reg::new("RH-P12-RNA", V1, "PresentTemperature", 594, Byte, R),
+    Reg::new("RH-P12-RNA", V1, "PresentInputVoltage", 592, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("RH-P12-RNA", V1, "PresentTemperature", 594, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("RH-P12-RNA", V1, "ExternalPortData1", 600, Half, RW),
     Reg::new("RH-P12-RNA", V1, "ExternalPortData2", 602, Half, RW),
     Reg::new("RH-P12-RNA", V1, "ExternalPortData3", 604, Half, RW),
@@ -4532,9 +5198,9 @@ pub static REGS: &[Reg] = &[
     Reg::new("RH-P12-RN", V1, "ReturnDelayTime", 9, Byte, RW),
     Reg::new("RH-P12-RN", V1, "OperatingMode", 11, Byte, RW),
     Reg::new("RH-P12-RN", V1, "MovingThreshold", 17, Word, RW),
-    Reg::new("RH-P12-RN", V1, "TemperatureLimit", 21, Byte, RW),
-    Reg::new("RH-P12-RN", V1, "MaxVoltageLimit", 22, Half, RW),
-    Reg::new("RH-P12-RN", V1, "MinVoltageLimit", 24, Half, RW),
+    Reg::new("RH-P12-RN", V1, "TemperatureLimit", 21, Byte, RW).unit("degC", 1.0, 0.0),
+    Reg::new("RH-P12-RN", V1, "MaxVoltageLimit", 22, Half, RW).unit("V", 0.1, 0.0),
+    Reg::new("RH-P12-RN", V1, "MinVoltageLimit", 24, Half, RW).unit("V", 0.1, 0.0),
     Reg::new("RH-P12-RN", V1, "AccelerationLimit", 26, Word, RW),
     Reg::new("RH-P12-RN", V1, "CurrentLimit", 30, Half, RW),
     Reg::new("RH-P12-RN", V1, "VelocityLimit", 32, Word, RW),
@@ -4560,8 +5226,8 @@ pub static REGS: &[Reg] = &[
     Reg::new("RH-P12-RN", V1, "PresentPosition", 611, Word, R),
     Reg::new("RH-P12-RN", V1, "PresentVelocity", 615, Word, R),
     Reg::new("RH-P12-RN", V1, "PresentCurrent", 621, Half, R),
-    Reg::new("RH-P12-RN", V1, "PresentInputVoltage", 623, Half, R),
-    Reg::new("RH-P12-RN", V1, "PresentTemperature", 625, Byte, R),
+    Reg::new("RH-P12-RN", V1, "PresentInputVoltage", 623, Half, R).unit("V", 0.1, 0.0),
+    Reg::new("RH-P12-RN", V1, "PresentTemperature", 625, Byte, R).unit("degC", 1.0, 0.0),
     Reg::new("RH-P12-RN", V1, "ExternalPortData1", 626, Half, RW),
     Reg::new("RH-P12-RN", V1, "ExternalPortData2", 628, Half, RW),
     Reg::new("RH-P12-RN", V1, "ExternalPortData3", 630, Half, RW),
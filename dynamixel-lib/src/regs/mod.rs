@@ -3,13 +3,13 @@ mod db;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, str::FromStr, sync::Mutex};
 use thiserror::Error;
 
 use crate::protocol::ProtocolVersion;
 use db::REGS;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Access {
     R,
     W,
@@ -26,6 +26,21 @@ impl Display for Access {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Area {
+    Eeprom,
+    Ram,
+}
+
+impl Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Area::Eeprom => "EEPROM".fmt(f),
+            Area::Ram => "RAM".fmt(f),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RegSize {
     Byte = 1,
@@ -34,6 +49,27 @@ pub enum RegSize {
     Variable = 0,
 }
 
+/// Physical unit of a register value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unit {
+    pub name: &'static str,
+    /// Unit value of one raw step
+    pub scale: f64,
+    /// Unit value of raw zero
+    pub offset: f64,
+}
+
+impl Unit {
+    pub fn value(&self, raw: i64) -> f64 {
+        raw as f64 * self.scale + self.offset
+    }
+
+    /// Decimal places needed to show one raw step
+    pub fn precision(&self) -> usize {
+        (-self.scale.log10()).ceil().clamp(0.0, 6.0) as usize
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Reg {
     pub model: &'static str,
@@ -42,6 +78,9 @@ pub struct Reg {
     pub address: u16,
     pub size: RegSize,
     pub access: Access,
+    /// Two's complement value
+    pub signed: bool,
+    pub unit: Option<Unit>,
 }
 
 impl Reg {
@@ -60,6 +99,48 @@ impl Reg {
             address,
             size,
             access,
+            signed: false,
+            unit: None,
+        }
+    }
+
+    pub const fn signed(self) -> Self {
+        Reg {
+            signed: true,
+            ..self
+        }
+    }
+
+    pub const fn unit(self, name: &'static str, scale: f64, offset: f64) -> Self {
+        Reg {
+            unit: Some(Unit {
+                name,
+                scale,
+                offset,
+            }),
+            ..self
+        }
+    }
+
+    /// Interpret a raw register value, sign extending signed registers
+    pub fn value_from_raw(&self, raw: u32) -> i64 {
+        match (self.signed, self.size) {
+            (true, RegSize::Byte) => raw as u8 as i8 as i64,
+            (true, RegSize::Half) => raw as u16 as i16 as i64,
+            (true, _) => raw as i32 as i64,
+            (false, _) => raw as i64,
+        }
+    }
+
+    /// Raw register value of `value`, `None` if out of range for the register
+    pub fn value_to_raw(&self, value: i64) -> Option<u32> {
+        match (self.signed, self.size) {
+            (true, RegSize::Byte) => i8::try_from(value).ok().map(|v| v as u8 as u32),
+            (true, RegSize::Half) => i16::try_from(value).ok().map(|v| v as u16 as u32),
+            (true, _) => i32::try_from(value).ok().map(|v| v as u32),
+            (false, RegSize::Byte) => u8::try_from(value).ok().map(u32::from),
+            (false, RegSize::Half) => u16::try_from(value).ok().map(u32::from),
+            (false, _) => u32::try_from(value).ok(),
         }
     }
 }
@@ -74,16 +155,18 @@ impl Display for Reg {
     }
 }
 
-#[derive(Debug)]
+/// Register named as `MODEL/REGISTER`, or just `REGISTER` for the model of
+/// the device it is read from
+#[derive(Debug, Clone)]
 pub struct RegSpec {
-    pub model: String,
+    pub model: Option<String>,
     pub name: String,
 }
 
 #[derive(Error, Debug)]
 pub enum RegSpecError {
-    #[error("invalid register specification")]
-    BadRegSpec,
+    #[error("expected [MODEL/]REGISTER like AX-12A/GoalPosition, got '{0}'")]
+    BadRegSpec(String),
 }
 
 impl FromStr for RegSpec {
@@ -91,21 +174,46 @@ impl FromStr for RegSpec {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         lazy_static! {
-            static ref RE: Regex = Regex::new(r"^([-_[:alnum:]]+)/([-_[:alnum:]]+)$").unwrap();
+            static ref RE: Regex = Regex::new(r"^(?:([-_[:alnum:]]+)/)?([-_[:alnum:]]+)$").unwrap();
         }
         if let Some(cap) = RE.captures(s) {
             Ok(RegSpec {
-                model: cap.get(1).unwrap().as_str().to_string(),
+                model: cap.get(1).map(|model| model.as_str().to_string()),
                 name: cap.get(2).unwrap().as_str().to_string(),
             })
         } else {
-            Err(RegSpecError::BadRegSpec)
+            Err(RegSpecError::BadRegSpec(s.to_string()))
         }
     }
 }
 
+/// Registers added with `add_registers`
+static EXTRA_REGS: Mutex<&'static [Reg]> = Mutex::new(&[]);
+
+/// Add registers to the built-in database, e.g. from a file. A register with
+/// the model, protocol and name of a built-in one replaces it. Meant to be
+/// called once at startup, as each call leaks the combined set.
+pub fn add_registers(regs: &[Reg]) {
+    let mut extra = EXTRA_REGS.lock().unwrap();
+    let combined: Vec<Reg> = regs.iter().chain(extra.iter()).cloned().collect();
+    *extra = Box::leak(combined.into_boxed_slice());
+}
+
+/// Added registers followed by the built-in ones they don't replace
+fn registers() -> impl Iterator<Item = &'static Reg> {
+    let extra: &'static [Reg] = *EXTRA_REGS.lock().unwrap();
+    let replaced = move |reg: &Reg| {
+        extra
+            .iter()
+            .any(|e| e.proto == reg.proto && e.model == reg.model && e.name == reg.name)
+    };
+    extra
+        .iter()
+        .chain(REGS.iter().filter(move |reg| !replaced(reg)))
+}
+
 pub fn list_models(proto: ProtocolVersion) -> Vec<&'static str> {
-    REGS.iter()
+    registers()
         .filter(|reg| reg.proto == proto)
         .map(|reg| reg.model)
         .unique()
@@ -113,17 +221,286 @@ pub fn list_models(proto: ProtocolVersion) -> Vec<&'static str> {
         .collect()
 }
 
+/// Levenshtein distance between `a` and `b`
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let sub = diag + usize::from(ca != cb);
+            diag = row[j + 1];
+            row[j + 1] = sub.min(row[j] + 1).min(diag + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Up to three of `candidates` close to `name`, closest first, for "did
+/// you mean" hints. Case, dashes and underscores are ignored, so that
+/// `goal_position` finds `GoalPosition`. Candidates starting with `name`
+/// count as close, so that `XM430` finds `XM430-W350`.
+pub fn similar_names<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let normalize = |s: &str| -> Vec<char> {
+        s.chars()
+            .filter(|c| *c != '-' && *c != '_')
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    };
+    let name = normalize(name);
+    let max_distance = (name.len() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let normalized = normalize(candidate);
+            let distance = match edit_distance(&name, &normalized) {
+                distance if !name.is_empty() && normalized.starts_with(&name) => distance.min(1),
+                distance => distance,
+            };
+            (distance, candidate)
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .sorted()
+        .unique_by(|(_, candidate)| *candidate)
+        .take(3)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Protocol versions the register database knows `model` for
+pub fn model_protocols(model: &str) -> Vec<ProtocolVersion> {
+    registers()
+        .filter(|reg| reg.model == model)
+        .map(|reg| reg.proto)
+        .unique_by(|proto| *proto as u8)
+        .collect()
+}
+
 pub fn list_registers(proto: ProtocolVersion, model: &str) -> Vec<Reg> {
-    REGS.iter()
+    registers()
         .cloned()
         .filter(|reg| reg.model == model && reg.proto == proto)
         .collect()
 }
 
+/// Order of registers returned by `query_registers`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum RegisterOrder {
+    #[default]
+    Address,
+    Name,
+    Size,
+}
+
+/// Registers `query_registers` selects. Unset fields select everything.
+#[derive(Debug, Clone, Default)]
+pub struct RegisterQuery {
+    /// Part of the register name, ignoring case
+    pub filter: Option<String>,
+    pub access: Option<Access>,
+    /// Models with unknown areas have no registers in either area
+    pub area: Option<Area>,
+    /// Address the register covers, not necessarily at its start
+    pub address: Option<u16>,
+    pub order: RegisterOrder,
+}
+
+/// Registers of `model` matching `query`, in the requested order
+pub fn query_registers(proto: ProtocolVersion, model: &str, query: &RegisterQuery) -> Vec<Reg> {
+    let filter = query.filter.as_ref().map(|filter| filter.to_lowercase());
+
+    let mut regs = list_registers(proto, model)
+        .into_iter()
+        .filter(|reg| match &filter {
+            Some(filter) => reg.name.to_lowercase().contains(filter),
+            None => true,
+        })
+        .filter(|reg| query.access.is_none() || query.access == Some(reg.access))
+        .filter(|reg| query.area.is_none() || register_area(reg) == query.area)
+        .filter(|reg| match query.address {
+            Some(address) => {
+                reg.address <= address && address < reg.address + (reg.size as u16).max(1)
+            }
+            None => true,
+        })
+        .collect::<Vec<_>>();
+
+    match query.order {
+        RegisterOrder::Address => regs.sort_by_key(|reg| reg.address),
+        RegisterOrder::Name => regs.sort_by_key(|reg| reg.name),
+        RegisterOrder::Size => regs.sort_by_key(|reg| (reg.size as u8, reg.address)),
+    }
+    regs
+}
+
+// Models without a torque enable register to infer the boundary from
+static RAM_START: &[(&str, ProtocolVersion, u16)] = &[("AX-S1", ProtocolVersion::V1, 24)];
+
+/// First RAM address of a model's control table. Servo control tables start
+/// their RAM area with the torque enable register.
+pub fn ram_start_address(proto: ProtocolVersion, model: &str) -> Option<u16> {
+    RAM_START
+        .iter()
+        .find(|(m, p, _)| *m == model && *p == proto)
+        .map(|(_, _, address)| *address)
+        .or_else(|| {
+            registers()
+                .find(|reg| reg.proto == proto && reg.model == model && reg.name == "TorqueEnable")
+                .map(|reg| reg.address)
+        })
+}
+
+pub fn register_area(reg: &Reg) -> Option<Area> {
+    ram_start_address(reg.proto, reg.model).map(|ram_start| {
+        if reg.address < ram_start {
+            Area::Eeprom
+        } else {
+            Area::Ram
+        }
+    })
+}
+
+/// Block of indirect address slots and the data window they map into
+#[derive(Debug, Clone, Copy)]
+pub struct IndirectBlock {
+    pub first_slot: u16,
+    pub address: u16,
+    pub data: u16,
+    pub count: u16,
+}
+
+static INDIRECT_X: &[IndirectBlock] = &[
+    IndirectBlock {
+        first_slot: 1,
+        address: 168,
+        data: 224,
+        count: 28,
+    },
+    IndirectBlock {
+        first_slot: 29,
+        address: 578,
+        data: 634,
+        count: 28,
+    },
+];
+
+pub fn indirect_blocks(proto: ProtocolVersion, model: &str) -> &'static [IndirectBlock] {
+    let is_x = ["X", "2X", "MX"].iter().any(|p| model.starts_with(p)) && model != "XL320";
+
+    if proto == ProtocolVersion::V2 && is_x && !list_registers(proto, model).is_empty() {
+        INDIRECT_X
+    } else {
+        &[]
+    }
+}
+
+// Operating modes of protocol 2 servos
+static MODES_X: &[(&str, u32)] = &[
+    ("current", 0),
+    ("velocity", 1),
+    ("position", 3),
+    ("extended_position", 4),
+    ("current_based_position", 5),
+    ("pwm", 16),
+];
+static MODES_X_NO_CURRENT: &[(&str, u32)] = &[
+    ("velocity", 1),
+    ("position", 3),
+    ("extended_position", 4),
+    ("pwm", 16),
+];
+static MODES_P: &[(&str, u32)] = &[
+    ("current", 0),
+    ("velocity", 1),
+    ("position", 3),
+    ("extended_position", 4),
+    ("current_based_position", 5),
+];
+static MODES_PRO: &[(&str, u32)] = &[
+    ("torque", 0),
+    ("velocity", 1),
+    ("position", 3),
+    ("extended_position", 4),
+];
+static MODES_XL320: &[(&str, u32)] = &[("wheel", 1), ("joint", 2)];
+
+/// Names of the values an enumerated register takes, empty for other
+/// registers
+pub fn enum_values(reg: &Reg) -> &'static [(&'static str, u32)] {
+    let model = reg.model;
+    let no_current = ["XL430", "2XL430", "XC430", "2XC430", "MX-28"];
+
+    match reg.name {
+        "ControlMode" if model == "XL320" => MODES_XL320,
+        "OperatingMode" if model.starts_with("PH") || model.starts_with("PM") => MODES_P,
+        "OperatingMode" if model.starts_with(['H', 'M', 'L']) && model.contains("-S") => MODES_PRO,
+        "OperatingMode" if no_current.iter().any(|p| model.starts_with(p)) => MODES_X_NO_CURRENT,
+        "OperatingMode" if model.starts_with('X') || model.starts_with("MX") => MODES_X,
+        _ => &[],
+    }
+}
+
+pub fn enum_value(reg: &Reg, name: &str) -> Option<u32> {
+    enum_values(reg)
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, value)| *value)
+}
+
+/// Name of the model with ModelNumber `number`, `None` if unknown
+pub fn model_name_from_number(proto: ProtocolVersion, number: u16) -> Option<&'static str> {
+    crate::model::ServoModel::name_from_model_number(proto, number)
+}
+
+pub fn find_register_by_address(proto: ProtocolVersion, model: &str, address: u16) -> Option<Reg> {
+    registers()
+        .cloned()
+        .filter(|reg| reg.model == model && reg.proto == proto)
+        .find(|reg| reg.address <= address && address < reg.address + (reg.size as u16).max(1))
+}
+
+/// Register `regspec` of `proto`, `None` if unknown or without a model
 pub fn find_register(proto: ProtocolVersion, regspec: RegSpec) -> Option<Reg> {
-    REGS.iter()
+    let model = regspec.model?;
+    registers()
         .cloned()
-        .filter(|reg| reg.proto == proto && reg.model == regspec.model && reg.name == regspec.name)
+        .filter(|reg| reg.proto == proto && reg.model == model && reg.name == regspec.name)
         .take(1)
         .next()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn similar_names_ignore_case_and_separators() {
+        let names = ["GoalPosition", "GoalVelocity", "PresentPosition"];
+        assert_eq!(similar_names("goal_position", names), ["GoalPosition"]);
+        assert_eq!(similar_names("goal-position", names), ["GoalPosition"]);
+    }
+
+    #[test]
+    fn similar_names_find_typos() {
+        let names = ["GoalPosition", "GoalVelocity", "PresentPosition"];
+        assert_eq!(similar_names("GoalPositon", names), ["GoalPosition"]);
+        assert_eq!(similar_names("GaolPosition", names), ["GoalPosition"]);
+        assert!(similar_names("Temperature", names).is_empty());
+    }
+
+    #[test]
+    fn similar_names_find_prefixes() {
+        let models = ["XM430-W210", "XM430-W350", "XM540-W150"];
+        assert_eq!(similar_names("xm430", models), ["XM430-W210", "XM430-W350"]);
+    }
+
+    #[test]
+    fn similar_names_are_closest_first() {
+        let names = ["LED", "LEDs", "Led1", "Leds2", "ID"];
+        assert_eq!(similar_names("led", names), ["LED", "LEDs", "Led1"]);
+    }
+}
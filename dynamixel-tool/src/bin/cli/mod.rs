@@ -1,21 +1,60 @@
 use anyhow::Result;
 pub use clap::StructOpt;
-use clap::{Parser, Subcommand};
+use clap::{ArgEnum, Parser, Subcommand};
+use clap_complete::Shell;
 use hex::FromHex;
 use lazy_static::lazy_static;
-use regex::Regex;
+use regex::{Captures, Regex};
 use std::cmp;
+use std::num::ParseIntError;
 use std::ops::Deref;
 use std::str::FromStr;
+use std::time::Duration;
 use thiserror::Error;
 
+use dynamixel_lib::port::UsbId;
 use dynamixel_lib::protocol::ProtocolVersion;
 use dynamixel_lib::regs::RegSpec;
 
 #[derive(Error, Debug)]
 pub enum RangeError {
-    #[error("invalid range '{0}'")]
+    #[error("expected ids like 1,3-5, got '{0}'")]
     BadRange(String),
+    #[error("expected id:address:size like 1:132:4, got '{0}'")]
+    BadReadSpec(String),
+    #[error("expected id:address:hexdata like 1:116:00080000, got '{0}'")]
+    BadWriteSpec(String),
+    #[error("expected MODEL/REGISTER=VALUE like XM430-W350/LED=1, got '{0}'")]
+    BadAssignment(String),
+    #[error("expected GAIN=VALUE like PositionPGain=800, got '{0}'")]
+    BadGain(String),
+    #[error("expected a number or value name like extended_position, got '{0}'")]
+    BadRegValue(String),
+    #[error("expected ID=VALUE pairs like 1=1000,2=2000, got '{0}'")]
+    BadMap(String),
+    #[error("id {0} appears more than once")]
+    DuplicateId(u8),
+    #[error("invalid {field} '{value}' in '{spec}'")]
+    BadField {
+        field: &'static str,
+        value: String,
+        spec: String,
+    },
+}
+
+/// Parse capture group `i` of a matched spec, naming the field on error
+fn parse_field<T: FromStr>(
+    c: &Captures,
+    i: usize,
+    field: &'static str,
+    spec: &str,
+) -> Result<T, RangeError> {
+    let value = c.get(i).unwrap().as_str();
+    value.parse().map_err(|_| RangeError::BadField {
+        field,
+        value: value.to_string(),
+        spec: spec.to_string(),
+    })
 }
 
 #[derive(Debug)]
@@ -42,26 +81,10 @@ impl FromStr for IdRange {
         for s in input.split(',') {
             if let Some(c) = RE.captures(s) {
                 if c.get(2).is_none() {
-                    let val = c
-                        .get(1)
-                        .unwrap()
-                        .as_str()
-                        .parse::<u8>()
-                        .map_err(|_| RangeError::BadRange(s.to_string()))?;
-                    result.push(val)
+                    result.push(parse_field(&c, 1, "id", input)?)
                 } else {
-                    let val1 = c
-                        .get(1)
-                        .unwrap()
-                        .as_str()
-                        .parse::<u8>()
-                        .map_err(|_| RangeError::BadRange(s.to_string()))?;
-                    let val2 = c
-                        .get(2)
-                        .unwrap()
-                        .as_str()
-                        .parse::<u8>()
-                        .map_err(|_| RangeError::BadRange(s.to_string()))?;
+                    let val1: u8 = parse_field(&c, 1, "id", input)?;
+                    let val2: u8 = parse_field(&c, 2, "id", input)?;
                     result.extend(cmp::min(val1, val2)..=cmp::max(val1, val2));
                 }
             } else {
@@ -74,6 +97,65 @@ impl FromStr for IdRange {
     }
 }
 
+/// Ids paired with a value each, like `1=1000,2=0x7d0`
+#[derive(Debug)]
+pub struct IdValueMap(Vec<(u8, i64)>);
+
+impl Deref for IdValueMap {
+    type Target = Vec<(u8, i64)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl FromStr for IdValueMap {
+    type Err = RangeError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut result: Vec<(u8, i64)> = Vec::new();
+
+        for s in input.split(',') {
+            let (id, value) = s
+                .split_once('=')
+                .ok_or_else(|| RangeError::BadMap(s.to_string()))?;
+            let field_error = |field, value: &str| RangeError::BadField {
+                field,
+                value: value.to_string(),
+                spec: input.to_string(),
+            };
+            let id: u8 = parse_with_radix(id).map_err(|_| field_error("id", id))?;
+            let value = parse_with_radix(value).map_err(|_| field_error("value", value))?;
+            if result.iter().any(|&(other, _)| other == id) {
+                return Err(RangeError::DuplicateId(id));
+            }
+            result.push((id, value));
+        }
+
+        Ok(IdValueMap(result))
+    }
+}
+
+/// Ids of an integer write, either a plain range or, with `--map`, ids
+/// paired with their values
+#[derive(Debug)]
+pub enum WriteTargets {
+    Ids(IdRange),
+    Map(IdValueMap),
+}
+
+impl FromStr for WriteTargets {
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('=') {
+            Ok(WriteTargets::Map(s.parse()?))
+        } else {
+            Ok(WriteTargets::Ids(s.parse()?))
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct MultiReadSpec {
     pub id: u8,
@@ -91,27 +173,12 @@ impl FromStr for MultiReadSpec {
 
         if let Some(c) = RE.captures(s) {
             Ok(MultiReadSpec {
-                id: c
-                    .get(1)
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .map_err(|_| RangeError::BadRange(s.to_string()))?,
-                address: c
-                    .get(2)
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .map_err(|_| RangeError::BadRange(s.to_string()))?,
-                size: c
-                    .get(3)
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .map_err(|_| RangeError::BadRange(s.to_string()))?,
+                id: parse_field(&c, 1, "id", s)?,
+                address: parse_field(&c, 2, "address", s)?,
+                size: parse_field(&c, 3, "size", s)?,
             })
         } else {
-            Err(RangeError::BadRange(s.to_string()))
+            Err(RangeError::BadReadSpec(s.to_string()))
         }
     }
 }
@@ -134,41 +201,192 @@ impl FromStr for MultiWriteSpec {
 
         if let Some(c) = RE.captures(s) {
             Ok(MultiWriteSpec {
-                id: c
-                    .get(1)
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .map_err(|_| RangeError::BadRange(s.to_string()))?,
-                address: c
-                    .get(2)
-                    .unwrap()
-                    .as_str()
-                    .parse()
-                    .map_err(|_| RangeError::BadRange(s.to_string()))?,
+                id: parse_field(&c, 1, "id", s)?,
+                address: parse_field(&c, 2, "address", s)?,
                 data: Vec::from_hex(c.get(3).unwrap().as_str())
-                    .map_err(|_| RangeError::BadRange(s.to_string()))?,
+                    .map_err(|_| RangeError::BadWriteSpec(s.to_string()))?,
             })
         } else {
-            Err(RangeError::BadRange(s.to_string()))
+            Err(RangeError::BadWriteSpec(s.to_string()))
         }
     }
 }
 
+#[derive(Debug)]
+pub struct RegAssignment {
+    pub reg: RegSpec,
+    pub value: RegValue,
+}
+
+impl FromStr for RegAssignment {
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (reg, value) = s
+            .split_once('=')
+            .ok_or_else(|| RangeError::BadAssignment(s.to_string()))?;
+
+        Ok(RegAssignment {
+            reg: reg
+                .parse()
+                .map_err(|_| RangeError::BadAssignment(s.to_string()))?,
+            value: value.parse().map_err(|_| RangeError::BadField {
+                field: "value",
+                value: value.to_string(),
+                spec: s.to_string(),
+            })?,
+        })
+    }
+}
+
+/// Register value, either a number or the name of an enumerated value
+#[derive(Debug)]
+pub enum RegValue {
+    Number(i64),
+    Name(String),
+}
+
+impl FromStr for RegValue {
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^[a-z][a-z0-9_]*$").unwrap();
+        }
+        if let Ok(value) = parse_with_radix(s) {
+            Ok(RegValue::Number(value))
+        } else if RE.is_match(s) {
+            Ok(RegValue::Name(s.to_string()))
+        } else {
+            Err(RangeError::BadRegValue(s.to_string()))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GainAssignment {
+    pub name: String,
+    pub value: u32,
+}
+
+impl FromStr for GainAssignment {
+    type Err = RangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| RangeError::BadGain(s.to_string()))?;
+
+        Ok(GainAssignment {
+            name: name.to_string(),
+            value: value.parse().map_err(|_| RangeError::BadField {
+                field: "value",
+                value: value.to_string(),
+                spec: s.to_string(),
+            })?,
+        })
+    }
+}
+
 fn parse_with_radix<T>(input: &str) -> Result<T, T::FromStrRadixErr>
 where
     T: num::Num,
     <T as num::Num>::FromStrRadixErr: std::error::Error + Send + Sync,
 {
+    // Signed types parse the sign themselves, in front of the digits
+    let (sign, input) = match input.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", input),
+    };
+
     if input.starts_with("0x") {
-        T::from_str_radix(input.trim_start_matches("0x"), 16)
+        T::from_str_radix(&[sign, input.trim_start_matches("0x")].concat(), 16)
     } else if input.starts_with("0b") {
-        T::from_str_radix(input.trim_start_matches("0b"), 2)
+        T::from_str_radix(&[sign, input.trim_start_matches("0b")].concat(), 2)
     } else {
-        T::from_str_radix(input, 10)
+        T::from_str_radix(&[sign, input].concat(), 10)
+    }
+}
+
+/// Duration like `100ms` or `2s`, plain numbers are milliseconds
+fn parse_duration(input: &str) -> Result<Duration, ParseIntError> {
+    if let Some(secs) = input.strip_suffix('s').filter(|s| !s.ends_with('m')) {
+        Ok(Duration::from_secs(secs.parse()?))
+    } else {
+        Ok(Duration::from_millis(input.trim_end_matches("ms").parse()?))
+    }
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug)]
+pub enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Switch {
+    On,
+    Off,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum AccessArg {
+    R,
+    W,
+    Rw,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum AreaArg {
+    Eeprom,
+    Ram,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum RegisterSort {
+    Address,
+    Name,
+    Size,
+}
+
+/// Model family, by name prefix
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Family {
+    Ax,
+    Mx,
+    Xl,
+    Xm,
+    Xh,
+    /// DYNAMIXEL-P and PRO series
+    Pro,
+}
+
+impl Family {
+    pub fn prefixes(self) -> &'static [&'static str] {
+        match self {
+            Family::Ax => &["AX-"],
+            Family::Mx => &["MX-"],
+            Family::Xl => &["XL", "2XL"],
+            Family::Xm => &["XM"],
+            Family::Xh => &["XH"],
+            Family::Pro => &["H42", "H54", "L42", "L54", "M42", "M54", "PH", "PM"],
+        }
     }
 }
 
+#[derive(ArgEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ScanOrder {
+    Id,
+    Model,
+}
+
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 pub struct Cli {
@@ -176,6 +394,10 @@ pub struct Cli {
     #[clap(long, short)]
     pub force: bool,
 
+    /// Print the instruction packets instead of sending them
+    #[clap(long)]
+    pub dry_run: bool,
+
     /// enable debug output
     #[clap(long, short)]
     pub debug: bool,
@@ -184,6 +406,10 @@ pub struct Cli {
     #[clap(long, short, default_value = "auto")]
     pub port: String,
 
+    /// Also pick USB adapters with this hex VID:PID for 'auto', e.g. 10c4:ea60
+    #[clap(long = "usb-id", value_name = "VID:PID")]
+    pub usb_ids: Vec<UsbId>,
+
     /// UART baud rate
     #[clap(long, short, default_value_t = 57600)]
     pub baudrate: u32,
@@ -192,10 +418,95 @@ pub struct Cli {
     #[clap(long, short, default_value_t = 0)]
     pub retries: usize,
 
+    /// Time to wait for a reply in milliseconds, 0 waits indefinitely.
+    /// Reads of many bytes wait longer by their transfer time.
+    #[clap(long, default_value_t = 10)]
+    pub timeout: u64,
+
+    /// Time to wait after sending an instruction before reading the reply,
+    /// in microseconds
+    #[clap(long, default_value_t = 0, value_name = "US")]
+    pub tx_delay: u64,
+
+    /// Read back and drop each instruction sent, for half-duplex adapters
+    /// that echo it
+    #[clap(long)]
+    pub echo_cancel: bool,
+
+    /// RS485 delay between setting RTS and sending, in milliseconds
+    #[clap(long, default_value_t = 0, value_name = "MS")]
+    pub rs485_delay_before: u32,
+
+    /// RS485 delay between sending and resetting RTS, in milliseconds
+    #[clap(long, default_value_t = 0, value_name = "MS")]
+    pub rs485_delay_after: u32,
+
+    /// RS485 RTS level while sending
+    #[clap(long, arg_enum, default_value = "high")]
+    pub rs485_rts_on_send: Level,
+
+    /// RS485 RTS level after sending
+    #[clap(long, arg_enum, default_value = "low")]
+    pub rs485_rts_after_send: Level,
+
     /// Use json-formatted output
     #[clap(long, short)]
     pub json: bool,
 
+    /// Write results to this file instead of stdout, replacing it once the
+    /// command succeeds. '-' is stdout
+    #[clap(long, value_name = "FILE")]
+    pub out: Option<String>,
+
+    /// Use json-formatted output without null or empty object members
+    #[clap(long)]
+    pub concise_json: bool,
+
+    /// Emit json arrays for reads from a single id too
+    #[clap(long)]
+    pub json_always_array: bool,
+
+    /// Use json-formatted output, with reads as an object keyed by id
+    #[clap(long, conflicts_with_all = &["json-always-array", "stream"])]
+    pub json_map: bool,
+
+    /// Print json reads as one line per id as soon as each is read
+    #[clap(long)]
+    pub stream: bool,
+
+    /// Use comma separated output (scan, read-uint, read-int and read-reg)
+    #[clap(
+        long,
+        conflicts_with_all = &["json", "concise-json", "json-map", "json-always-array", "stream"]
+    )]
+    pub csv: bool,
+
+    /// Use yaml output (dump and read-reg)
+    #[clap(
+        long,
+        conflicts_with_all = &[
+            "json",
+            "concise-json",
+            "json-map",
+            "json-always-array",
+            "stream",
+            "csv"
+        ]
+    )]
+    pub yaml: bool,
+
+    /// Colorize output, 'auto' only does so on a terminal
+    #[clap(long, arg_enum, default_value = "auto")]
+    pub color: ColorChoice,
+
+    /// File defining virtual registers for read-reg
+    #[clap(long)]
+    pub virtual_regs: Option<String>,
+
+    /// Json file with register definitions to add to the built-in ones
+    #[clap(long, value_name = "FILE")]
+    pub regs_file: Option<String>,
+
     /// Dynamixel protocol version
     #[clap(long, short = 'P', default_value = "1")]
     pub protocol: ProtocolVersion,
@@ -204,27 +515,113 @@ pub struct Cli {
     pub command: Commands,
 }
 
+/// A line of a `run` script: a command with its arguments
+#[derive(Parser, Debug)]
+#[clap(no_binary_name = true)]
+pub struct ScriptLine {
+    #[clap(subcommand)]
+    pub command: Commands,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum Commands {
-    /// List known device models
-    ListModels,
+    /// List known device models with their model numbers
+    ListModels {
+        /// Only models of this family
+        #[clap(long, arg_enum)]
+        family: Option<Family>,
+        /// Also print the number of registers of each model
+        #[clap(long, short)]
+        verbose: bool,
+    },
+
+    /// Print a completion script for SHELL. Bash, zsh and fish scripts
+    /// also complete MODEL/REGISTER arguments.
+    Completions {
+        #[clap(arg_enum)]
+        shell: Shell,
+    },
 
     /// List registers for a model
-    ListRegisters { model: String },
+    ListRegisters {
+        /// Only registers whose name contains this, ignoring case
+        #[clap(long, visible_alias = "grep")]
+        filter: Option<String>,
+        /// Only registers covering this address
+        #[clap(long, parse(try_from_str=parse_with_radix))]
+        address: Option<u16>,
+        /// Only registers with this access
+        #[clap(long, arg_enum)]
+        access: Option<AccessArg>,
+        /// Only registers in this area of the control table
+        #[clap(long, arg_enum)]
+        area: Option<AreaArg>,
+        #[clap(long, arg_enum, default_value = "address")]
+        sort: RegisterSort,
+        model: String,
+    },
+
+    /// List serial ports and whether 'auto' would consider them, without
+    /// opening any
+    ListPorts,
 
     /// Scan for servos
     Scan {
+        /// Also report model number and name
+        #[clap(long)]
+        detail: bool,
+        /// Report ID and model name, tab separated
+        #[clap(long, conflicts_with = "detail")]
+        models: bool,
+        /// Order of found devices
+        #[clap(long, arg_enum, default_value = "id")]
+        sort: ScanOrder,
+        /// Scan at each of these baud rates, e.g. 57600,1000000
+        #[clap(long, use_value_delimiter = true, conflicts_with = "all-bauds")]
+        bauds: Vec<u32>,
+        /// Scan at each standard baud rate
+        #[clap(long)]
+        all_bauds: bool,
+        #[clap(default_value_t = 0, parse(try_from_str=parse_with_radix))]
+        scan_start: u8,
+        #[clap(default_value_t = 253, parse(try_from_str=parse_with_radix))]
+        scan_end: u8,
+    },
+
+    /// Ping servos, reporting model number and firmware version (protocol 2)
+    Ping { ids: IdRange },
+
+    /// Read firmware version
+    Firmware { ids: IdRange },
+
+    /// Scan for servos, fail unless exactly COUNT are found
+    ExpectCount {
+        count: usize,
         #[clap(default_value_t = 0, parse(try_from_str=parse_with_radix))]
         scan_start: u8,
         #[clap(default_value_t = 253, parse(try_from_str=parse_with_radix))]
         scan_end: u8,
     },
 
+    /// Find protocol, baud rate and model of devices
+    Identify {
+        /// Try all standard baud rates and both protocols
+        #[clap(long)]
+        probe_baudrates: bool,
+        ids: IdRange,
+    },
+
     /// Read unsigned 8-bit integer
     #[clap(visible_alias = "readb")]
     ReadUint8 {
-        #[clap(short, short)]
+        #[clap(short, long)]
         sync: bool,
+        /// Sync read with a single combined reply (protocol 2)
+        #[clap(long)]
+        fast: bool,
+        /// Print values as 0x prefixed hex, zero padded to the value size
+        #[clap(long)]
+        hex: bool,
         ids: IdRange,
         #[clap(parse(try_from_str=parse_with_radix))]
         address: u16,
@@ -233,8 +630,14 @@ pub enum Commands {
     /// Read unsigned 16-bit integer
     #[clap(visible_alias = "readh")]
     ReadUint16 {
-        #[clap(short, short)]
+        #[clap(short, long)]
         sync: bool,
+        /// Sync read with a single combined reply (protocol 2)
+        #[clap(long)]
+        fast: bool,
+        /// Print values as 0x prefixed hex, zero padded to the value size
+        #[clap(long)]
+        hex: bool,
         ids: IdRange,
         #[clap(parse(try_from_str=parse_with_radix))]
         address: u16,
@@ -243,8 +646,62 @@ pub enum Commands {
     /// Read unsigned 32-bit integer
     #[clap(visible_alias = "readw")]
     ReadUint32 {
-        #[clap(short, short)]
+        #[clap(short, long)]
+        sync: bool,
+        /// Sync read with a single combined reply (protocol 2)
+        #[clap(long)]
+        fast: bool,
+        /// Print values as 0x prefixed hex, zero padded to the value size
+        #[clap(long)]
+        hex: bool,
+        ids: IdRange,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        address: u16,
+    },
+
+    /// Read signed 8-bit integer
+    #[clap(visible_alias = "readsb")]
+    ReadInt8 {
+        #[clap(short, long)]
         sync: bool,
+        /// Sync read with a single combined reply (protocol 2)
+        #[clap(long)]
+        fast: bool,
+        /// Print values as 0x prefixed hex, zero padded to the value size
+        #[clap(long)]
+        hex: bool,
+        ids: IdRange,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        address: u16,
+    },
+
+    /// Read signed 16-bit integer
+    #[clap(visible_alias = "readsh")]
+    ReadInt16 {
+        #[clap(short, long)]
+        sync: bool,
+        /// Sync read with a single combined reply (protocol 2)
+        #[clap(long)]
+        fast: bool,
+        /// Print values as 0x prefixed hex, zero padded to the value size
+        #[clap(long)]
+        hex: bool,
+        ids: IdRange,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        address: u16,
+    },
+
+    /// Read signed 32-bit integer
+    #[clap(visible_alias = "readsw")]
+    ReadInt32 {
+        #[clap(short, long)]
+        sync: bool,
+        /// Sync read with a single combined reply (protocol 2)
+        #[clap(long)]
+        fast: bool,
+        /// Print values as 0x prefixed hex, zero padded to the value size
+        #[clap(long)]
+        hex: bool,
         ids: IdRange,
         #[clap(parse(try_from_str=parse_with_radix))]
         address: u16,
@@ -253,6 +710,16 @@ pub enum Commands {
     /// Read byte array
     #[clap(visible_alias = "reada")]
     ReadBytes {
+        /// Read all ids in one sync read (protocol 2), falling back to one
+        /// read per id on protocol 1
+        #[clap(short, long)]
+        sync: bool,
+        /// Fail instead of falling back when sync read isn't supported
+        #[clap(long, requires = "sync")]
+        strict: bool,
+        /// Print bytes as space separated two digit hex
+        #[clap(long)]
+        hex: bool,
         ids: IdRange,
         #[clap(parse(try_from_str=parse_with_radix))]
         address: u16,
@@ -263,19 +730,43 @@ pub enum Commands {
     /// Read mutiple byte arrays
     #[clap(visible_alias = "readm")]
     ReadBytesMultiple {
+        /// Use fast sync read (protocol 2), all specs need the same
+        /// address and size
+        #[clap(long)]
+        fast: bool,
+        /// Print bytes as space separated two digit hex
+        #[clap(long)]
+        hex: bool,
         #[clap(required = true)]
         specs: Vec<MultiReadSpec>,
     },
 
     /// Read register
-    ReadReg { ids: IdRange, reg: RegSpec },
+    ReadReg {
+        /// Convert to the physical unit of the register, e.g. degrees
+        #[clap(long)]
+        scaled: bool,
+        /// Print raw values as 0x prefixed hex, zero padded to the
+        /// register size
+        #[clap(long, conflicts_with = "scaled")]
+        hex: bool,
+        ids: IdRange,
+        reg: RegSpec,
+    },
 
     /// Write unsigned 8-bit integer
     #[clap(visible_alias = "writeb")]
     WriteUint8 {
-        #[clap(short, short)]
+        #[clap(short, long)]
         sync: bool,
-        ids: IdRange,
+        /// Stage the write, applied by 'action'
+        #[clap(long)]
+        deferred: bool,
+        /// Take IDS as ID=VALUE pairs like 1=1000,2=2000, written with
+        /// one sync write
+        #[clap(long, conflicts_with_all = &["sync", "deferred", "value"])]
+        map: bool,
+        ids: WriteTargets,
         #[clap(parse(try_from_str=parse_with_radix))]
         address: u16,
         #[clap(parse(try_from_str=parse_with_radix), min_values(1))]
@@ -287,7 +778,14 @@ pub enum Commands {
     WriteUint16 {
         #[clap(long, short)]
         sync: bool,
-        ids: IdRange,
+        /// Stage the write, applied by 'action'
+        #[clap(long)]
+        deferred: bool,
+        /// Take IDS as ID=VALUE pairs like 1=1000,2=2000, written with
+        /// one sync write
+        #[clap(long, conflicts_with_all = &["sync", "deferred", "value"])]
+        map: bool,
+        ids: WriteTargets,
         #[clap(parse(try_from_str=parse_with_radix))]
         address: u16,
         #[clap(parse(try_from_str=parse_with_radix), min_values(1))]
@@ -299,13 +797,89 @@ pub enum Commands {
     WriteUint32 {
         #[clap(long, short)]
         sync: bool,
-        ids: IdRange,
+        /// Stage the write, applied by 'action'
+        #[clap(long)]
+        deferred: bool,
+        /// Take IDS as ID=VALUE pairs like 1=1000,2=2000, written with
+        /// one sync write
+        #[clap(long, conflicts_with_all = &["sync", "deferred", "value"])]
+        map: bool,
+        ids: WriteTargets,
         #[clap(parse(try_from_str=parse_with_radix))]
         address: u16,
         #[clap(parse(try_from_str=parse_with_radix), min_values(1))]
         value: Vec<u32>,
     },
 
+    /// Write signed 8-bit integer
+    #[clap(visible_alias = "writesb")]
+    WriteInt8 {
+        #[clap(long, short)]
+        sync: bool,
+        /// Stage the write, applied by 'action'
+        #[clap(long)]
+        deferred: bool,
+        /// Take IDS as ID=VALUE pairs like 1=1000,2=2000, written with
+        /// one sync write
+        #[clap(long, conflicts_with_all = &["sync", "deferred", "value"])]
+        map: bool,
+        ids: WriteTargets,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        address: u16,
+        #[clap(
+            parse(try_from_str=parse_with_radix),
+            min_values(1),
+            allow_hyphen_values = true
+        )]
+        value: Vec<i8>,
+    },
+
+    /// Write signed 16-bit integer
+    #[clap(visible_alias = "writesh")]
+    WriteInt16 {
+        #[clap(long, short)]
+        sync: bool,
+        /// Stage the write, applied by 'action'
+        #[clap(long)]
+        deferred: bool,
+        /// Take IDS as ID=VALUE pairs like 1=1000,2=2000, written with
+        /// one sync write
+        #[clap(long, conflicts_with_all = &["sync", "deferred", "value"])]
+        map: bool,
+        ids: WriteTargets,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        address: u16,
+        #[clap(
+            parse(try_from_str=parse_with_radix),
+            min_values(1),
+            allow_hyphen_values = true
+        )]
+        value: Vec<i16>,
+    },
+
+    /// Write signed 32-bit integer
+    #[clap(visible_alias = "writesw")]
+    WriteInt32 {
+        #[clap(long, short)]
+        sync: bool,
+        /// Stage the write, applied by 'action'
+        #[clap(long)]
+        deferred: bool,
+        /// Take IDS as ID=VALUE pairs like 1=1000,2=2000, written with
+        /// one sync write
+        #[clap(long, conflicts_with_all = &["sync", "deferred", "value"])]
+        map: bool,
+        ids: WriteTargets,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        address: u16,
+        #[clap(
+            parse(try_from_str=parse_with_radix),
+            min_values(1),
+            allow_hyphen_values = true
+        )]
+        value: Vec<i32>,
+    },
+
     /// Write byte array
     #[clap(visible_alias = "writea")]
     WriteBytes {
@@ -316,9 +890,27 @@ pub enum Commands {
         values: Vec<u8>,
     },
 
+    /// Stage byte array write, applied by 'action'
+    RegWrite {
+        ids: IdRange,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        address: u16,
+        #[clap(required = true, parse(try_from_str=parse_with_radix))]
+        values: Vec<u8>,
+    },
+
+    /// Apply staged writes, on all servos at once by default
+    Action {
+        #[clap(default_value_t = 254, parse(try_from_str=parse_with_radix))]
+        id: u8,
+    },
+
     /// Write multiple byte arrays
     #[clap(visible_alias = "writem")]
     WriteBytesMultiple {
+        /// Send all writes in a single bulk write (protocol 2 only)
+        #[clap(long)]
+        bulk: bool,
         #[clap(required = true)]
         specs: Vec<MultiWriteSpec>,
     },
@@ -327,6 +919,373 @@ pub enum Commands {
     WriteReg {
         ids: IdRange,
         reg: RegSpec,
-        value: u32,
+        /// Number or value name, e.g. extended_position
+        #[clap(allow_hyphen_values = true)]
+        value: RegValue,
+    },
+
+    /// Write multiple registers, EEPROM first
+    WriteRegs {
+        id: u8,
+        /// MODEL/REGISTER=VALUE pairs
+        #[clap(required = true)]
+        assignments: Vec<RegAssignment>,
+    },
+
+    /// Read all known registers of a model
+    #[clap(visible_alias = "read-all")]
+    Dump {
+        /// Device model, detected per device if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        /// Only read EEPROM registers
+        #[clap(long, conflicts_with = "ram-only")]
+        eeprom_only: bool,
+        /// Only read RAM registers
+        #[clap(long)]
+        ram_only: bool,
+        /// Print write-reg commands reproducing the configuration
+        #[clap(long)]
+        as_commands: bool,
+        ids: IdRange,
+    },
+
+    /// Compare EEPROM registers of two devices, printing those that differ.
+    /// Exits with status 1 if any do.
+    Diff {
+        /// Model of both devices, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        /// Compare RAM registers too
+        #[clap(long)]
+        all: bool,
+        /// Comma separated registers to leave out, e.g. id,baud-rate
+        #[clap(long, use_value_delimiter = true)]
+        ignore: Vec<String>,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        id_a: u8,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        id_b: u8,
+    },
+
+    /// Write registers saved by dump --json back, EEPROM first
+    Restore {
+        /// Model to resolve register names for, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        /// Json file written by dump
+        #[clap(long)]
+        file: String,
+        /// Print the planned writes without sending them
+        #[clap(long)]
+        dry_run: bool,
+        id: u8,
+    },
+
+    /// Compare registers of a device with a file saved by dump --json.
+    /// Exits with status 1 on any mismatch of a writable register.
+    Verify {
+        /// Model to resolve register names for, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        /// Json file written by dump
+        #[clap(long)]
+        file: String,
+        /// Write the expected value of mismatched writable registers
+        #[clap(long)]
+        fix: bool,
+        id: u8,
+    },
+
+    /// Poll a register, printing a timestamped line per sample. Ctrl-C
+    /// stops and prints the minimum and maximum of each device.
+    Watch {
+        /// Time between samples, e.g. 100ms or 1s
+        #[clap(long, short, default_value = "100ms", parse(try_from_str = parse_duration))]
+        interval: Duration,
+        /// Stop after COUNT samples
+        #[clap(long, short)]
+        count: Option<usize>,
+        ids: IdRange,
+        reg: RegSpec,
+    },
+
+    /// Run the commands of a script, one per line, on a single port. Stops
+    /// at the first failing line unless --keep-going. A script of `-` reads
+    /// commands from stdin until EOF, continuing after failing lines.
+    Run {
+        /// Continue after failing lines, still failing at the end
+        #[clap(long, short)]
+        keep_going: bool,
+        /// Print each result as a json object with the line number as `seq`
+        #[clap(long)]
+        ndjson: bool,
+        /// Reopen the port before the next line if it goes away, e.g. when
+        /// the USB adapter is unplugged
+        #[clap(long)]
+        reconnect: bool,
+        script: String,
     },
+
+    /// Poll several registers, redrawing a table of their values per
+    /// sample until COUNT samples or Ctrl-C
+    Monitor {
+        /// Time between samples, e.g. 100ms or 1s
+        #[clap(long, short, default_value = "100ms", parse(try_from_str = parse_duration))]
+        interval: Duration,
+        /// Stop after COUNT samples
+        #[clap(long, short)]
+        count: Option<usize>,
+        ids: IdRange,
+        #[clap(required = true)]
+        regs: Vec<RegSpec>,
+    },
+
+    /// Show or set position control gains of the detected model:
+    /// compliance margin/slope on AX style models, PID gains otherwise
+    Tune {
+        ids: IdRange,
+        /// GAIN=VALUE pairs, e.g. CWComplianceSlope=32 or PositionPGain=800
+        gains: Vec<GainAssignment>,
+    },
+
+    /// Show indirect address mapping
+    ShowIndirect { id: u8, model: String },
+
+    /// Set status return level (0: ping only, 1: ping and read, 2: all)
+    SetReturnLevel {
+        /// Model to resolve the register address for
+        #[clap(long, short)]
+        model: Option<String>,
+        ids: IdRange,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        level: u8,
+    },
+
+    /// Enable or disable torque, reading the state back to confirm
+    Torque {
+        /// Model to resolve the register address for, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        ids: IdRange,
+        #[clap(arg_enum)]
+        state: Switch,
+    },
+
+    /// Enable torque, same as torque IDS on
+    TorqueOn {
+        /// Model to resolve the register address for, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        ids: IdRange,
+    },
+
+    /// Disable torque, same as torque IDS off
+    TorqueOff {
+        /// Model to resolve the register address for, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        ids: IdRange,
+    },
+
+    /// Blink the LED of a servo to find it on the robot, restoring the LED
+    /// state afterwards
+    Blink {
+        /// Model to resolve the register address for, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        /// How long to blink, e.g. 500ms or 5s
+        #[clap(long, short, default_value = "5s", parse(try_from_str = parse_duration))]
+        duration: Duration,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        id: u8,
+    },
+
+    /// Write goal position and print the present position, with --wait
+    /// once all servos are within tolerance
+    Move {
+        /// Model to resolve the register addresses for, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        /// Wait until the present position is within tolerance of the goal
+        #[clap(long, short)]
+        wait: bool,
+        /// Time to wait for, e.g. 500ms or 5s
+        #[clap(long, default_value = "5s", parse(try_from_str = parse_duration))]
+        timeout: Duration,
+        /// Largest distance from the goal that counts as reached
+        #[clap(long, default_value = "10")]
+        tolerance: u32,
+        ids: IdRange,
+        #[clap(parse(try_from_str=parse_with_radix), allow_hyphen_values = true)]
+        position: i64,
+    },
+
+    /// Find the baud rate of servos by pinging them at each standard rate
+    DetectBaud { ids: IdRange },
+
+    /// Time COUNT reads from a servo, reporting latency, throughput and
+    /// errors. Ctrl-C stops early.
+    Benchmark {
+        /// Number of reads
+        #[clap(long, short, default_value_t = 1000)]
+        count: usize,
+        /// Bytes per read, from address 0
+        #[clap(long, short, default_value_t = 2)]
+        size: u16,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        id: u8,
+    },
+
+    /// Read the model number of servos and print the model name
+    DetectModel { ids: IdRange },
+
+    /// Change servo id, refusing to duplicate an answering id unless --force
+    #[clap(visible_alias = "set-id")]
+    ChangeId {
+        /// Model to resolve the register address for, detected if omitted
+        #[clap(long, short)]
+        model: Option<String>,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        old_id: u8,
+        #[clap(parse(try_from_str=parse_with_radix))]
+        new_id: u8,
+    },
+
+    /// Change servo baud rate and confirm it answers at the new rate
+    #[clap(visible_alias = "set-baud")]
+    ChangeBaud { ids: IdRange, baudrate: u32 },
+
+    /// Reboot servos (protocol 2), 254 reboots all at once and needs --force
+    Reboot { ids: IdRange },
+
+    /// Reset multi-turn position to within one revolution (protocol 2),
+    /// servos with torque enabled need --force
+    Clear { ids: IdRange },
+
+    /// Store control table to flash (protocol 2)
+    Backup {
+        /// Restore control table from flash instead
+        #[clap(long)]
+        restore: bool,
+        ids: IdRange,
+    },
+
+    /// Reset servos to factory defaults, several ids or 254 need --force
+    FactoryReset {
+        /// Keep ID (protocol 2)
+        #[clap(long)]
+        keep_id: bool,
+        /// Keep ID and baud rate (protocol 2)
+        #[clap(long)]
+        keep_baud: bool,
+        ids: IdRange,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Commands {
+        Cli::try_parse_from(std::iter::once("dynamixel-tool").chain(args.iter().copied()))
+            .unwrap()
+            .command
+    }
+
+    #[test]
+    fn signed_reads_take_sync() {
+        for args in [
+            ["read-int8", "--sync", "1,2", "116"],
+            ["read-int16", "-s", "1,2", "116"],
+            ["readsw", "--sync", "1,2", "116"],
+        ] {
+            match parse(&args) {
+                Commands::ReadInt8 { sync, .. }
+                | Commands::ReadInt16 { sync, .. }
+                | Commands::ReadInt32 { sync, .. } => assert!(sync, "{:?}", args),
+                other => panic!("{:?} parsed as {:?}", args, other),
+            }
+        }
+    }
+
+    #[test]
+    fn text_formats_conflict_with_json_layouts() {
+        for format in ["--csv", "--yaml"] {
+            for json in ["--json-map", "--json-always-array"] {
+                let args = ["dynamixel-tool", format, json, "ping", "1"];
+                assert!(Cli::try_parse_from(args).is_err(), "{:?}", args);
+            }
+        }
+    }
+
+    #[test]
+    fn negative_values_are_parsed() {
+        assert_eq!(parse_with_radix::<i16>("-32768").unwrap(), i16::MIN);
+        assert_eq!(parse_with_radix::<i16>("-0x8000").unwrap(), i16::MIN);
+        assert_eq!(parse_with_radix::<i16>("0x7FFF").unwrap(), i16::MAX);
+        assert_eq!(parse_with_radix::<i8>("-0b1").unwrap(), -1);
+        assert_eq!(parse_with_radix::<i32>("-1024").unwrap(), -1024);
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        assert!(parse_with_radix::<i16>("-32769").is_err());
+        assert!(parse_with_radix::<i16>("32768").is_err());
+        assert!(parse_with_radix::<i16>("0xFFFF").is_err());
+        assert!(parse_with_radix::<u8>("-1").is_err());
+        assert!(parse_with_radix::<u8>("--1").is_err());
+    }
+
+    #[test]
+    fn signed_writes_take_negative_values() {
+        match parse(&["write-int16", "--sync", "1,2", "116", "-32768", "1024"]) {
+            Commands::WriteInt16 { sync, value, .. } => {
+                assert!(sync);
+                assert_eq!(value, [-32768, 1024]);
+            }
+            other => panic!("parsed as {:?}", other),
+        }
+    }
+
+    #[test]
+    fn bad_fields_are_named() {
+        assert_eq!(
+            "1,300".parse::<IdRange>().unwrap_err().to_string(),
+            "invalid id '300' in '1,300'"
+        );
+        assert_eq!(
+            "1,-2".parse::<IdRange>().unwrap_err().to_string(),
+            "expected ids like 1,3-5, got '-2'"
+        );
+    }
+
+    #[test]
+    fn map_values_may_be_negative() {
+        let map: IdValueMap = "1=-1024,2=0x10,3=-0x10".parse().unwrap();
+        assert_eq!(*map, [(1, -1024), (2, 16), (3, -16)]);
+
+        assert!(matches!(
+            "1=1,1=2".parse::<IdValueMap>(),
+            Err(RangeError::DuplicateId(1))
+        ));
+        assert_eq!(
+            "1=-x".parse::<IdValueMap>().unwrap_err().to_string(),
+            "invalid value '-x' in '1=-x'"
+        );
+    }
+
+    #[test]
+    fn reg_values_may_be_negative() {
+        assert!(matches!("-5".parse(), Ok(RegValue::Number(-5))));
+        assert!(matches!("-0x10".parse(), Ok(RegValue::Number(-16))));
+        assert!(matches!(
+            "extended_position".parse(),
+            Ok(RegValue::Name(name)) if name == "extended_position"
+        ));
+        assert!(matches!(
+            "-x".parse::<RegValue>(),
+            Err(RangeError::BadRegValue(_))
+        ));
+    }
 }
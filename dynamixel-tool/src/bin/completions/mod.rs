@@ -0,0 +1,170 @@
+//! Shell completion scripts. On top of what clap generates, the bash, zsh
+//! and fish scripts complete MODEL/REGISTER arguments by calling back into
+//! `dynamixel-tool list-models` and `dynamixel-tool list-registers`.
+
+use clap::CommandFactory;
+use clap_complete::{generate, Shell};
+
+use crate::cli::Cli;
+
+const BIN_NAME: &str = "dynamixel-tool";
+
+/// Subcommands taking MODEL/REGISTER arguments
+const REG_COMMANDS: &[&str] = &["read-reg", "write-reg", "write-regs", "watch", "monitor"];
+
+/// Models of both protocols, one per line
+const LIST_MODELS: &str =
+    "{ dynamixel-tool list-models; dynamixel-tool -P2 list-models; } 2>/dev/null | cut -f1";
+
+/// Register names of `$model`, whichever protocol it belongs to
+const LIST_REGISTERS: &str = "{ dynamixel-tool list-registers \"$model\" || \
+     dynamixel-tool -P2 list-registers \"$model\"; } 2>/dev/null | awk '{print $4}'";
+
+pub fn completion_script(shell: Shell) -> String {
+    let mut buf = Vec::new();
+    generate(shell, &mut Cli::command(), BIN_NAME, &mut buf);
+    let script = String::from_utf8(buf).expect("completion scripts are utf-8");
+
+    match shell {
+        Shell::Bash => script + &bash_regs(),
+        // The generated script ends by calling the completion function,
+        // which the wrapper has to replace
+        Shell::Zsh => {
+            let call = format!("_{} \"$@\"\n", BIN_NAME);
+            match script.strip_suffix(&call) {
+                Some(script) => script.to_string() + &zsh_regs(),
+                None => script,
+            }
+        }
+        Shell::Fish => script + &fish_regs(),
+        _ => script,
+    }
+}
+
+fn bash_regs() -> String {
+    format!(
+        r#"
+_{name}_regs() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}" word model
+    for word in "${{COMP_WORDS[@]:1:COMP_CWORD-1}}"; do
+        case "${{word}}" in
+            {commands})
+                case "${{cur}}" in
+                    */*)
+                        model="${{cur%%/*}}"
+                        COMPREPLY=($(compgen -P "${{model}}/" -W "$({registers})" -- "${{cur#*/}}"))
+                        return 0
+                        ;;
+                    [A-Z]*)
+                        COMPREPLY=($(compgen -S / -W "$({models})" -- "${{cur}}"))
+                        compopt -o nospace
+                        return 0
+                        ;;
+                esac
+                ;;
+        esac
+    done
+    _{name} "$@"
+}}
+
+complete -F _{name}_regs -o bashdefault -o default {name}
+"#,
+        name = BIN_NAME,
+        commands = REG_COMMANDS.join("|"),
+        models = LIST_MODELS,
+        registers = LIST_REGISTERS,
+    )
+}
+
+fn zsh_regs() -> String {
+    format!(
+        r#"_{name}_regs() {{
+    local cur="${{words[CURRENT]}}" model
+    if (( ${{words[(I)({commands})]}} )); then
+        case "${{cur}}" in
+            */*)
+                model="${{cur%%/*}}"
+                compset -P '*/'
+                compadd -- ${{(f)"$({registers})"}}
+                return
+                ;;
+            [A-Z]*)
+                compadd -S / -- ${{(f)"$({models})"}}
+                return
+                ;;
+        esac
+    fi
+    _{name} "$@"
+}}
+
+compdef _{name}_regs {name}
+_{name}_regs "$@"
+"#,
+        name = BIN_NAME,
+        commands = REG_COMMANDS.join("|"),
+        models = LIST_MODELS,
+        registers = LIST_REGISTERS,
+    )
+}
+
+fn fish_regs() -> String {
+    format!(
+        r#"
+function __fish_{func}_regs
+    set -l cur (commandline -ct)
+    if string match -q '*/*' -- $cur
+        set -l model (string split -m1 / -- $cur)[1]
+        begin; {name} list-registers $model; or {name} -P2 list-registers $model; end 2>/dev/null \
+            | string replace -r '^.* ' "$model/"
+    else
+        begin; {name} list-models; {name} -P2 list-models; end 2>/dev/null | cut -f1 | string replace -r '$' /
+    end
+end
+
+complete -c {name} -n "__fish_seen_subcommand_from {commands}" -f -a "(__fish_{func}_regs)"
+"#,
+        name = BIN_NAME,
+        func = BIN_NAME.replace('-', "_"),
+        commands = REG_COMMANDS.join(" "),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scripts_are_shell_specific() {
+        for (shell, marker) in [
+            (Shell::Bash, "complete -F _dynamixel-tool_regs"),
+            (Shell::Zsh, "#compdef dynamixel-tool"),
+            (Shell::Fish, "complete -c dynamixel-tool"),
+            (Shell::Elvish, "edit:completion:arg-completer"),
+            (Shell::PowerShell, "Register-ArgumentCompleter"),
+        ] {
+            let script = completion_script(shell);
+            assert!(script.contains(marker), "{} script lacks {}", shell, marker);
+            assert!(
+                script.contains("read-reg"),
+                "{} script lacks commands",
+                shell
+            );
+        }
+    }
+
+    #[test]
+    fn zsh_script_calls_the_wrapper_only() {
+        let script = completion_script(Shell::Zsh);
+        assert!(script.ends_with("_dynamixel-tool_regs \"$@\"\n"));
+        assert_eq!(script.matches("_dynamixel-tool \"$@\"\n").count(), 1);
+    }
+
+    #[test]
+    fn register_completion_lists_models() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = completion_script(shell);
+            assert!(script.contains("list-models"), "{}", shell);
+            assert!(script.contains("list-registers"), "{}", shell);
+        }
+    }
+}
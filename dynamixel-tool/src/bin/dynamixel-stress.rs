@@ -5,8 +5,20 @@ use dynamixel_lib::protocol::{self, ProtocolVersion};
 use env_logger::TimestampPrecision;
 
 fn main() -> Result<()> {
-    let mut port: Box<dyn SerialPort + Send> = port::open_port("auto", 1000000, false)?;
-    let mut proto_box = protocol::master::make_protocol(ProtocolVersion::V1, port.as_mut(), 0);
+    let mut port: Box<dyn SerialPort + Send> = port::open_port(
+        "auto",
+        1000000,
+        false,
+        port::DEFAULT_TIMEOUT,
+        Default::default(),
+    )?;
+    let mut proto_box = protocol::master::make_protocol(
+        ProtocolVersion::V1,
+        port.as_mut(),
+        0,
+        port::DEFAULT_TIMEOUT,
+        Default::default(),
+    );
 
     env_logger::Builder::from_env(env_logger::Env::default())
         .format_timestamp(Some(TimestampPrecision::Millis))
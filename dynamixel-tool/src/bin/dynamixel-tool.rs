@@ -1,23 +1,66 @@
 pub mod cli;
+pub mod completions;
+pub mod regs_file;
+pub mod virtual_regs;
 
-use std::io;
-use std::{convert::TryFrom, convert::TryInto, fmt::Display};
+use std::io::{self, BufRead, BufWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use std::{convert::TryFrom, convert::TryInto, fmt, fmt::Display, fmt::UpperHex};
 
 use anyhow::{anyhow, Context, Result};
-use clap::CommandFactory;
-use clap_complete::{generate, shells::Bash};
-use log::error;
+use clap_complete::Shell;
+use log::{debug, error, warn};
 use num_traits::{FromBytes, ToBytes};
+use termcolor::{Buffer, Color, ColorSpec, WriteColor};
 
-use dynamixel_lib::port;
-use dynamixel_lib::protocol::{self, master::Protocol, ProtocolVersion};
+use dynamixel_lib::model::ServoModel;
+use dynamixel_lib::port::{self, SerialPort};
+use dynamixel_lib::protocol::master::{
+    self as master, DryRunPort, PingResult, Protocol, ResetScope,
+};
+use dynamixel_lib::protocol::{
+    self, HardwareErrorStatus, ProtocolError, ProtocolVersion, StatusByte,
+};
 use dynamixel_lib::regs::{self, RegSpec};
 
-use cli::{Cli, MultiReadSpec, MultiWriteSpec, StructOpt};
+use cli::{
+    AccessArg, AreaArg, Cli, ColorChoice, Family, GainAssignment, Level, MultiReadSpec,
+    MultiWriteSpec, RegAssignment, RegValue, RegisterSort, ScanOrder, StructOpt, Switch,
+    WriteTargets,
+};
+use virtual_regs::VirtualReg;
 
+#[derive(Clone, Copy)]
 enum OutputFormat {
     Plain,
     Json,
+    /// Comma separated rows, for the commands that support it
+    Csv,
+    /// Register values per device, for the commands that support it
+    Yaml,
+}
+
+static BAUDRATES: &[u32] = &[9600, 57600, 115200, 1000000, 2000000, 3000000, 4000000];
+
+/// Port settings for commands that reopen the port themselves
+struct Bus {
+    port: String,
+    baudrate: u32,
+    force: bool,
+    retries: usize,
+    timeout: Duration,
+    tx: master::TxOptions,
+    rs485: port::Rs485Config,
+    protocol: ProtocolVersion,
+}
+
+impl Bus {
+    fn open(&self, baudrate: u32) -> Result<Box<dyn SerialPort + Send>> {
+        port::open_port(&self.port, baudrate, self.force, self.timeout, self.rs485)
+    }
 }
 
 fn slice_to_line<T>(data: &[T]) -> String
@@ -40,6 +83,191 @@ where
         .join("\n")
 }
 
+/// Layout of json output for reads from several ids
+#[derive(Clone, Copy, PartialEq)]
+enum JsonLayout {
+    /// Array, or a scalar for a single result
+    Auto,
+    Array,
+    /// Object keyed by id, for a single result too
+    Map,
+    /// One `{"id": .., "value": ..}` line per id, printed as it is read
+    Stream,
+}
+
+/// Json of the results of `ids`, one result per id in the same order
+fn json_results<T: Into<json::JsonValue>>(ids: &[u8], res: Vec<T>, layout: JsonLayout) -> String {
+    match layout {
+        JsonLayout::Auto if res.len() == 1 => json::stringify(res.into_iter().next()),
+        JsonLayout::Map => {
+            let mut obj = json::JsonValue::new_object();
+            for (id, value) in ids.iter().zip(res) {
+                obj.insert(&id.to_string(), value).unwrap();
+            }
+            json::stringify(obj)
+        }
+        JsonLayout::Stream => String::new(),
+        _ => json::stringify(res),
+    }
+}
+
+/// Register values per device, as `ID: {model: MODEL, registers: {NAME: VALUE}}`
+fn regs_yaml<'a>(
+    devices: impl Iterator<Item = (u8, &'a str, Vec<(&'a str, serde_yaml::Value)>)>,
+) -> Result<String> {
+    let mut doc = serde_yaml::Mapping::new();
+    for (id, model, values) in devices {
+        let mut registers = serde_yaml::Mapping::new();
+        for (name, value) in values {
+            registers.insert(name.into(), value);
+        }
+        let mut device = serde_yaml::Mapping::new();
+        device.insert("model".into(), model.into());
+        device.insert("registers".into(), registers.into());
+        doc.insert(id.into(), device.into());
+    }
+    Ok(serde_yaml::to_string(&doc)?.trim_end().to_string())
+}
+
+/// Integer printed as `0x` prefixed hex, zero padded to its size in bytes
+#[derive(Clone, Copy)]
+struct Hex<T>(T, usize);
+
+impl<T: UpperHex> Display for Hex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:#0width$X}", self.0, width = 2 + 2 * self.1)
+    }
+}
+
+impl<T: UpperHex> From<Hex<T>> for json::JsonValue {
+    fn from(hex: Hex<T>) -> Self {
+        hex.to_string().into()
+    }
+}
+
+impl<T: UpperHex> From<Hex<T>> for serde_yaml::Value {
+    fn from(hex: Hex<T>) -> Self {
+        hex.to_string().into()
+    }
+}
+
+/// Bytes as space separated two digit hex
+fn hex_bytes(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn csv_rows(rows: impl Iterator<Item = String>) -> String {
+    rows.collect::<Vec<_>>().join("\n")
+}
+
+fn stream_result<T: Into<json::JsonValue>>(layout: JsonLayout, id: u8, value: T) -> Result<()> {
+    if layout == JsonLayout::Stream {
+        let line = json_text(json::object! { id: id, value: value });
+        writeln!(Output, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Whether json output leaves out null and empty members, set once in
+/// `do_main` from --concise-json
+static CONCISE_JSON: AtomicBool = AtomicBool::new(false);
+
+/// `value` without object members that are null or empty. Array elements
+/// stay in place, as per-id results are told apart by their position.
+fn prune_json(value: json::JsonValue) -> json::JsonValue {
+    match value {
+        json::JsonValue::Array(items) => {
+            items.into_iter().map(prune_json).collect::<Vec<_>>().into()
+        }
+        json::JsonValue::Object(obj) => {
+            let mut pruned = json::JsonValue::new_object();
+            for (key, value) in obj.iter() {
+                let value = prune_json(value.clone());
+                let empty = match &value {
+                    json::JsonValue::Null => true,
+                    json::JsonValue::Array(items) => items.is_empty(),
+                    json::JsonValue::Object(obj) => obj.is_empty(),
+                    _ => false,
+                };
+                if !empty {
+                    pruned[key] = value;
+                }
+            }
+            pruned
+        }
+        value => value,
+    }
+}
+
+/// Json text of `value`, pruned with --concise-json
+fn json_text(value: json::JsonValue) -> String {
+    match CONCISE_JSON.load(Ordering::Relaxed) {
+        true => json::stringify(prune_json(value)),
+        false => json::stringify(value),
+    }
+}
+
+/// Command output pruned with --concise-json. Output of several json
+/// lines is pruned line by line, anything that isn't json is kept.
+fn concise_output(output: String) -> String {
+    if !CONCISE_JSON.load(Ordering::Relaxed) {
+        return output;
+    }
+    if let Ok(value) = json::parse(&output) {
+        return json_text(value);
+    }
+    output
+        .lines()
+        .map(|line| json::parse(line).map_or(line.to_string(), json_text))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Whether results are colored, decided once in `do_main`
+static COLOR: AtomicBool = AtomicBool::new(false);
+
+/// How a result turned out, shown by its color
+#[derive(Clone, Copy)]
+enum Outcome {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// `text` in the color of `outcome` if results are colored
+fn paint(text: impl Display, outcome: Outcome) -> String {
+    let text = text.to_string();
+    if !COLOR.load(Ordering::Relaxed) {
+        return text;
+    }
+
+    let color = match outcome {
+        Outcome::Ok => Color::Green,
+        Outcome::Warning => Color::Yellow,
+        Outcome::Error => Color::Red,
+    };
+    colored(text, color)
+}
+
+fn colored(output: String, color: Color) -> String {
+    if output.is_empty() {
+        return output;
+    }
+
+    let mut buffer = Buffer::ansi();
+    buffer
+        .set_color(ColorSpec::new().set_fg(Some(color)))
+        .and_then(|_| buffer.write_all(output.as_bytes()))
+        .and_then(|_| buffer.reset())
+        .map_or(output.clone(), |_| {
+            String::from_utf8_lossy(buffer.as_slice()).into_owned()
+        })
+}
+
 fn slice_to_byte_slices<T: Copy>(slice: &[T]) -> Vec<&[u8]> {
     slice
         .iter()
@@ -49,380 +277,4570 @@ fn slice_to_byte_slices<T: Copy>(slice: &[T]) -> Vec<&[u8]> {
         .collect()
 }
 
-fn cmd_list_models(proto: ProtocolVersion, fmt: OutputFormat) -> Result<String> {
-    let models = regs::list_models(proto);
+fn cmd_list_models(
+    proto: ProtocolVersion,
+    family: Option<Family>,
+    verbose: bool,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let models = regs::list_models(proto)
+        .into_iter()
+        .filter(|name| match family {
+            Some(family) => family
+                .prefixes()
+                .iter()
+                .any(|prefix| name.starts_with(prefix)),
+            None => true,
+        })
+        .filter_map(|name| ServoModel::lookup(proto, name))
+        .collect::<Vec<_>>();
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => models
+            .iter()
+            .map(|model| {
+                let numbers = match model.model_numbers() {
+                    [] => "-".to_string(),
+                    numbers => numbers
+                        .iter()
+                        .map(|number| number.to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                };
+                let line = format!("{}\t{}\t{}", model.name(), numbers, proto);
+                match verbose {
+                    true => format!("{}\t{}", line, model.registers().len()),
+                    false => line,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            models
+                .iter()
+                .map(|model| {
+                    let mut obj = json::object! {
+                        model: model.name(),
+                        number: model.model_numbers().first().copied(),
+                        numbers: model.model_numbers(),
+                        protocol: proto as u8,
+                    };
+                    if verbose {
+                        obj["n_registers"] = model.registers().len().into();
+                    }
+                    obj
+                })
+                .collect::<Vec<_>>(),
+        ),
+    })
+}
+
+fn cmd_list_ports(fmt: OutputFormat) -> Result<String> {
+    let ports = port::list_ports()?;
+    // Unknown type ports are only compatible if they do rs485
+    let compatible = |port: &port::PortInfo| match port.compatible {
+        Some(true) => "yes",
+        Some(false) => "no",
+        None => "maybe",
+    };
     Ok(match fmt {
-        OutputFormat::Plain => slice_to_column(models.as_slice()),
-        OutputFormat::Json => json::stringify(models),
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => ports
+            .iter()
+            .map(|port| {
+                format!(
+                    "{} {} {} {}",
+                    port.name,
+                    port.kind,
+                    port.usb_id.map_or("-".to_string(), |id| id.to_string()),
+                    compatible(port)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            ports
+                .iter()
+                .map(|port| {
+                    json::object! {
+                        port: port.name.as_str(),
+                        type: port.kind,
+                        usb_id: port.usb_id.map(|id| id.to_string()),
+                        compatible: port.compatible,
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ),
     })
 }
 
-fn cmd_list_registers(proto: ProtocolVersion, model: &str, _fmt: OutputFormat) -> Result<String> {
-    let regs = regs::list_registers(proto, model);
+fn reg_json(reg: &regs::Reg) -> json::JsonValue {
+    json::object! {
+        name: reg.name,
+        address: reg.address,
+        size: reg.size as u8,
+        access: reg.access.to_string(),
+        area: regs::register_area(reg).map(|area| area.to_string()),
+        signed: reg.signed,
+        unit: reg.unit.map(|unit| unit.name),
+    }
+}
+
+fn cmd_list_registers(
+    proto: ProtocolVersion,
+    model: &str,
+    query: &regs::RegisterQuery,
+    fmt: OutputFormat,
+) -> Result<String> {
+    if regs::list_registers(proto, model).is_empty() {
+        return Err(model_not_found(proto, model));
+    }
+    if query.area.is_some() && regs::ram_start_address(proto, model).is_none() {
+        return Err(anyhow!("EEPROM and RAM areas of {} are unknown", model));
+    }
+    let regs = regs::query_registers(proto, model, query);
 
-    if !regs.is_empty() {
-        Ok(slice_to_column(
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => slice_to_column(
             regs.iter()
                 .map(|reg| reg.to_string())
                 .collect::<Vec<_>>()
                 .as_slice(),
-        ))
-    } else {
-        Err(anyhow!("Model {} not found (protocol {})", model, proto))
+        ),
+        OutputFormat::Json => json::stringify(regs.iter().map(reg_json).collect::<Vec<_>>()),
+    })
+}
+
+/// What scan reports per device
+#[derive(Clone, Copy, PartialEq)]
+enum ScanReport {
+    Ids,
+    /// ID, model number and model name
+    Detail,
+    /// ID and model name, tab separated
+    Models,
+}
+
+fn scan_report(detail: bool, models: bool) -> ScanReport {
+    match (detail, models) {
+        (true, _) => ScanReport::Detail,
+        (_, true) => ScanReport::Models,
+        _ => ScanReport::Ids,
     }
 }
 
+struct ScanResult {
+    id: u8,
+    model_number: Option<u16>,
+    model: Option<&'static str>,
+}
+
 fn cmd_scan(
     proto: &mut dyn Protocol,
     scan_start: u8,
     scan_end: u8,
+    report: ScanReport,
+    sort: ScanOrder,
     fmt: OutputFormat,
 ) -> Result<String> {
-    proto.scan(scan_start, scan_end).map(|ids| match fmt {
-        OutputFormat::Plain => slice_to_column(&ids),
-        OutputFormat::Json => json::stringify(ids),
-    })
+    let res = scan_devices(proto, scan_start, scan_end, sort)?;
+    Ok(scan_output(&res, report, fmt))
 }
 
-fn cmd_read_int<const N: usize, T>(
-    proto: &mut dyn Protocol,
-    ids: &[u8],
-    address: u16,
+fn cmd_scan_bauds(
+    bus: &Bus,
+    baudrates: &[u32],
+    scan_start: u8,
+    scan_end: u8,
+    report: ScanReport,
+    sort: ScanOrder,
     fmt: OutputFormat,
-    sync: bool,
-) -> Result<String>
-where
-    T: Copy + Display + FromBytes<Bytes = [u8; N]>,
-    T: Into<json::JsonValue>,
-{
-    let res = if !sync {
-        ids.iter()
-            .map(|&id| -> Result<T> {
-                let bytes = proto.read(id, address, N as u16).with_context(|| {
-                    format!(
-                        "Failed to read {} from id {}",
-                        std::any::type_name::<T>(),
-                        id
-                    )
-                })?;
-                Ok(T::from_le_bytes(bytes.as_slice().try_into().unwrap()))
-            })
-            .collect::<Result<Vec<_>, _>>()?
-    } else {
-        proto
-            .sync_read(ids, address, N as u16)?
-            .into_iter()
-            .map(|bytes| T::from_le_bytes(bytes[..N].try_into().unwrap()))
-            .collect()
-    };
+) -> Result<String> {
+    let mut found = Vec::new();
+    for &baudrate in baudrates {
+        let mut port = match bus.open(baudrate) {
+            Ok(port) => port,
+            Err(e) => {
+                warn!("Failed to open port at {} baud: {:#}", baudrate, e);
+                continue;
+            }
+        };
+        let mut proto = protocol::master::make_protocol(
+            bus.protocol,
+            port.as_mut(),
+            bus.retries,
+            bus.timeout,
+            bus.tx,
+        );
+        match scan_devices(proto.as_mut(), scan_start, scan_end, sort) {
+            Ok(res) if res.is_empty() => {}
+            Ok(res) => found.push((baudrate, res)),
+            Err(e) => warn!("Failed to scan at {} baud: {:#}", baudrate, e),
+        }
+    }
 
     Ok(match fmt {
-        OutputFormat::Plain => slice_to_line(res.as_slice()),
-        OutputFormat::Json => {
-            if res.len() > 1 {
-                json::stringify(res)
+        OutputFormat::Plain | OutputFormat::Yaml if report == ScanReport::Ids => found
+            .iter()
+            .map(|(baudrate, res)| {
+                let ids: Vec<_> = res.iter().map(|r| paint(r.id, Outcome::Ok)).collect();
+                format!("{}: {}", baudrate, slice_to_line(&ids))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Plain | OutputFormat::Yaml | OutputFormat::Csv => {
+            let sep = if matches!(fmt, OutputFormat::Csv) {
+                ","
             } else {
-                res[0].to_string()
+                ": "
+            };
+            found
+                .iter()
+                .flat_map(|(baudrate, res)| {
+                    scan_output(res, report, fmt)
+                        .lines()
+                        .map(|line| format!("{}{}{}", baudrate, sep, line))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        OutputFormat::Json => {
+            let mut obj = json::JsonValue::new_object();
+            for (baudrate, res) in &found {
+                obj[baudrate.to_string()] = scan_json(res, report);
             }
+            json::stringify(obj)
         }
     })
 }
 
-fn cmd_read_bytes(
+/// Scan for devices, reading the model number of each
+fn scan_devices(
     proto: &mut dyn Protocol,
-    ids: &[u8],
-    address: u16,
-    count: u16,
-    fmt: OutputFormat,
-) -> Result<String> {
-    let res = ids
-        .iter()
-        .map(|&id| -> Result<Vec<u8>> {
-            proto
-                .read(id, address, count)
-                .with_context(|| format!("Failed to read bytes from id {}", id))
+    scan_start: u8,
+    scan_end: u8,
+    sort: ScanOrder,
+) -> Result<Vec<ScanResult>> {
+    let found = proto.scan_ping(scan_start, scan_end)?;
+
+    let mut res = found
+        .into_iter()
+        .map(|(id, ping)| {
+            let model_number = ping_model_number(proto, id, ping);
+            ScanResult {
+                id,
+                model_number,
+                model: model_number
+                    .and_then(|n| ServoModel::from_model_number(proto.version(), n))
+                    .map(|model| model.name()),
+            }
         })
-        .collect::<Result<Vec<_>, _>>()?;
+        .collect::<Vec<_>>();
 
-    Ok(match fmt {
-        OutputFormat::Plain => res
+    // Unknown models go last, scan order is by id already
+    if sort == ScanOrder::Model {
+        res.sort_by_key(|r| (r.model.is_none(), r.model, r.model_number));
+    }
+    Ok(res)
+}
+
+fn scan_output(res: &[ScanResult], report: ScanReport, fmt: OutputFormat) -> String {
+    if report == ScanReport::Ids {
+        let ids: Vec<_> = res.iter().map(|r| paint(r.id, Outcome::Ok)).collect();
+        return match fmt {
+            OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => slice_to_column(&ids),
+            OutputFormat::Json => json::stringify(scan_json(res, report)),
+        };
+    }
+
+    match fmt {
+        OutputFormat::Plain | OutputFormat::Yaml if report == ScanReport::Models => res
             .iter()
-            .map(|x| slice_to_line(x.as_slice()))
-            .collect::<Vec<String>>()
+            .map(|r| match (r.model, r.model_number) {
+                (Some(model), _) => paint(format!("{}\t{}", r.id, model), Outcome::Ok),
+                (None, Some(number)) => {
+                    paint(format!("{}\tunknown({})", r.id, number), Outcome::Warning)
+                }
+                (None, None) => paint(format!("{}\t-", r.id), Outcome::Warning),
+            })
+            .collect::<Vec<_>>()
             .join("\n"),
-        OutputFormat::Json => {
-            if res.len() > 1 {
-                json::stringify(res)
-            } else {
-                json::stringify(res[0].clone())
+        OutputFormat::Plain | OutputFormat::Yaml => res
+            .iter()
+            .map(|r| {
+                let line = format!(
+                    "{} {} {}",
+                    r.id,
+                    r.model_number.map_or("-".to_string(), |n| n.to_string()),
+                    r.model.unwrap_or("-")
+                );
+                match r.model {
+                    Some(_) => paint(line, Outcome::Ok),
+                    None => paint(line, Outcome::Warning),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Csv => res
+            .iter()
+            .map(|r| {
+                format!(
+                    "{},{},{}",
+                    r.id,
+                    r.model_number.map_or(String::new(), |n| n.to_string()),
+                    r.model.unwrap_or("")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(scan_json(res, report)),
+    }
+}
+
+fn scan_json(res: &[ScanResult], report: ScanReport) -> json::JsonValue {
+    if report == ScanReport::Ids {
+        return res.iter().map(|r| r.id).collect::<Vec<_>>().into();
+    }
+    res.iter()
+        .map(|r| {
+            json::object! {
+                id: r.id,
+                model_number: r.model_number,
+                model: r.model,
             }
-        }
-    })
+        })
+        .collect::<Vec<_>>()
+        .into()
 }
 
-fn cmd_read_bytes_multiple(
-    proto: &mut dyn Protocol,
-    specs: &[MultiReadSpec],
-    fmt: OutputFormat,
-) -> Result<String> {
-    let res = specs
+fn cmd_ping(proto: &mut dyn Protocol, ids: &[u8], fmt: OutputFormat) -> Result<String> {
+    let res = ids
         .iter()
-        .map(|spec| -> Result<Vec<u8>> {
+        .map(|&id| {
             proto
-                .read(spec.id, spec.address, spec.size)
-                .with_context(|| format!("Failed to read bytes from id {}", spec.id))
+                .ping(id)
+                .with_context(|| format!("Failed to ping id {}", id))
         })
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(match fmt {
-        OutputFormat::Plain => res
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => ids
             .iter()
-            .map(|x| slice_to_line(x.as_slice()))
-            .collect::<Vec<String>>()
+            .zip(res.iter())
+            .map(|(id, r)| {
+                let line = format!(
+                    "{} {} {}",
+                    id,
+                    r.model.map_or("-".to_string(), |n| n.to_string()),
+                    r.firmware.map_or("-".to_string(), |n| n.to_string())
+                );
+                paint(line, Outcome::Ok)
+            })
+            .collect::<Vec<_>>()
             .join("\n"),
-        OutputFormat::Json => {
-            if res.len() > 1 {
-                json::stringify(res)
-            } else {
-                json::stringify(res[0].clone())
-            }
-        }
+        OutputFormat::Json => json::stringify(
+            ids.iter()
+                .zip(res)
+                .map(|(&id, r)| {
+                    json::object! {
+                        id: id,
+                        model_number: r.model,
+                        firmware: r.firmware,
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ),
     })
 }
 
-fn cmd_read_reg(
-    proto: &mut dyn Protocol,
-    ids: &[u8],
-    regspec: RegSpec,
-    fmt: OutputFormat,
-) -> Result<String> {
-    let reg = regs::find_register(proto.version(), regspec).ok_or(anyhow!("Register not found"))?;
+// Protocol 2 ping reports firmware version, with protocol 1 its address is
+// found through the model number
+fn read_firmware(proto: &mut dyn Protocol, id: u8) -> Result<u8> {
+    if let Some(firmware) = proto.ping(id)?.firmware {
+        return Ok(firmware);
+    }
+
+    let bytes = proto.read(id, 0, 2)?;
+    let model_number = u16::from_le_bytes(bytes[0..=1].try_into().unwrap());
+    let address = ServoModel::from_model_number(proto.version(), model_number)
+        .and_then(|model| model.register("FirmwareVersion"))
+        .map_or(2, |reg| reg.address);
 
+    Ok(proto.read(id, address, 1)?[0])
+}
+
+fn cmd_firmware(proto: &mut dyn Protocol, ids: &[u8], fmt: OutputFormat) -> Result<String> {
     let res = ids
         .iter()
-        .map(|&id| -> Result<u32> {
-            let bytes: Vec<_> = proto
-                .read(id, reg.address, reg.size as u16)
-                .with_context(|| format!("Failed to read register from id {}", id))?;
-            Ok(match reg.size {
-                regs::RegSize::Byte => u8::from_le_bytes(bytes[0..=0].try_into().unwrap()) as u32,
-                regs::RegSize::Half => u16::from_le_bytes(bytes[0..=1].try_into().unwrap()) as u32,
-                regs::RegSize::Word => u32::from_le_bytes(bytes[0..=3].try_into().unwrap()),
-                regs::RegSize::Variable => panic!("variable size registers not supported!"),
-            })
+        .map(|&id| {
+            read_firmware(proto, id)
+                .with_context(|| format!("Failed to read firmware version from id {}", id))
         })
         .collect::<Result<Vec<_>, _>>()?;
 
     Ok(match fmt {
-        OutputFormat::Plain => slice_to_line(res.as_slice()),
-        OutputFormat::Json => {
-            if res.len() > 1 {
-                json::stringify(res)
-            } else {
-                res[0].to_string()
-            }
-        }
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => ids
+            .iter()
+            .zip(res.iter())
+            .map(|(id, firmware)| paint(format!("{} {}", id, firmware), Outcome::Ok))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            ids.iter()
+                .zip(res)
+                .map(|(&id, firmware)| json::object! { id: id, firmware: firmware })
+                .collect::<Vec<_>>(),
+        ),
     })
 }
 
-fn cmd_write_int<const N: usize, T: Copy + ToBytes<Bytes = [u8; N]>>(
-    proto: &mut dyn Protocol,
-    ids: &[u8],
-    address: u16,
-    values: &[T],
-    sync: bool,
-) -> Result<String> {
-    if !sync {
-        if values.len() != 1 {
-            return Err(anyhow!("Multiple values supported in sync mode only"));
-        }
-        ids.iter()
-            .map(|&id| {
-                proto
-                    .write(id, address, values[0].to_le_bytes().as_slice())
-                    .with_context(|| {
-                        format!(
-                            "Failed to write {} to id {}",
-                            std::any::type_name::<T>(),
-                            id
-                        )
-                    })
-            })
-            .collect::<Result<Vec<_>, _>>()
-            .map(|_| Ok(String::new()))?
-    } else {
-        if values.len() != ids.len() && values.len() != 1 {
-            return Err(anyhow!("Need {} values, got {}", ids.len(), values.len()));
+/// Model number of `id`, which answered `ping`. Protocol 1 reads it,
+/// protocol 2 pings carry it and StatusReturnLevel is read instead, so that
+/// servos answering pings only are still noticed.
+fn ping_model_number(proto: &mut dyn Protocol, id: u8, ping: PingResult) -> Option<u16> {
+    if let Some(number) = ping.model {
+        let reg = ServoModel::from_model_number(proto.version(), number)
+            .and_then(|model| model.register("StatusReturnLevel"));
+        if let Some(reg) = reg {
+            if let Err(e) = proto.read(id, reg.address, 1) {
+                warn_unreadable(id, &e);
+            }
         }
+        return Some(number);
+    }
 
-        if values.len() != 1 {
-            proto
-                .sync_write(ids, address, &slice_to_byte_slices(values))
-                .map(|_| Ok(String::new()))?
-        } else {
-            proto
-                .sync_write(
-                    ids,
-                    address,
-                    &slice_to_byte_slices(&vec![values[0]; ids.len()]),
-                )
-                .map(|_| Ok(String::new()))?
+    match proto.read(id, 0, 2) {
+        Ok(bytes) => Some(u16::from_le_bytes(bytes[0..=1].try_into().unwrap())),
+        Err(e) => {
+            warn_unreadable(id, &e);
+            None
         }
     }
 }
 
+// A servo with status return level 0 answers pings only, so every read or
+// write to it times out. Other errors have other causes.
+fn warn_unreadable(id: u8, e: &anyhow::Error) {
+    if is_timeout(e) {
+        warn!(
+            "id {} answers ping but not read, status return level may be 0 (see set-return-level)",
+            id
+        );
+    } else {
+        warn!("id {} answers ping but read failed: {:#}", id, e);
+    }
+}
+
+fn cmd_identify(bus: &Bus, ids: &[u8], probe_baudrates: bool, fmt: OutputFormat) -> Result<String> {
+    let mut settings = vec![(bus.baudrate, bus.protocol)];
+    if probe_baudrates {
+        for &baudrate in BAUDRATES {
+            for version in [ProtocolVersion::V1, ProtocolVersion::V2] {
+                if !settings.contains(&(baudrate, version)) {
+                    settings.push((baudrate, version));
+                }
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+    let mut missing = ids.to_vec();
+
+    for (baudrate, version) in settings {
+        if missing.is_empty() {
+            break;
+        }
+
+        let mut port = bus.open(baudrate)?;
+        let mut proto = protocol::master::make_protocol(
+            version,
+            port.as_mut(),
+            bus.retries,
+            bus.timeout,
+            bus.tx,
+        );
+
+        missing.retain(|&id| {
+            let ping = match proto.ping(id) {
+                Ok(ping) => ping,
+                Err(_) => return true,
+            };
+            let model_number = ping_model_number(proto.as_mut(), id, ping);
+            found.push((id, version, baudrate, model_number));
+            false
+        });
+    }
+
+    for id in missing {
+        warn!("id {} not found", id);
+    }
+    found.sort_by_key(|(id, _, _, _)| *id);
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => found
+            .iter()
+            .map(|(id, version, baudrate, model_number)| {
+                let (number, name) = match model_number {
+                    Some(n) => (n.to_string(), regs::model_name_from_number(*version, *n)),
+                    None => ("-".to_string(), None),
+                };
+                let line = format!(
+                    "{} {} {} {} {}",
+                    id,
+                    version,
+                    baudrate,
+                    number,
+                    name.unwrap_or(if model_number.is_some() {
+                        "unknown"
+                    } else {
+                        "-"
+                    })
+                );
+                match name {
+                    Some(_) => paint(line, Outcome::Ok),
+                    None => paint(line, Outcome::Warning),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            found
+                .into_iter()
+                .map(|(id, version, baudrate, model_number)| {
+                    json::object! {
+                        id: id,
+                        protocol: version as u8,
+                        baudrate: baudrate,
+                        model_number: model_number,
+                        model: model_number
+                            .and_then(|n| regs::model_name_from_number(version, n)),
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ),
+    })
+}
+
+fn cmd_detect_baud(bus: &Bus, ids: &[u8], fmt: OutputFormat) -> Result<String> {
+    let mut found = Vec::new();
+    let mut missing = ids.to_vec();
+
+    // The configured rate is the likeliest, try it first
+    let baudrates = std::iter::once(bus.baudrate).chain(
+        BAUDRATES
+            .iter()
+            .cloned()
+            .filter(|&baudrate| baudrate != bus.baudrate),
+    );
+    for baudrate in baudrates {
+        if missing.is_empty() {
+            break;
+        }
+
+        let mut port = match bus.open(baudrate) {
+            Ok(port) => port,
+            Err(e) => {
+                warn!("Failed to open port at {} baud: {:#}", baudrate, e);
+                continue;
+            }
+        };
+        let mut proto = protocol::master::make_protocol(
+            bus.protocol,
+            port.as_mut(),
+            bus.retries,
+            bus.timeout,
+            bus.tx,
+        );
+
+        missing.retain(|&id| {
+            if proto.ping(id).is_err() {
+                return true;
+            }
+            found.push((id, baudrate));
+            false
+        });
+    }
+
+    for id in missing {
+        warn!("id {} not found", id);
+    }
+    found.sort_by_key(|(id, _)| *id);
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => found
+            .iter()
+            .map(|(id, baudrate)| paint(format!("{} {}", id, baudrate), Outcome::Ok))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            found
+                .into_iter()
+                .map(|(id, baudrate)| json::object! { id: id, baudrate: baudrate })
+                .collect::<Vec<_>>(),
+        ),
+    })
+}
+
+fn cmd_expect_count(
+    proto: &mut dyn Protocol,
+    count: usize,
+    scan_start: u8,
+    scan_end: u8,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let ids = proto.scan(scan_start, scan_end)?;
+
+    if ids.len() != count {
+        return Err(anyhow!(
+            "Expected {} servos, found {} ({:+}): {}",
+            count,
+            ids.len(),
+            ids.len() as isize - count as isize,
+            slice_to_line(&ids)
+        ));
+    }
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => {
+            let ids: Vec<_> = ids.iter().map(|&id| paint(id, Outcome::Ok)).collect();
+            slice_to_column(&ids)
+        }
+        OutputFormat::Json => json::stringify(ids),
+    })
+}
+
+/// Sync read, failing unless every id answers
+fn sync_read_all(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    address: u16,
+    count: u16,
+) -> Result<Vec<Vec<u8>>> {
+    proto
+        .sync_read(ids, address, count)?
+        .into_iter()
+        .map(|(id, data)| data.with_context(|| format!("Failed to sync read from id {}", id)))
+        .collect()
+}
+
+/// Fast sync read, falling back to plain sync read if nothing answers as
+/// devices with older firmware don't know the instruction
+fn fast_sync_read(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    address: u16,
+    count: u16,
+) -> Result<Vec<Vec<u8>>> {
+    let res = match proto.fast_sync_read(ids, address, count) {
+        Err(e) if matches!(e.downcast_ref(), Some(ProtocolError::TimedOut)) => {
+            debug!("No reply to fast sync read, falling back to sync read");
+            return sync_read_all(proto, ids, address, count);
+        }
+        res => res?,
+    };
+
+    ids.iter()
+        .zip(res)
+        .map(|(id, data)| {
+            data.map_err(|value| {
+                let status = StatusByte {
+                    version: ProtocolVersion::V2,
+                    value,
+                };
+                anyhow!("Id {} reported status error {}", id, status)
+            })
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cmd_read_int<const N: usize, T>(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    address: u16,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+    sync: bool,
+    fast: bool,
+    hex: bool,
+) -> Result<String>
+where
+    T: Copy + Display + UpperHex + FromBytes<Bytes = [u8; N]>,
+    T: Into<i64>,
+{
+    let stream_result = |layout, id, value: T| match hex {
+        true => stream_result(layout, id, Hex(value, N)),
+        false => stream_result(layout, id, value.into()),
+    };
+
+    // Sync reads keep the values of the ids that answered, the others are
+    // None
+    let res = if fast {
+        fast_sync_read(proto, ids, address, N as u16)?
+            .into_iter()
+            .zip(ids)
+            .map(|(bytes, &id)| -> Result<Option<T>> {
+                let value = T::from_le_bytes(bytes[..N].try_into().unwrap());
+                stream_result(layout, id, value)?;
+                Ok(Some(value))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    } else if !sync {
+        ids.iter()
+            .map(|&id| -> Result<Option<T>> {
+                let bytes = proto.read(id, address, N as u16).with_context(|| {
+                    format!(
+                        "Failed to read {} from id {}",
+                        std::any::type_name::<T>(),
+                        id
+                    )
+                })?;
+                let value = T::from_le_bytes(bytes.as_slice().try_into().unwrap());
+                stream_result(layout, id, value)?;
+                Ok(Some(value))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        let res = proto
+            .sync_read(ids, address, N as u16)?
+            .into_iter()
+            .map(|(id, data)| -> Result<Option<T>> {
+                match data {
+                    Ok(bytes) => {
+                        let value = T::from_le_bytes(bytes[..N].try_into().unwrap());
+                        stream_result(layout, id, value)?;
+                        Ok(Some(value))
+                    }
+                    Err(e) => {
+                        error!(
+                            "Failed to sync read {} from id {}: {:#}",
+                            std::any::type_name::<T>(),
+                            id,
+                            e
+                        );
+                        Ok(None)
+                    }
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if res.iter().all(Option::is_none) {
+            return Err(anyhow!("No id answered the sync read"));
+        }
+        res
+    };
+
+    Ok(if hex {
+        let res = res.into_iter().map(|v| v.map(|v| Hex(v, N))).collect();
+        int_output(ids, res, fmt, layout)
+    } else {
+        int_output(ids, widen(res), fmt, layout)
+    })
+}
+
+/// `json` negates negative integers in their own type, which overflows for
+/// the minimum value, so values are widened before output
+fn widen<T: Into<i64>>(res: Vec<Option<T>>) -> Vec<Option<i64>> {
+    res.into_iter().map(|v| v.map(Into::into)).collect()
+}
+
+fn int_output<T>(ids: &[u8], res: Vec<Option<T>>, fmt: OutputFormat, layout: JsonLayout) -> String
+where
+    T: Copy + Display + Into<json::JsonValue>,
+{
+    // A dash stands for an id that didn't answer
+    let value_or_dash = |value: &Option<T>| match value {
+        Some(v) => paint(v, Outcome::Ok),
+        None => paint("-", Outcome::Error),
+    };
+    match fmt {
+        OutputFormat::Plain | OutputFormat::Yaml => {
+            slice_to_line(&res.iter().map(value_or_dash).collect::<Vec<_>>())
+        }
+        OutputFormat::Json => json_results(ids, res, layout),
+        OutputFormat::Csv => csv_rows(ids.iter().zip(&res).map(|(id, value)| {
+            format!("{},{}", id, value.map_or(String::new(), |v| v.to_string()))
+        })),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cmd_read_bytes(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    address: u16,
+    count: u16,
+    sync: bool,
+    strict: bool,
+    hex: bool,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> Result<String> {
+    let stream_result = |layout, id, bytes: &[u8]| match hex {
+        true => stream_result(layout, id, hex_bytes(bytes)),
+        false => stream_result(layout, id, bytes),
+    };
+
+    if sync {
+        // Protocol 1 sync read is one read per id
+        if strict && proto.version() == ProtocolVersion::V1 {
+            return Err(anyhow!("Protocol 1 has no sync read"));
+        }
+        let res = sync_read_all(proto, ids, address, count)?;
+        for (&id, bytes) in ids.iter().zip(&res) {
+            stream_result(layout, id, bytes)?;
+        }
+        return Ok(bytes_output(ids, res, hex, fmt, layout));
+    }
+
+    let res = ids
+        .iter()
+        .map(|&id| -> Result<Vec<u8>> {
+            let bytes = proto
+                .read_chunked(id, address, count)
+                .with_context(|| format!("Failed to read bytes from id {}", id))?;
+            stream_result(layout, id, &bytes)?;
+            Ok(bytes)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(bytes_output(ids, res, hex, fmt, layout))
+}
+
+fn bytes_output(
+    ids: &[u8],
+    res: Vec<Vec<u8>>,
+    hex: bool,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> String {
+    match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => res
+            .iter()
+            .map(|x| match hex {
+                true => paint(hex_bytes(x), Outcome::Ok),
+                false => paint(slice_to_line(x.as_slice()), Outcome::Ok),
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        OutputFormat::Json if hex => {
+            json_results(ids, res.iter().map(|x| hex_bytes(x)).collect(), layout)
+        }
+        OutputFormat::Json => json_results(ids, res, layout),
+    }
+}
+
+fn cmd_read_bytes_multiple(
+    proto: &mut dyn Protocol,
+    specs: &[MultiReadSpec],
+    fast: bool,
+    hex: bool,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> Result<String> {
+    let stream_result = |layout, id, bytes: &[u8]| match hex {
+        true => stream_result(layout, id, hex_bytes(bytes)),
+        false => stream_result(layout, id, bytes),
+    };
+
+    let requests: Vec<_> = specs
+        .iter()
+        .map(|spec| (spec.id, spec.address, spec.size))
+        .collect();
+    let unique = requests
+        .iter()
+        .enumerate()
+        .all(|(i, r)| requests[..i].iter().all(|other| other.0 != r.0));
+    if layout == JsonLayout::Map && !unique {
+        return Err(anyhow!("Json map output needs each id read only once"));
+    }
+    let ids: Vec<_> = specs.iter().map(|spec| spec.id).collect();
+
+    // Single transaction reads. Protocol 1 devices other than MX series
+    // ignore bulk read, so fall back to one read per spec.
+    let combined = if fast {
+        let (address, size) = (specs[0].address, specs[0].size);
+        if specs
+            .iter()
+            .any(|spec| spec.address != address || spec.size != size)
+        {
+            return Err(anyhow!(
+                "Fast sync read needs the same address and size for all ids"
+            ));
+        }
+        Some(fast_sync_read(proto, &ids, address, size)?)
+    } else if unique {
+        match proto.bulk_read(&requests) {
+            Ok(res) => Some(res),
+            Err(e) if proto.version() == ProtocolVersion::V1 => {
+                debug!("Bulk read failed, reading one by one: {:#}", e);
+                None
+            }
+            Err(e) => return Err(e.context("Failed to bulk read bytes")),
+        }
+    } else {
+        None
+    };
+
+    let res = match combined {
+        Some(res) => {
+            for (spec, bytes) in specs.iter().zip(&res) {
+                stream_result(layout, spec.id, bytes)?;
+            }
+            res
+        }
+        None => specs
+            .iter()
+            .map(|spec| -> Result<Vec<u8>> {
+                let bytes = proto
+                    .read_chunked(spec.id, spec.address, spec.size)
+                    .with_context(|| format!("Failed to read bytes from id {}", spec.id))?;
+                stream_result(layout, spec.id, &bytes)?;
+                Ok(bytes)
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+    };
+
+    Ok(bytes_output(&ids, res, hex, fmt, layout))
+}
+
+/// Like `regs::find_register`, but tells a model used with the wrong
+/// protocol apart from a missing register. A register given without a
+/// model is looked up in the detected model of `ids`.
+fn lookup_register(proto: &mut dyn Protocol, ids: &[u8], regspec: RegSpec) -> Result<regs::Reg> {
+    let model = match &regspec.model {
+        Some(model) => model.clone(),
+        None => return infer_register(proto, ids, &regspec.name),
+    };
+    let proto = proto.version();
+    let name = format!("{}/{}", model, regspec.name);
+    let protocols = regs::model_protocols(&model);
+
+    if !protocols.is_empty() && !protocols.contains(&proto) {
+        return Err(anyhow!(
+            "Model {} is protocol {}; you specified protocol {}",
+            model,
+            protocols[0],
+            proto
+        ));
+    }
+
+    if protocols.is_empty() {
+        return Err(model_not_found(proto, &model));
+    }
+
+    regs::find_register(proto, regspec.clone()).ok_or_else(|| {
+        anyhow!(
+            "Register {} not found{}",
+            name,
+            did_you_mean(&regs::similar_names(
+                &regspec.name,
+                regs::list_registers(proto, &model)
+                    .iter()
+                    .map(|reg| reg.name)
+            ))
+        )
+    })
+}
+
+/// Register `name` of the model of `ids`, detected from their model number.
+/// All of them have to be the same model.
+fn infer_register(proto: &mut dyn Protocol, ids: &[u8], name: &str) -> Result<regs::Reg> {
+    let mut found: Option<(u8, ServoModel)> = None;
+    for &id in ids {
+        let model = detect_model(proto, id).with_context(|| {
+            format!(
+                "Failed to detect the model for {0}, give it as MODEL/{0}",
+                name
+            )
+        })?;
+        match &found {
+            Some((first, other)) if other.name() != model.name() => {
+                return Err(anyhow!(
+                    "Id {} is {} but id {} is {}",
+                    first,
+                    other.name(),
+                    id,
+                    model.name()
+                ))
+            }
+            Some(_) => {}
+            None => found = Some((id, model)),
+        }
+    }
+
+    let (_, model) = found.ok_or(anyhow!("No device to detect the model of"))?;
+    model_register(&model, name)
+}
+
+/// `; did you mean ...?` listing `names`, empty if there are none
+fn did_you_mean(names: &[&str]) -> String {
+    match names {
+        [] => String::new(),
+        [name] => format!("; did you mean {}?", name),
+        [names @ .., last] => format!("; did you mean {} or {}?", names.join(", "), last),
+    }
+}
+
+fn model_not_found(proto: ProtocolVersion, name: &str) -> anyhow::Error {
+    anyhow!(
+        "Model {} not found (protocol {}){}",
+        name,
+        proto,
+        did_you_mean(&regs::similar_names(name, regs::list_models(proto)))
+    )
+}
+
+fn lookup_model(proto: ProtocolVersion, name: &str) -> Result<ServoModel> {
+    ServoModel::lookup(proto, name).ok_or_else(|| model_not_found(proto, name))
+}
+
+fn register_not_found(model: &ServoModel, name: &str) -> anyhow::Error {
+    anyhow!(
+        "Register {} not found for {}{}",
+        name,
+        model.name(),
+        did_you_mean(&regs::similar_names(
+            name,
+            model.registers().iter().map(|reg| reg.name)
+        ))
+    )
+}
+
+fn model_register(model: &ServoModel, name: &str) -> Result<regs::Reg> {
+    model
+        .register(name)
+        .ok_or_else(|| register_not_found(model, name))
+}
+
+fn read_reg_value(proto: &mut dyn Protocol, id: u8, reg: &regs::Reg) -> Result<u32> {
+    let bytes: Vec<_> = proto
+        .read(id, reg.address, reg.size as u16)
+        .map_err(|e| name_hardware_errors(proto, id, reg, e))?;
+    Ok(reg_value_from_bytes(reg, &bytes))
+}
+
+/// `e` of a register of `id`, with the errors in its HardwareErrorStatus
+/// named if its status reported a hardware alert
+fn name_hardware_errors(
+    proto: &mut dyn Protocol,
+    id: u8,
+    reg: &regs::Reg,
+    e: anyhow::Error,
+) -> anyhow::Error {
+    let alert = e.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref(),
+            Some(ProtocolError::StatusError(status)) if status.is_alert()
+        )
+    });
+    let status_reg = ServoModel::lookup(reg.proto, reg.model)
+        .and_then(|model| model.register("HardwareErrorStatus"));
+    let status_reg = match status_reg {
+        Some(status_reg) if alert => status_reg,
+        _ => return e,
+    };
+
+    match proto.read_alerted(id, status_reg.address, 1) {
+        Ok(bytes) => e.context(format!(
+            "{} {}",
+            status_reg.name,
+            HardwareErrorStatus(bytes[0])
+        )),
+        Err(read_err) => {
+            debug!(
+                "Failed to read {} of id {}: {:#}",
+                status_reg.name, id, read_err
+            );
+            e
+        }
+    }
+}
+
+fn reg_value_from_bytes(reg: &regs::Reg, bytes: &[u8]) -> u32 {
+    match reg.size {
+        regs::RegSize::Byte => u8::from_le_bytes(bytes[0..=0].try_into().unwrap()) as u32,
+        regs::RegSize::Half => u16::from_le_bytes(bytes[0..=1].try_into().unwrap()) as u32,
+        regs::RegSize::Word => u32::from_le_bytes(bytes[0..=3].try_into().unwrap()),
+        regs::RegSize::Variable => panic!("variable size registers not supported!"),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cmd_read_reg(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    regspec: RegSpec,
+    scaled: bool,
+    hex: bool,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+    vregs: &[VirtualReg],
+) -> Result<String> {
+    if let Some(vreg) = virtual_regs::find(vregs, &regspec) {
+        if hex {
+            return Err(anyhow!(
+                "{}/{} is a virtual register, it has no hex form",
+                vreg.model,
+                vreg.name
+            ));
+        }
+        return cmd_read_virtual_reg(proto, ids, vreg, fmt, layout);
+    }
+    let reg = lookup_register(proto, ids, regspec)?;
+    if scaled {
+        let unit = reg
+            .unit
+            .ok_or(anyhow!("{}/{} has no unit", reg.model, reg.name))?;
+        return cmd_read_reg_scaled(proto, ids, &reg, unit, fmt, layout);
+    }
+
+    // Hex shows the raw bits, so negative values keep their two's
+    // complement form
+    let size = reg.size as usize;
+    let res = ids
+        .iter()
+        .map(|&id| -> Result<u32> {
+            let raw = read_reg_value(proto, id, &reg)
+                .with_context(|| format!("Failed to read register from id {}", id))?;
+            match hex {
+                true => stream_result(layout, id, Hex(raw, size))?,
+                false => stream_result(layout, id, reg.value_from_raw(raw))?,
+            }
+            Ok(raw)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if hex {
+        let res = res.into_iter().map(|raw| Hex(raw, size)).collect();
+        reg_output(ids, &reg, res, fmt, layout)
+    } else {
+        let res = res.into_iter().map(|raw| reg.value_from_raw(raw)).collect();
+        reg_output(ids, &reg, res, fmt, layout)
+    }
+}
+
+fn reg_output<T>(
+    ids: &[u8],
+    reg: &regs::Reg,
+    res: Vec<T>,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> Result<String>
+where
+    T: Display + Into<json::JsonValue> + Into<serde_yaml::Value>,
+{
+    Ok(match fmt {
+        OutputFormat::Plain => {
+            let values: Vec<_> = res.iter().map(|v| paint(v, Outcome::Ok)).collect();
+            slice_to_line(&values)
+        }
+        OutputFormat::Json => json_results(ids, res, layout),
+        OutputFormat::Csv => csv_rows(
+            ids.iter()
+                .zip(res)
+                .map(|(id, value)| format!("{},{},{}", id, reg.name, value)),
+        ),
+        OutputFormat::Yaml => regs_yaml(
+            ids.iter()
+                .zip(res)
+                .map(|(&id, value)| (id, reg.model, vec![(reg.name, value.into())])),
+        )?,
+    })
+}
+
+fn cmd_read_reg_scaled(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    reg: &regs::Reg,
+    unit: regs::Unit,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> Result<String> {
+    let res = ids
+        .iter()
+        .map(|&id| -> Result<i64> {
+            let raw = read_reg_value(proto, id, reg)
+                .with_context(|| format!("Failed to read register from id {}", id))?;
+            let value = reg.value_from_raw(raw);
+            stream_result(layout, id, scaled_json(unit, value))?;
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match fmt {
+        OutputFormat::Plain => res
+            .iter()
+            .map(|&value| {
+                let value = format!("{:.*} {}", unit.precision(), unit.value(value), unit.name);
+                paint(value, Outcome::Ok)
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        OutputFormat::Csv => csv_rows(ids.iter().zip(res).map(|(id, value)| {
+            format!(
+                "{},{},{:.*},{}",
+                id,
+                reg.name,
+                unit.precision(),
+                unit.value(value),
+                unit.name
+            )
+        })),
+        OutputFormat::Yaml => regs_yaml(ids.iter().zip(res).map(|(&id, value)| {
+            let value = unit.value(value).into();
+            (id, reg.model, vec![(reg.name, value)])
+        }))?,
+        OutputFormat::Json => json_results(
+            ids,
+            res.into_iter()
+                .map(|value| scaled_json(unit, value))
+                .collect(),
+            layout,
+        ),
+    })
+}
+
+fn scaled_json(unit: regs::Unit, value: i64) -> json::JsonValue {
+    json::object! { raw: value, value: unit.value(value), unit: unit.name }
+}
+
+fn cmd_read_virtual_reg(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    vreg: &VirtualReg,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> Result<String> {
+    let regs = vreg
+        .registers()
+        .into_iter()
+        .map(|regspec| lookup_register(proto, ids, regspec))
+        .collect::<Result<Vec<_>>>()
+        .with_context(|| format!("Bad formula for {}/{}", vreg.model, vreg.name))?;
+
+    let res = ids
+        .iter()
+        .map(|&id| -> Result<f64> {
+            let values = regs
+                .iter()
+                .map(|reg| read_reg_value(proto, id, reg).map(|raw| reg.value_from_raw(raw)))
+                .collect::<Result<Vec<_>>>()
+                .with_context(|| format!("Failed to read register from id {}", id))?;
+            let value = vreg.eval(&values);
+            stream_result(layout, id, value)?;
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match fmt {
+        OutputFormat::Plain => {
+            let values: Vec<_> = res.iter().map(|v| paint(v, Outcome::Ok)).collect();
+            slice_to_line(&values)
+        }
+        OutputFormat::Json => json_results(ids, res, layout),
+        OutputFormat::Csv => csv_rows(
+            ids.iter()
+                .zip(res)
+                .map(|(id, value)| format!("{},{},{}", id, vreg.name, value)),
+        ),
+        OutputFormat::Yaml => regs_yaml(ids.iter().zip(res).map(|(&id, value)| {
+            let values = vec![(vreg.name.as_str(), value.into())];
+            (id, vreg.model.as_str(), values)
+        }))?,
+    })
+}
+
+fn cmd_write_int<const N: usize, T>(
+    proto: &mut dyn Protocol,
+    targets: &WriteTargets,
+    map: bool,
+    address: u16,
+    values: &[T],
+    sync: bool,
+    deferred: bool,
+) -> Result<String>
+where
+    T: Copy + ToBytes<Bytes = [u8; N]> + TryFrom<i64>,
+{
+    let ids = match (targets, map) {
+        (WriteTargets::Ids(ids), false) => ids,
+        (WriteTargets::Map(pairs), true) => {
+            return cmd_write_int_map::<N, T>(proto, pairs, address)
+        }
+        (WriteTargets::Map(_), false) => return Err(anyhow!("ID=VALUE pairs need --map")),
+        (WriteTargets::Ids(_), true) => {
+            return Err(anyhow!("--map needs ID=VALUE pairs like 1=1000,2=2000"))
+        }
+    };
+
+    if deferred {
+        if sync {
+            return Err(anyhow!("Deferred writes can't be synchronous"));
+        }
+        if values.len() != ids.len() && values.len() != 1 {
+            return Err(anyhow!("Need {} values, got {}", ids.len(), values.len()));
+        }
+        ids.iter()
+            .enumerate()
+            .map(|(i, &id)| {
+                let value = values[if values.len() == 1 { 0 } else { i }];
+                proto
+                    .reg_write(id, address, value.to_le_bytes().as_slice())
+                    .with_context(|| {
+                        format!(
+                            "Failed to stage {} write to id {}",
+                            std::any::type_name::<T>(),
+                            id
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|_| Ok(String::new()))?
+    } else if !sync {
+        if values.len() != 1 {
+            return Err(anyhow!("Multiple values supported in sync mode only"));
+        }
+        ids.iter()
+            .map(|&id| {
+                proto
+                    .write(id, address, values[0].to_le_bytes().as_slice())
+                    .with_context(|| {
+                        format!(
+                            "Failed to write {} to id {}",
+                            std::any::type_name::<T>(),
+                            id
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|_| Ok(String::new()))?
+    } else {
+        if values.len() != ids.len() && values.len() != 1 {
+            return Err(anyhow!("Need {} values, got {}", ids.len(), values.len()));
+        }
+
+        if values.len() != 1 {
+            proto
+                .sync_write(ids, address, &slice_to_byte_slices(values))
+                .map(|_| Ok(String::new()))?
+        } else {
+            proto
+                .sync_write(
+                    ids,
+                    address,
+                    &slice_to_byte_slices(&vec![values[0]; ids.len()]),
+                )
+                .map(|_| Ok(String::new()))?
+        }
+    }
+}
+
+/// Sync write of a value per id
+fn cmd_write_int_map<const N: usize, T>(
+    proto: &mut dyn Protocol,
+    pairs: &[(u8, i64)],
+    address: u16,
+) -> Result<String>
+where
+    T: Copy + ToBytes<Bytes = [u8; N]> + TryFrom<i64>,
+{
+    let ids: Vec<u8> = pairs.iter().map(|&(id, _)| id).collect();
+    let values = pairs
+        .iter()
+        .map(|&(id, value)| {
+            T::try_from(value).map_err(|_| {
+                anyhow!(
+                    "Value {} for id {} is out of range for {}",
+                    value,
+                    id,
+                    std::any::type_name::<T>()
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    proto
+        .sync_write(&ids, address, &slice_to_byte_slices(&values))
+        .map(|_| String::new())
+}
+
 fn cmd_write_bytes(
     proto: &mut dyn Protocol,
     ids: &[u8],
-    address: u16,
-    values: &[u8],
+    address: u16,
+    values: &[u8],
+) -> Result<String> {
+    ids.iter()
+        .map(|&id| {
+            proto
+                .write(id, address, values)
+                .with_context(|| format!("Failed to write bytes to id {}", id))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|_| Ok(String::new()))?
+}
+
+fn cmd_reg_write(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    address: u16,
+    values: &[u8],
+) -> Result<String> {
+    ids.iter()
+        .map(|&id| {
+            proto
+                .reg_write(id, address, values)
+                .with_context(|| format!("Failed to stage write to id {}", id))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|_| Ok(String::new()))?
+}
+
+fn cmd_action(proto: &mut dyn Protocol, id: u8) -> Result<String> {
+    proto
+        .action(id)
+        .with_context(|| format!("Failed to send action to id {}", id))
+        .map(|_| String::new())
+}
+
+fn cmd_write_bytes_multiple(
+    proto: &mut dyn Protocol,
+    specs: &[MultiWriteSpec],
+    bulk: bool,
+) -> Result<String> {
+    if bulk {
+        for (i, spec) in specs.iter().enumerate() {
+            if spec.id == BROADCAST_ID {
+                return Err(anyhow!("Bulk write can't target broadcast id"));
+            }
+            if specs[..i].iter().any(|other| other.id == spec.id) {
+                return Err(anyhow!("Bulk write can't address id {} twice", spec.id));
+            }
+        }
+
+        let writes: Vec<_> = specs
+            .iter()
+            .map(|spec| (spec.id, spec.address, spec.data.as_slice()))
+            .collect();
+        proto
+            .bulk_write(&writes)
+            .context("Failed to bulk write bytes")?;
+        return Ok(String::new());
+    }
+
+    specs
+        .iter()
+        .map(|spec| {
+            proto
+                .write(spec.id, spec.address, &spec.data)
+                .with_context(|| format!("Failed to write bytes to id {}", spec.id))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|_| Ok(String::new()))?
+}
+
+fn reg_value_bytes(reg: &regs::Reg, value: u32) -> Result<Vec<u8>> {
+    Ok(match reg.size {
+        regs::RegSize::Byte => u8::try_from(value)?.to_le_bytes().to_vec(),
+        regs::RegSize::Half => u16::try_from(value)?.to_le_bytes().to_vec(),
+        regs::RegSize::Word => value.to_le_bytes().to_vec(),
+        regs::RegSize::Variable => {
+            return Err(anyhow!(
+                "{} has a variable size, which is not supported",
+                reg.name
+            ))
+        }
+    })
+}
+
+/// Raw value of `value` for `reg`, two's complement for signed registers
+fn reg_raw_value(reg: &regs::Reg, value: i64) -> Result<u32> {
+    reg.value_to_raw(value).ok_or(anyhow!(
+        "Value {} out of range for {}/{}",
+        value,
+        reg.model,
+        reg.name
+    ))
+}
+
+/// Numeric value of `value`, looking up value names of enumerated registers
+fn reg_named_value(reg: &regs::Reg, value: &RegValue) -> Result<i64> {
+    let name = match value {
+        RegValue::Number(value) => return Ok(*value),
+        RegValue::Name(name) => name,
+    };
+    if let Some(value) = regs::enum_value(reg, name) {
+        return Ok(value.into());
+    }
+
+    let names: Vec<_> = regs::enum_values(reg)
+        .iter()
+        .map(|(name, _)| *name)
+        .collect();
+    if names.is_empty() {
+        Err(anyhow!("{}/{} has no named values", reg.model, reg.name))
+    } else {
+        Err(anyhow!(
+            "Unknown value {} for {}/{}, expected one of: {}",
+            name,
+            reg.model,
+            reg.name,
+            names.join(", ")
+        ))
+    }
+}
+
+fn write_reg_value(proto: &mut dyn Protocol, id: u8, reg: &regs::Reg, value: u32) -> Result<()> {
+    proto
+        .write(id, reg.address, &reg_value_bytes(reg, value)?)
+        .map_err(|e| name_hardware_errors(proto, id, reg, e))
+}
+
+fn cmd_write_reg(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    regspec: RegSpec,
+    value: RegValue,
+) -> Result<String> {
+    let reg = lookup_register(proto, ids, regspec)?;
+    let raw = reg_raw_value(&reg, reg_named_value(&reg, &value)?)?;
+
+    ids.iter()
+        .map(|&id| {
+            write_reg_value(proto, id, &reg, raw)
+                .with_context(|| format!("Failed to write register to id {}", id))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|_| Ok(String::new()))?
+}
+
+fn cmd_write_regs(
+    proto: &mut dyn Protocol,
+    id: u8,
+    assignments: Vec<RegAssignment>,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let mut writes = assignments
+        .into_iter()
+        .map(|assignment| -> Result<(regs::Reg, i64, u32)> {
+            let reg = lookup_register(proto, &[id], assignment.reg)?;
+            let value = reg_named_value(&reg, &assignment.value)?;
+            let raw = reg_raw_value(&reg, value)?;
+            Ok((reg, value, raw))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let is_eeprom = |reg: &regs::Reg| {
+        ServoModel::lookup(reg.proto, reg.model).and_then(|model| model.area(reg))
+            == Some(regs::Area::Eeprom)
+    };
+
+    // EEPROM is only writable with torque off, so write it before RAM
+    writes.sort_by_key(|(reg, _, _)| !is_eeprom(reg));
+
+    let torque = writes
+        .iter()
+        .find(|(reg, _, _)| is_eeprom(reg))
+        .and_then(|(reg, _, _)| ServoModel::lookup(reg.proto, reg.model))
+        .and_then(|model| model.register("TorqueEnable"));
+    if let Some(torque) = torque {
+        let enabled = read_reg_value(proto, id, &torque)
+            .with_context(|| format!("Failed to read torque of id {}", id))?;
+        if enabled != 0 {
+            // Turned back on after the EEPROM, unless it is assigned too
+            if !writes.iter().any(|(reg, _, _)| reg.name == torque.name) {
+                writes.push((torque, enabled.into(), enabled));
+            }
+            writes.insert(0, (torque, 0, 0));
+        }
+    }
+
+    let res: Vec<_> = writes
+        .iter()
+        .map(|(reg, value, raw)| (reg, *value, write_reg_value(proto, id, reg, *raw)))
+        .collect();
+
+    write_summary(id, &res, fmt)
+}
+
+/// Per-register outcome of several writes, an error if any failed
+fn write_summary(
+    id: u8,
+    res: &[(&regs::Reg, i64, Result<()>)],
+    fmt: OutputFormat,
+) -> Result<String> {
+    let n_failed = res.iter().filter(|(_, _, r)| r.is_err()).count();
+
+    let summary = match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => res
+            .iter()
+            .map(|(reg, value, r)| match r {
+                Ok(_) => format!("{} {} OK", reg.name, value),
+                Err(e) => format!("{} {} FAILED ({:#})", reg.name, value, e),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            res.iter()
+                .map(|(reg, value, r)| {
+                    json::object! {
+                        register: reg.name,
+                        value: *value,
+                        error: r.as_ref().err().map(|e| format!("{:#}", e)),
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ),
+    };
+
+    if n_failed == 0 {
+        Ok(summary)
+    } else {
+        Err(anyhow!(
+            "Failed to write {} of {} registers to id {}\n{}",
+            n_failed,
+            res.len(),
+            id,
+            summary
+        ))
+    }
+}
+
+// Changing these mid-restore would cut the connection to the device
+static RESTORE_SKIPPED: &[&str] = &["ID", "BaudRate", "ProtocolType"];
+
+/// Registers of `id` in a json file written by `dump`. A file with a single
+/// device applies to any id.
+fn restore_values(path: &str, id: u8) -> Result<json::object::Object> {
+    let text = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?;
+    let data = json::parse(&text).with_context(|| format!("Failed to parse {}", path))?;
+
+    let devices = match data {
+        json::JsonValue::Object(devices) => devices,
+        _ => return Err(anyhow!("Expected a json object in {}", path)),
+    };
+    let values = match devices.get(&id.to_string()) {
+        Some(values) => values,
+        None if devices.len() == 1 => devices.iter().next().unwrap().1,
+        None => return Err(anyhow!("No registers of id {} in {}", id, path)),
+    };
+
+    match values {
+        json::JsonValue::Object(values) => Ok(values.clone()),
+        _ => Err(anyhow!("Expected an object of registers for id {}", id)),
+    }
+}
+
+fn cmd_restore(
+    proto: &mut dyn Protocol,
+    id: u8,
+    model: Option<&str>,
+    path: &str,
+    dry_run: bool,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let values = restore_values(path, id)?;
+    let model = match model {
+        Some(name) => lookup_model(proto.version(), name)?,
+        None => detect_model(proto, id)?,
+    };
+
+    let mut writes = Vec::new();
+    for (name, value) in values.iter() {
+        let reg = model_register(&model, name)?;
+        if value.is_null()
+            || matches!(reg.access, regs::Access::R)
+            || RESTORE_SKIPPED.contains(&reg.name)
+        {
+            debug!("restore: skipping {}", reg.name);
+            continue;
+        }
+
+        let value = value
+            .as_i64()
+            .ok_or(anyhow!("Value of {} is not an integer", reg.name))?;
+        let raw = reg_raw_value(&reg, value)?;
+        writes.push((reg, value, raw));
+    }
+
+    // Goal values last, once everything they depend on is set
+    writes.sort_by_key(|(reg, _, _)| {
+        if reg.name.starts_with("Goal") {
+            3
+        } else if reg.name == "TorqueEnable" {
+            2
+        } else if model.area(reg) == Some(regs::Area::Eeprom) {
+            0
+        } else {
+            1
+        }
+    });
+    // EEPROM is only writable with torque off
+    if let Some(torque) = model.register("TorqueEnable") {
+        writes.insert(0, (torque, 0, 0));
+    }
+
+    if dry_run {
+        return Ok(match fmt {
+            OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => writes
+                .iter()
+                .map(|(reg, value, _)| format!("{} {}", reg.name, value))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            OutputFormat::Json => json::stringify(
+                writes
+                    .iter()
+                    .map(|(reg, value, _)| json::object! {register: reg.name, value: *value})
+                    .collect::<Vec<_>>(),
+            ),
+        });
+    }
+
+    let res: Vec<_> = writes
+        .iter()
+        .map(|(reg, value, raw)| (reg, *value, write_reg_value(proto, id, reg, *raw)))
+        .collect();
+
+    write_summary(id, &res, fmt)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Verdict {
+    Pass,
+    /// Mismatch of a writable register, or a failed read or fix
+    Fail,
+    /// Mismatch of a read-only register
+    Warn,
+    Fixed,
+}
+
+impl Verdict {
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail => "FAIL",
+            Verdict::Warn => "WARN",
+            Verdict::Fixed => "FIXED",
+        }
+    }
+}
+
+fn cmd_verify(
+    proto: &mut dyn Protocol,
+    id: u8,
+    model: Option<&str>,
+    path: &str,
+    fix: bool,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let values = restore_values(path, id)?;
+    let model = match model {
+        Some(name) => lookup_model(proto.version(), name)?,
+        None => detect_model(proto, id)?,
+    };
+
+    let mut expected = Vec::new();
+    for (name, value) in values.iter() {
+        let reg = model_register(&model, name)?;
+        if value.is_null()
+            || matches!(reg.access, regs::Access::W)
+            || RESTORE_SKIPPED.contains(&reg.name)
+        {
+            debug!("verify: skipping {}", reg.name);
+            continue;
+        }
+        if reg.size == regs::RegSize::Variable {
+            return Err(anyhow!(
+                "{} has a variable size, which verify doesn't support",
+                reg.name
+            ));
+        }
+        let value = value
+            .as_i64()
+            .ok_or(anyhow!("Value of {} is not an integer", reg.name))?;
+        expected.push((reg, value));
+    }
+    expected.sort_by_key(|(reg, _)| reg.address);
+
+    let regs = expected.iter().map(|(reg, _)| *reg).collect::<Vec<_>>();
+    let actual = read_reg_runs(proto, id, &regs);
+
+    let mut res = Vec::new();
+    for ((reg, value), raw) in expected.into_iter().zip(actual) {
+        let actual = raw.map(|raw| reg.value_from_raw(raw));
+        let verdict = match actual {
+            Some(actual) if actual == value => Verdict::Pass,
+            Some(_) if matches!(reg.access, regs::Access::R) => Verdict::Warn,
+            Some(_) if fix => {
+                match reg_raw_value(&reg, value)
+                    .and_then(|raw| write_reg_value(proto, id, &reg, raw))
+                {
+                    Ok(()) => Verdict::Fixed,
+                    Err(e) => {
+                        warn!("Failed to write {} to id {}: {:#}", reg.name, id, e);
+                        Verdict::Fail
+                    }
+                }
+            }
+            _ => Verdict::Fail,
+        };
+        res.push((reg, value, actual, verdict));
+    }
+
+    let count = |verdict| res.iter().filter(|(_, _, _, v)| *v == verdict).count();
+    let (matched, mismatched, fixed, warnings) = (
+        count(Verdict::Pass),
+        count(Verdict::Fail),
+        count(Verdict::Fixed),
+        count(Verdict::Warn),
+    );
+
+    let out = match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => {
+            let width = res
+                .iter()
+                .map(|(reg, _, _, _)| reg.name.len())
+                .max()
+                .unwrap_or(0);
+            res.iter()
+                .map(|(reg, value, actual, verdict)| {
+                    let actual = actual.map_or("-".to_string(), |v| v.to_string());
+                    match verdict {
+                        Verdict::Pass => format!("PASS  {:<width$} {}", reg.name, value),
+                        _ => format!(
+                            "{:<5} {:<width$} {} (read {})",
+                            verdict.name(),
+                            reg.name,
+                            value,
+                            actual
+                        ),
+                    }
+                })
+                .chain(std::iter::once(format!(
+                    "{} matched, {} mismatched, {} fixed, {} warnings",
+                    matched, mismatched, fixed, warnings
+                )))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        OutputFormat::Json => json::stringify(json::object! {
+            registers: res
+                .iter()
+                .map(|(reg, value, actual, verdict)| {
+                    json::object! {
+                        register: reg.name,
+                        expected: *value,
+                        actual: *actual,
+                        result: verdict.name().to_lowercase(),
+                    }
+                })
+                .collect::<Vec<_>>(),
+            matched: matched,
+            mismatched: mismatched,
+            fixed: fixed,
+            warnings: warnings,
+        }),
+    };
+
+    if mismatched == 0 {
+        Ok(out)
+    } else {
+        Err(ExitFailure(out).into())
+    }
+}
+
+fn regs_json(values: &[(regs::Reg, u32)]) -> json::JsonValue {
+    let mut obj = json::JsonValue::new_object();
+    for (reg, value) in values {
+        obj[reg.name] = reg.value_from_raw(*value).into();
+    }
+    obj
+}
+
+/// Read registers of a device with one read per run of adjacent registers.
+/// If a run fails, its registers are read one by one, those still failing
+/// are `None`.
+fn read_reg_runs(proto: &mut dyn Protocol, id: u8, regs: &[regs::Reg]) -> Vec<Option<u32>> {
+    let mut values = Vec::with_capacity(regs.len());
+
+    for run in regs.chunk_by(|a, b| a.address + a.size as u16 == b.address) {
+        let start = run[0].address;
+        let last = run.last().unwrap();
+        let count = last.address + last.size as u16 - start;
+
+        match proto.read_chunked(id, start, count) {
+            Ok(bytes) => values.extend(run.iter().map(|reg| {
+                let offset = (reg.address - start) as usize;
+                Some(reg_value_from_bytes(reg, &bytes[offset..]))
+            })),
+            Err(e) => {
+                debug!("Failed to read {} bytes at {}: {:#}", count, start, e);
+                values.extend(run.iter().map(|reg| {
+                    read_reg_value(proto, id, reg)
+                        .map_err(|e| {
+                            warn!(
+                                "Failed to read register {} from id {}: {:#}",
+                                reg.name, id, e
+                            )
+                        })
+                        .ok()
+                }))
+            }
+        }
+    }
+    values
+}
+
+/// Registers of a device with their raw values, `None` if the read failed
+type DumpValues = Vec<(regs::Reg, Option<u32>)>;
+
+/// Like `regs_json`, with registers that failed to read as nulls
+fn dump_json(values: &[(regs::Reg, Option<u32>)]) -> json::JsonValue {
+    let mut obj = json::JsonValue::new_object();
+    for (reg, value) in values {
+        obj[reg.name] = value.map(|value| reg.value_from_raw(value)).into();
+    }
+    obj
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cmd_dump(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    model: Option<&str>,
+    area: Option<regs::Area>,
+    as_commands: bool,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> Result<String> {
+    let model = model
+        .map(|name| lookup_model(proto.version(), name))
+        .transpose()?;
+
+    let res = ids
+        .iter()
+        .map(|&id| -> Result<(&str, DumpValues)> {
+            let model = match &model {
+                Some(model) => model.clone(),
+                None => detect_model(proto, id)?,
+            };
+            if area.is_some() && model.ram_start().is_none() {
+                return Err(anyhow!(
+                    "EEPROM and RAM areas of {} are unknown",
+                    model.name()
+                ));
+            }
+
+            let regs = model
+                .registers()
+                .iter()
+                .filter(|reg| {
+                    !matches!(reg.access, regs::Access::W)
+                        && reg.size != regs::RegSize::Variable
+                        && (area.is_none() || model.area(reg) == area)
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            let values = regs
+                .iter()
+                .cloned()
+                .zip(read_reg_runs(proto, id, &regs))
+                .collect::<Vec<_>>();
+            if !as_commands {
+                stream_result(layout, id, dump_json(&values))?;
+            }
+            Ok((model.name(), values))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if as_commands {
+        return Ok(ids
+            .iter()
+            .zip(res.iter())
+            .flat_map(|(id, (_, values))| {
+                values
+                    .iter()
+                    .filter(|(reg, _)| matches!(reg.access, regs::Access::RW))
+                    .filter_map(move |(reg, value)| {
+                        value.map(|value| {
+                            format!(
+                                "dynamixel-tool -P {} write-reg {} {}/{} {}",
+                                reg.proto,
+                                id,
+                                reg.model,
+                                reg.name,
+                                reg.value_from_raw(value)
+                            )
+                        })
+                    })
+            })
+            .collect::<Vec<_>>()
+            .join("\n"));
+    }
+
+    let width = res
+        .iter()
+        .flat_map(|(_, values)| values)
+        .map(|(reg, _)| reg.name.len())
+        .max()
+        .unwrap_or(0);
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv => ids
+            .iter()
+            .zip(res.iter())
+            .flat_map(|(id, (_, values))| {
+                values.iter().map(move |(reg, value)| {
+                    let value = match value {
+                        Some(value) => paint(reg.value_from_raw(*value), Outcome::Ok),
+                        None => paint("-", Outcome::Error),
+                    };
+                    format!("{:3} {:4} {:<width$} {}", id, reg.address, reg.name, value)
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json if layout == JsonLayout::Stream => String::new(),
+        OutputFormat::Json => {
+            let mut obj = json::JsonValue::new_object();
+            for (id, (_, values)) in ids.iter().zip(res.iter()) {
+                obj[id.to_string()] = dump_json(values);
+            }
+            json::stringify(obj)
+        }
+        OutputFormat::Yaml => regs_yaml(ids.iter().zip(res).map(|(&id, (model, values))| {
+            let values = values
+                .into_iter()
+                .map(|(reg, value)| {
+                    let value = value.map(|value| reg.value_from_raw(value).into());
+                    (reg.name, value.unwrap_or(serde_yaml::Value::Null))
+                })
+                .collect();
+            (id, model, values)
+        }))?,
+    })
+}
+
+/// Error carrying the output of a command that ran fine but should exit
+/// with status 1, like `diff` finding differences
+#[derive(Debug)]
+struct ExitFailure(String);
+
+impl Display for ExitFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for ExitFailure {}
+
+// Exit statuses of failed commands, usage errors take EX_USAGE
+const EXIT_FAILURE: i32 = 1;
+const EXIT_PORT: i32 = 2;
+const EXIT_TIMEOUT: i32 = 3;
+const EXIT_STATUS: i32 = 4;
+const EXIT_USAGE: i32 = 64;
+
+/// Exit status for `e`, from the first error of its chain with a status of
+/// its own
+fn exit_code(e: &anyhow::Error) -> i32 {
+    for cause in e.chain() {
+        if cause.is::<port::OpenPortError>() {
+            return EXIT_PORT;
+        }
+        match cause.downcast_ref::<ProtocolError>() {
+            Some(ProtocolError::TimedOut) => return EXIT_TIMEOUT,
+            Some(ProtocolError::StatusError(_)) => return EXIT_STATUS,
+            _ => {}
+        }
+    }
+    EXIT_FAILURE
+}
+
+/// Output cut short by a closed pipe, e.g. `list-models | head -1`
+fn is_broken_pipe(e: &anyhow::Error) -> bool {
+    e.chain()
+        .filter_map(|cause| cause.downcast_ref::<io::Error>())
+        .any(|e| e.kind() == io::ErrorKind::BrokenPipe)
+}
+
+/// Destination of command results, chosen with --out
+enum Sink {
+    Stdout,
+    /// Temporary file renamed to `path` once the command is done, so that
+    /// `path` never holds partial output
+    File {
+        file: BufWriter<std::fs::File>,
+        tmp: PathBuf,
+        path: PathBuf,
+    },
+}
+
+static SINK: Mutex<Sink> = Mutex::new(Sink::Stdout);
+
+/// Writer for command results, to stdout or the file given with --out.
+/// Commands printing results as they go, like `watch`, write here too.
+struct Output;
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match &mut *SINK.lock().unwrap() {
+            Sink::Stdout => io::stdout().write(buf),
+            Sink::File { file, .. } => file.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut *SINK.lock().unwrap() {
+            Sink::Stdout => io::stdout().flush(),
+            Sink::File { file, .. } => file.flush(),
+        }
+    }
+}
+
+/// Send results to `path`, `-` is stdout
+fn open_output(path: &str) -> Result<()> {
+    if path == "-" {
+        return Ok(());
+    }
+    let path = PathBuf::from(path);
+    let mut tmp = path.clone().into_os_string();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    let file = std::fs::File::create(&tmp)
+        .with_context(|| format!("Failed to create {}", tmp.display()))?;
+
+    *SINK.lock().unwrap() = Sink::File {
+        file: BufWriter::new(file),
+        tmp,
+        path,
+    };
+    Ok(())
+}
+
+/// Move the results into place, or drop them if the command failed
+fn close_output(keep: bool) -> Result<()> {
+    let sink = std::mem::replace(&mut *SINK.lock().unwrap(), Sink::Stdout);
+    if let Sink::File { file, tmp, path } = sink {
+        if !keep {
+            let _ = std::fs::remove_file(&tmp);
+            return Ok(());
+        }
+        file.into_inner()
+            .map_err(|e| e.into_error())
+            .and_then(|file| file.sync_all())
+            .and_then(|_| std::fs::rename(&tmp, &path))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn output_is_file() -> bool {
+    matches!(*SINK.lock().unwrap(), Sink::File { .. })
+}
+
+fn print_output(output: &str) -> io::Result<()> {
+    let output = concise_output(output.to_string());
+    if !output.is_empty() {
+        writeln!(Output, "{}", output)?;
+    }
+    Ok(())
+}
+
+/// Register names compare ignoring case, dashes and underscores, so that
+/// `baud-rate` matches `BaudRate`
+fn reg_name_matches(reg: &regs::Reg, name: &str) -> bool {
+    reg.name.eq_ignore_ascii_case(&name.replace(['-', '_'], ""))
+}
+
+fn cmd_diff(
+    proto: &mut dyn Protocol,
+    ids: (u8, u8),
+    model: Option<&str>,
+    all: bool,
+    ignore: &[String],
+    fmt: OutputFormat,
+) -> Result<String> {
+    let model = match model {
+        Some(name) => lookup_model(proto.version(), name)?,
+        None => {
+            let model = detect_model(proto, ids.0)?;
+            let other = detect_model(proto, ids.1)?;
+            if model.name() != other.name() {
+                return Err(anyhow!(
+                    "Id {} is {} but id {} is {}",
+                    ids.0,
+                    model.name(),
+                    ids.1,
+                    other.name()
+                ));
+            }
+            model
+        }
+    };
+    if !all && model.ram_start().is_none() {
+        return Err(anyhow!(
+            "EEPROM area of {} is unknown, use --all",
+            model.name()
+        ));
+    }
+    if let Some(name) = ignore.iter().find(|name| {
+        !model
+            .registers()
+            .iter()
+            .any(|reg| reg_name_matches(reg, name))
+    }) {
+        return Err(register_not_found(&model, name));
+    }
+
+    let regs = model
+        .registers()
+        .iter()
+        .filter(|reg| {
+            !matches!(reg.access, regs::Access::W)
+                && reg.size != regs::RegSize::Variable
+                && (all || model.area(reg) == Some(regs::Area::Eeprom))
+                && !ignore.iter().any(|name| reg_name_matches(reg, name))
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    let values_a = read_reg_runs(proto, ids.0, &regs);
+    let values_b = read_reg_runs(proto, ids.1, &regs);
+
+    let value = |reg: &regs::Reg, value: Option<u32>| value.map(|value| reg.value_from_raw(value));
+    let diffs = regs
+        .iter()
+        .zip(values_a.into_iter().zip(values_b))
+        .filter(|(_, (a, b))| a != b)
+        .map(|(reg, (a, b))| (reg, value(reg, a), value(reg, b)))
+        .collect::<Vec<_>>();
+
+    let width = diffs
+        .iter()
+        .map(|(reg, _, _)| reg.name.len())
+        .max()
+        .unwrap_or(0);
+    let out = match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => diffs
+            .iter()
+            .map(|(reg, a, b)| {
+                let show = |v: &Option<i64>| v.map_or("-".to_string(), |v| v.to_string());
+                format!("{:<width$} {:>11} {:>11}", reg.name, show(a), show(b))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => {
+            let mut obj = json::JsonValue::new_object();
+            for (reg, a, b) in &diffs {
+                obj[reg.name] = json::array![*a, *b];
+            }
+            json::stringify(obj)
+        }
+    };
+
+    if diffs.is_empty() {
+        Ok(out)
+    } else {
+        Err(ExitFailure(out).into())
+    }
+}
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Make Ctrl-C set `INTERRUPTED` instead of killing the process, so that
+/// long running commands can stop gracefully. Called as each of them
+/// starts, clearing a Ctrl-C that stopped an earlier one in a `run`
+/// session.
+#[cfg(unix)]
+fn catch_interrupt() {
+    use nix::sys::signal::{signal, SigHandler, Signal};
+
+    INTERRUPTED.store(false, Ordering::Relaxed);
+
+    extern "C" fn handler(_: nix::libc::c_int) {
+        INTERRUPTED.store(true, Ordering::Relaxed);
+    }
+    // The handler only stores an atomic, which is async signal safe
+    if let Err(e) = unsafe { signal(Signal::SIGINT, SigHandler::Handler(handler)) } {
+        warn!("Failed to catch Ctrl-C: {}", e);
+    }
+}
+
+#[cfg(not(unix))]
+fn catch_interrupt() {
+    INTERRUPTED.store(false, Ordering::Relaxed);
+}
+
+/// Read a register from each of `ids`, in one sync read where possible
+fn poll_reg(proto: &mut dyn Protocol, ids: &[u8], reg: &regs::Reg) -> Vec<Option<i64>> {
+    if ids.len() > 1 && proto.version() == ProtocolVersion::V2 {
+        match proto.sync_read(ids, reg.address, reg.size as u16) {
+            Ok(res) => {
+                return res
+                    .into_iter()
+                    .map(|(id, data)| match data {
+                        Ok(bytes) => Some(reg.value_from_raw(reg_value_from_bytes(reg, &bytes))),
+                        Err(e) => {
+                            warn!("Failed to read register from id {}: {:#}", id, e);
+                            None
+                        }
+                    })
+                    .collect()
+            }
+            Err(e) => warn!("Failed to sync read register: {:#}", e),
+        }
+    }
+
+    ids.iter()
+        .map(|&id| match read_reg_value(proto, id, reg) {
+            Ok(raw) => Some(reg.value_from_raw(raw)),
+            Err(e) => {
+                warn!("Failed to read register from id {}: {:#}", id, e);
+                None
+            }
+        })
+        .collect()
+}
+
+fn cmd_watch(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    regspec: RegSpec,
+    interval: Duration,
+    count: Option<usize>,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let reg = lookup_register(proto, ids, regspec)?;
+    // Samples, min and max per id
+    let mut stats = vec![(0usize, i64::MAX, i64::MIN); ids.len()];
+
+    catch_interrupt();
+    let start = Instant::now();
+    let mut n = 0;
+    while count.is_none_or(|count| n < count) && !INTERRUPTED.load(Ordering::Relaxed) {
+        let next = start + interval * n as u32;
+        if let Some(delay) = next.checked_duration_since(Instant::now()) {
+            std::thread::sleep(delay);
+        }
+        let time = start.elapsed().as_millis() as f64 / 1000.0;
+        let values = poll_reg(proto, ids, &reg);
+        n += 1;
+
+        for (stat, value) in stats.iter_mut().zip(values.iter()) {
+            if let Some(value) = *value {
+                *stat = (stat.0 + 1, stat.1.min(value), stat.2.max(value));
+            }
+        }
+
+        let mut out = Output;
+        match fmt {
+            OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => {
+                let values: Vec<_> = values
+                    .iter()
+                    .map(|value| value.map_or("-".to_string(), |value| value.to_string()))
+                    .collect();
+                writeln!(out, "{:.3} {}", time, values.join(" "))?;
+            }
+            OutputFormat::Json => {
+                for (id, value) in ids.iter().zip(values) {
+                    let line = json::object! { time: time, id: *id, value: value };
+                    writeln!(out, "{}", json_text(line))?;
+                }
+            }
+        }
+    }
+
+    let stats = ids
+        .iter()
+        .zip(stats)
+        .filter(|(_, (samples, _, _))| *samples > 0);
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => stats
+            .map(|(id, (samples, min, max))| {
+                format!("{} samples {} min {} max {}", id, samples, min, max)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => {
+            let mut obj = json::JsonValue::new_object();
+            for (id, (samples, min, max)) in stats {
+                obj[id.to_string()] = json::object! { samples: samples, min: min, max: max };
+            }
+            json::stringify(obj)
+        }
+    })
+}
+
+fn cmd_blink(
+    proto: &mut dyn Protocol,
+    id: u8,
+    model: Option<&str>,
+    duration: Duration,
+) -> Result<String> {
+    let model = match model {
+        Some(name) => lookup_model(proto.version(), name)?,
+        None => detect_model(proto, id)?,
+    };
+    let reg = model
+        .register("LED")
+        .ok_or(anyhow!("{} has no LED register", model.name()))?;
+    let original = read_reg_value(proto, id, &reg)
+        .with_context(|| format!("Failed to read LED from id {}", id))?;
+    // The XL-320 LED takes a color, 7 is white
+    let on = match original {
+        0 if reg.model == "XL320" => 7,
+        0 => 1,
+        value => value,
+    };
+
+    catch_interrupt();
+    let start = Instant::now();
+    let half_period = Duration::from_millis(250);
+    let mut n = 0;
+    let mut res = Ok(());
+    while start.elapsed() < duration && !INTERRUPTED.load(Ordering::Relaxed) {
+        let value = if n % 2 == 0 { 0 } else { on };
+        res = write_reg_value(proto, id, &reg, value);
+        if res.is_err() {
+            break;
+        }
+        n += 1;
+        if let Some(delay) = (start + half_period * n).checked_duration_since(Instant::now()) {
+            std::thread::sleep(delay);
+        }
+    }
+
+    // Restore even if blinking failed or was interrupted
+    write_reg_value(proto, id, &reg, original)
+        .and(res)
+        .with_context(|| format!("Failed to write LED of id {}", id))?;
+    Ok(String::new())
+}
+
+fn cmd_monitor(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    regspecs: Vec<RegSpec>,
+    interval: Duration,
+    count: Option<usize>,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let regs = regspecs
+        .into_iter()
+        .map(|regspec| lookup_register(proto, ids, regspec))
+        .collect::<Result<Vec<_>>>()?;
+    // Redraw in place on a terminal, print one table per sample otherwise
+    let redraw = atty::is(atty::Stream::Stdout) && !output_is_file();
+    let widths: Vec<_> = regs.iter().map(|reg| reg.name.len().max(6)).collect();
+
+    catch_interrupt();
+    let start = Instant::now();
+    let mut n = 0;
+    while count.is_none_or(|count| n < count) && !INTERRUPTED.load(Ordering::Relaxed) {
+        let next = start + interval * n as u32;
+        if let Some(delay) = next.checked_duration_since(Instant::now()) {
+            std::thread::sleep(delay);
+        }
+        let time = start.elapsed().as_millis() as f64 / 1000.0;
+        // Values per register, then per id
+        let values: Vec<_> = regs.iter().map(|reg| poll_reg(proto, ids, reg)).collect();
+        n += 1;
+
+        let mut out = Output;
+        match fmt {
+            OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => {
+                if redraw {
+                    write!(out, "\x1b[2J\x1b[H")?;
+                } else if n > 1 {
+                    writeln!(out)?;
+                }
+                writeln!(out, "{:.3}", time)?;
+                let header: Vec<_> = regs
+                    .iter()
+                    .zip(&widths)
+                    .map(|(reg, width)| format!("{:>width$}", reg.name))
+                    .collect();
+                writeln!(out, " ID {}", header.join(" "))?;
+                for (i, id) in ids.iter().enumerate() {
+                    let row: Vec<_> = values
+                        .iter()
+                        .zip(&widths)
+                        .map(|(values, width)| {
+                            let value = values[i].map_or("-".to_string(), |v| v.to_string());
+                            format!("{:>width$}", value)
+                        })
+                        .collect();
+                    writeln!(out, "{:3} {}", id, row.join(" "))?;
+                }
+            }
+            OutputFormat::Json => {
+                for (i, id) in ids.iter().enumerate() {
+                    let mut line = json::object! { time: time, id: *id };
+                    for (reg, values) in regs.iter().zip(&values) {
+                        line[reg.name] = values[i].into();
+                    }
+                    writeln!(out, "{}", json_text(line))?;
+                }
+            }
+        }
+        out.flush()?;
+    }
+    Ok(String::new())
+}
+
+fn cmd_detect_model(proto: &mut dyn Protocol, ids: &[u8], fmt: OutputFormat) -> Result<String> {
+    let version = proto.version();
+    let found: Vec<_> = ids
+        .iter()
+        .filter_map(|&id| match proto.read(id, 0, 2) {
+            Ok(bytes) => Some((id, u16::from_le_bytes(bytes[0..=1].try_into().unwrap()))),
+            Err(e) => {
+                warn!("Failed to read model number from id {}: {:#}", id, e);
+                None
+            }
+        })
+        .map(|(id, number)| (id, number, regs::model_name_from_number(version, number)))
+        .collect();
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => found
+            .iter()
+            .map(|(id, number, model)| match model {
+                Some(model) => paint(format!("{} {} ({})", id, model, number), Outcome::Ok),
+                None => paint(format!("{} unknown({})", id, number), Outcome::Warning),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            found
+                .into_iter()
+                .map(|(id, number, model)| {
+                    json::object! { id: id, model_number: number, model: model }
+                })
+                .collect::<Vec<_>>(),
+        ),
+    })
+}
+
+fn cmd_benchmark(
+    proto: &mut dyn Protocol,
+    id: u8,
+    count: usize,
+    size: u16,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let mut latencies = Vec::with_capacity(count);
+    // Timeouts, corrupted packets, status errors and other failures
+    let mut errors = [0usize; 4];
+
+    catch_interrupt();
+    let retries = proto.retry_count();
+    let start = Instant::now();
+    while latencies.len() + errors.iter().sum::<usize>() < count
+        && !INTERRUPTED.load(Ordering::Relaxed)
+    {
+        let t = Instant::now();
+        match proto.read(id, 0, size) {
+            Ok(_) => latencies.push(t.elapsed()),
+            Err(e) => {
+                debug!("read failed: {:#}", e);
+                let kind = match e.downcast_ref::<ProtocolError>() {
+                    Some(ProtocolError::TimedOut) => 0,
+                    Some(ProtocolError::BadPacket) => 1,
+                    Some(ProtocolError::StatusError(_)) => 2,
+                    _ => 3,
+                };
+                errors[kind] += 1;
+            }
+        }
+    }
+    let elapsed = start.elapsed();
+    let retries = proto.retry_count() - retries;
+    let transactions = latencies.len() + errors.iter().sum::<usize>();
+    let rate = transactions as f64 / elapsed.as_secs_f64();
+
+    latencies.sort();
+    let ms = |d: &Duration| d.as_secs_f64() * 1000.0;
+    let min = latencies.first().map(ms);
+    let max = latencies.last().map(ms);
+    let avg = (!latencies.is_empty())
+        .then(|| latencies.iter().map(ms).sum::<f64>() / latencies.len() as f64);
+    let p95 = latencies.get(latencies.len() * 95 / 100).map(ms);
+
+    // Power of two buckets in microseconds, as (upper bound, count)
+    let mut histogram: Vec<(u128, usize)> = Vec::new();
+    for latency in &latencies {
+        let bound = latency.as_micros().max(1).next_power_of_two();
+        match histogram.last_mut() {
+            Some((last, n)) if *last == bound => *n += 1,
+            _ => histogram.push((bound, 1)),
+        }
+    }
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => {
+            let stat = |v: Option<f64>| v.map_or("-".to_string(), |v| format!("{:.3}", v));
+            format!(
+                "transactions {} ok {} timeouts {} bad packets {} status errors {} other {} retries {}\n\
+                 latency ms min {} avg {} p95 {} max {}\n\
+                 throughput {:.1} transactions/s",
+                transactions,
+                latencies.len(),
+                errors[0],
+                errors[1],
+                errors[2],
+                errors[3],
+                retries,
+                stat(min),
+                stat(avg),
+                stat(p95),
+                stat(max),
+                rate
+            )
+        }
+        OutputFormat::Json => json::stringify(json::object! {
+            transactions: transactions,
+            ok: latencies.len(),
+            timeouts: errors[0],
+            bad_packets: errors[1],
+            status_errors: errors[2],
+            other_errors: errors[3],
+            retries: retries,
+            latency_ms: json::object! { min: min, avg: avg, p95: p95, max: max },
+            transactions_per_second: rate,
+            histogram_us: histogram
+                .into_iter()
+                .map(|(bound, count)| json::object! { le: bound as u64, count: count })
+                .collect::<Vec<_>>(),
+        }),
+    })
+}
+
+/// Identify the model of a device from its ModelNumber register. A device
+/// with a hardware alert still tells it, for its errors to be named later.
+fn detect_model(proto: &mut dyn Protocol, id: u8) -> Result<ServoModel> {
+    let bytes = proto
+        .read_alerted(id, 0, 2)
+        .with_context(|| format!("Failed to read model number from id {}", id))?;
+    let number = u16::from_le_bytes(bytes[0..=1].try_into().unwrap());
+
+    ServoModel::from_model_number(proto.version(), number).ok_or(anyhow!(
+        "Unknown model number {} of id {} (protocol {})",
+        number,
+        id,
+        proto.version()
+    ))
+}
+
+fn cmd_tune(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    gains: &[GainAssignment],
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> Result<String> {
+    let res = ids
+        .iter()
+        .map(|&id| -> Result<Vec<(regs::Reg, u32)>> {
+            let model = detect_model(proto, id)?;
+            let regs = model.gain_registers();
+            if regs.is_empty() {
+                return Err(anyhow!("No gain registers known for {}", model.name()));
+            }
+
+            for gain in gains {
+                let reg = regs
+                    .iter()
+                    .find(|reg| reg.name == gain.name)
+                    .ok_or(anyhow!(
+                        "{} has no gain {}, expected one of {}",
+                        model.name(),
+                        gain.name,
+                        regs.iter()
+                            .map(|reg| reg.name)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))?;
+                write_reg_value(proto, id, reg, gain.value)
+                    .with_context(|| format!("Failed to write {} to id {}", gain.name, id))?;
+            }
+
+            let values = regs
+                .iter()
+                .map(|reg| -> Result<(regs::Reg, u32)> {
+                    let value = read_reg_value(proto, id, reg).with_context(|| {
+                        format!("Failed to read register {} from id {}", reg.name, id)
+                    })?;
+                    Ok((*reg, value))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            stream_result(layout, id, regs_json(&values))?;
+            Ok(values)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => ids
+            .iter()
+            .zip(res.iter())
+            .flat_map(|(id, values)| {
+                values.iter().map(move |(reg, value)| {
+                    format!("{} {} {}", id, reg.name, reg.value_from_raw(*value))
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json if layout == JsonLayout::Stream => String::new(),
+        OutputFormat::Json => {
+            let mut obj = json::JsonValue::new_object();
+            for (id, values) in ids.iter().zip(res.iter()) {
+                obj[id.to_string()] = regs_json(values);
+            }
+            json::stringify(obj)
+        }
+    })
+}
+
+fn cmd_show_indirect(
+    proto: &mut dyn Protocol,
+    id: u8,
+    model: &str,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let model = ServoModel::lookup(proto.version(), model)
+        .filter(|m| !m.indirect_blocks().is_empty())
+        .ok_or(anyhow!(
+            "No indirect addressing known for model {} (protocol {})",
+            model,
+            proto.version()
+        ))?;
+    let blocks = model.indirect_blocks();
+
+    let mut res = Vec::new();
+
+    for block in blocks {
+        let bytes = proto
+            .read(id, block.address, block.count * 2)
+            .with_context(|| format!("Failed to read indirect addresses from id {}", id))?;
+
+        for (i, chunk) in bytes.chunks_exact(2).enumerate() {
+            let target = u16::from_le_bytes(chunk.try_into().unwrap());
+            let name = model
+                .register_at(target)
+                .map(|reg| match target - reg.address {
+                    0 => reg.name.to_string(),
+                    offset => format!("{}+{}", reg.name, offset),
+                });
+            res.push((
+                block.first_slot + i as u16,
+                block.data + i as u16,
+                target,
+                name,
+            ));
+        }
+    }
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => res
+            .iter()
+            .map(|(slot, data, target, name)| {
+                format!(
+                    "{:2} {:4} {:4} {}",
+                    slot,
+                    data,
+                    target,
+                    name.as_deref().unwrap_or("-")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            res.into_iter()
+                .map(|(slot, data, target, name)| {
+                    json::object! {
+                        slot: slot,
+                        data_address: data,
+                        address: target,
+                        register: name,
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ),
+    })
+}
+
+fn cmd_set_return_level(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    model: Option<&str>,
+    level: u8,
+) -> Result<String> {
+    if level > 2 {
+        return Err(anyhow!("Invalid status return level {}", level));
+    }
+
+    let model = model
+        .map(|name| lookup_model(proto.version(), name))
+        .transpose()?;
+
+    for &id in ids {
+        let model = match &model {
+            Some(model) => model.clone(),
+            None => ping_detect_model(proto, id)
+                .context("Give the model with --model if the servo doesn't answer reads")?,
+        };
+        let address = model
+            .register("StatusReturnLevel")
+            .ok_or(anyhow!(
+                "{} has no StatusReturnLevel register",
+                model.name()
+            ))?
+            .address;
+
+        // Below level 2 the servo doesn't acknowledge the write. Level 0
+        // can't be confirmed at all, level 1 is read back instead.
+        match proto.write(id, address, &[level]) {
+            Ok(()) => {}
+            Err(e) if is_timeout(&e) && level == 0 => {}
+            Err(e) if is_timeout(&e) => match proto.read(id, address, 1) {
+                Ok(data) if data[0] == level => {}
+                _ => return Err(anyhow!("Failed to set status return level for id {}", id)),
+            },
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to set status return level for id {}", id))
+            }
+        }
+    }
+    Ok(String::new())
+}
+
+/// Like `detect_model`, using the model number of the ping status on
+/// protocol 2. Servos at status return level 0 answer pings only.
+fn ping_detect_model(proto: &mut dyn Protocol, id: u8) -> Result<ServoModel> {
+    let number = match proto.ping(id)?.model {
+        Some(number) => number,
+        None => return detect_model(proto, id),
+    };
+    ServoModel::from_model_number(proto.version(), number).ok_or(anyhow!(
+        "Unknown model number {} of id {} (protocol {})",
+        number,
+        id,
+        proto.version()
+    ))
+}
+
+fn is_timeout(e: &anyhow::Error) -> bool {
+    matches!(e.downcast_ref(), Some(ProtocolError::TimedOut))
+}
+
+fn cmd_torque(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    model: Option<&str>,
+    enable: bool,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let model = model
+        .map(|name| lookup_model(proto.version(), name))
+        .transpose()?;
+    let regs = ids
+        .iter()
+        .map(|&id| -> Result<regs::Reg> {
+            let model = match &model {
+                Some(model) => model.clone(),
+                None => detect_model(proto, id)?,
+            };
+            model
+                .register("TorqueEnable")
+                .ok_or(anyhow!("{} has no TorqueEnable register", model.name()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Switch all servos at once where possible
+    let value = [enable as u8];
+    let same_address = regs.iter().all(|reg| reg.address == regs[0].address);
+    if ids.len() > 1 && proto.version() == ProtocolVersion::V2 && same_address {
+        let data = vec![value.as_slice(); ids.len()];
+        proto
+            .sync_write(ids, regs[0].address, &data)
+            .context("Failed to sync write torque enable")?;
+    } else {
+        for (&id, reg) in ids.iter().zip(&regs) {
+            write_reg_value(proto, id, reg, value[0].into())
+                .with_context(|| format!("Failed to write torque enable to id {}", id))?;
+        }
+    }
+
+    let states = ids
+        .iter()
+        .zip(&regs)
+        .map(|(&id, reg)| {
+            read_reg_value(proto, id, reg)
+                .map(|value| value != 0)
+                .with_context(|| format!("Failed to read torque enable from id {}", id))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let summary = match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => ids
+            .iter()
+            .zip(&states)
+            .map(|(id, &on)| format!("{} {}", id, if on { "on" } else { "off" }))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            ids.iter()
+                .zip(&states)
+                .map(|(&id, &on)| json::object! { id: id, torque: on })
+                .collect::<Vec<_>>(),
+        ),
+    };
+
+    match ids.iter().zip(&states).find(|(_, &on)| on != enable) {
+        Some((id, _)) => Err(anyhow!("Torque of id {} didn't switch\n{}", id, summary)),
+        None => Ok(summary),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cmd_move(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    model: Option<&str>,
+    position: i64,
+    wait: bool,
+    timeout: Duration,
+    tolerance: u32,
+    fmt: OutputFormat,
+) -> Result<String> {
+    let model = model
+        .map(|name| lookup_model(proto.version(), name))
+        .transpose()?;
+    // Goal and present position register per id
+    let regs = ids
+        .iter()
+        .map(|&id| -> Result<(regs::Reg, regs::Reg)> {
+            let model = match &model {
+                Some(model) => model.clone(),
+                None => detect_model(proto, id)?,
+            };
+            let reg = |name| {
+                model
+                    .register(name)
+                    .ok_or(anyhow!("{} has no {} register", model.name(), name))
+            };
+            Ok((reg("GoalPosition")?, reg("PresentPosition")?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let data = regs
+        .iter()
+        .map(|(goal, _)| reg_value_bytes(goal, reg_raw_value(goal, position)?))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Start all servos at once where possible
+    let (goal, present) = &regs[0];
+    let same_goal = regs.iter().all(|(reg, _)| reg.address == goal.address);
+    if ids.len() > 1 && proto.version() == ProtocolVersion::V2 && same_goal {
+        let data: Vec<_> = data.iter().map(Vec::as_slice).collect();
+        proto
+            .sync_write(ids, goal.address, &data)
+            .context("Failed to sync write goal position")?;
+    } else {
+        for ((&id, (goal, _)), bytes) in ids.iter().zip(&regs).zip(&data) {
+            proto
+                .write(id, goal.address, bytes)
+                .with_context(|| format!("Failed to write goal position to id {}", id))?;
+        }
+    }
+
+    let same_present = regs.iter().all(|(_, reg)| reg.address == present.address);
+    let start = Instant::now();
+    let (positions, reached) = loop {
+        let positions = if same_present {
+            poll_reg(proto, ids, present)
+        } else {
+            ids.iter()
+                .zip(&regs)
+                .map(|(&id, (_, reg))| poll_reg(proto, &[id], reg)[0])
+                .collect()
+        };
+        let reached = positions
+            .iter()
+            .map(|value| value.is_some_and(|value| value.abs_diff(position) <= tolerance.into()))
+            .collect::<Vec<_>>();
+        if !wait || reached.iter().all(|&r| r) || start.elapsed() >= timeout {
+            break (positions, reached);
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    };
+
+    let summary = match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => ids
+            .iter()
+            .zip(&positions)
+            .map(|(id, value)| {
+                let value = value.map_or("-".to_string(), |value| value.to_string());
+                format!("{} {}", id, value)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            ids.iter()
+                .zip(&positions)
+                .map(|(&id, &value)| json::object! { id: id, position: value })
+                .collect::<Vec<_>>(),
+        ),
+    };
+
+    match ids.iter().zip(&reached).find(|(_, &r)| wait && !r) {
+        Some((id, _)) => Err(anyhow!(
+            "Timed out waiting for id {} to reach {}\n{}",
+            id,
+            position,
+            summary
+        )),
+        None => Ok(summary),
+    }
+}
+
+const BROADCAST_ID: u8 = 254;
+
+// Time for a rebooted servo to come back, polled in REBOOT_POLL steps
+const REBOOT_TIMEOUT: Duration = Duration::from_secs(2);
+const REBOOT_POLL: Duration = Duration::from_millis(100);
+
+fn wait_for_reboot(proto: &mut dyn Protocol, id: u8) -> Result<()> {
+    let start = Instant::now();
+    loop {
+        std::thread::sleep(REBOOT_POLL);
+        match proto.ping(id) {
+            Ok(_) => return Ok(()),
+            Err(_) if start.elapsed() < REBOOT_TIMEOUT => continue,
+            Err(e) => return Err(e).context("No response after reboot"),
+        }
+    }
+}
+
+fn cmd_reboot(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    force: bool,
+    fmt: OutputFormat,
+) -> Result<String> {
+    if ids.contains(&BROADCAST_ID) && !force {
+        return Err(anyhow!(
+            "Broadcast reboot can't be confirmed, use --force to send it anyway"
+        ));
+    }
+
+    let res: Vec<_> = ids
+        .iter()
+        .map(|&id| {
+            let r = proto.reboot(id);
+            if id == BROADCAST_ID {
+                r
+            } else {
+                r.and_then(|_| wait_for_reboot(proto, id))
+            }
+        })
+        .collect();
+    let n_failed = res.iter().filter(|r| r.is_err()).count();
+
+    let summary = match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => ids
+            .iter()
+            .zip(res.iter())
+            .map(|(id, r)| match r {
+                Ok(_) => format!("{} OK", id),
+                Err(e) => format!("{} FAILED ({:#})", id, e),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json::stringify(
+            ids.iter()
+                .zip(res.iter())
+                .map(|(&id, r)| {
+                    json::object! {
+                        id: id,
+                        error: r.as_ref().err().map(|e| format!("{:#}", e)),
+                    }
+                })
+                .collect::<Vec<_>>(),
+        ),
+    };
+
+    if n_failed == 0 {
+        Ok(summary)
+    } else {
+        Err(anyhow!(
+            "Failed to reboot {} of {} ids\n{}",
+            n_failed,
+            res.len(),
+            summary
+        ))
+    }
+}
+
+fn cmd_clear(proto: &mut dyn Protocol, ids: &[u8], force: bool) -> Result<String> {
+    if proto.version() != ProtocolVersion::V2 {
+        return Err(anyhow!("Clearing multi-turn position needs protocol 2"));
+    }
+
+    for &id in ids {
+        if !force {
+            let model = detect_model(proto, id)
+                .context("Can't check torque, use --force to clear anyway")?;
+            let reg = model
+                .register("TorqueEnable")
+                .ok_or(anyhow!("{} has no TorqueEnable register", model.name()))?;
+            if read_reg_value(proto, id, &reg)? != 0 {
+                return Err(anyhow!(
+                    "Torque of id {} is enabled, use --force to clear anyway",
+                    id
+                ));
+            }
+        }
+
+        proto
+            .clear_multiturn(id)
+            .with_context(|| format!("Failed to clear id {}", id))?;
+    }
+    Ok(String::new())
+}
+
+fn cmd_backup(proto: &mut dyn Protocol, ids: &[u8], restore: bool) -> Result<String> {
+    ids.iter()
+        .map(|&id| {
+            proto.control_table_backup(id, restore).with_context(|| {
+                if restore {
+                    format!("Failed to restore control table of id {}", id)
+                } else {
+                    format!("Failed to back up control table of id {}", id)
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|_| Ok(String::new()))?
+}
+
+fn cmd_change_id(
+    proto: &mut dyn Protocol,
+    old_id: u8,
+    new_id: u8,
+    model: Option<&str>,
+    force: bool,
+    fmt: OutputFormat,
+) -> Result<String> {
+    // 253 is reserved in protocol 2
+    let max_id = match proto.version() {
+        ProtocolVersion::V1 => 253,
+        ProtocolVersion::V2 => 252,
+    };
+    if new_id > max_id {
+        return Err(anyhow!("Invalid id {}, expected 0-{}", new_id, max_id));
+    }
+    if new_id == old_id {
+        return Err(anyhow!("Id {} is unchanged", old_id));
+    }
+
+    let model = match model {
+        Some(name) => lookup_model(proto.version(), name)?,
+        None => detect_model(proto, old_id)?,
+    };
+    let reg = model
+        .register("ID")
+        .ok_or(anyhow!("{} has no ID register", model.name()))?;
+
+    if proto.ping(new_id).is_ok() && !force {
+        return Err(anyhow!(
+            "Id {} is already taken, use --force to change anyway",
+            new_id
+        ));
+    }
+
+    write_reg_value(proto, old_id, &reg, new_id.into())
+        .with_context(|| format!("Failed to write id to id {}", old_id))?;
+    proto
+        .ping(new_id)
+        .with_context(|| format!("No answer from new id {}", new_id))?;
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => {
+            format!("Changed id {} to {}", old_id, new_id)
+        }
+        OutputFormat::Json => json::stringify(json::object! {
+            old_id: old_id,
+            new_id: new_id,
+        }),
+    })
+}
+
+/// Opens the port itself since the servo stops answering at the old rate
+fn cmd_change_baud(
+    bus: &Bus,
+    ids: &[u8],
+    baudrate: u32,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+) -> Result<String> {
+    let mut port = bus.open(bus.baudrate)?;
+    let mut proto = protocol::master::make_protocol(
+        bus.protocol,
+        port.as_mut(),
+        bus.retries,
+        bus.timeout,
+        bus.tx,
+    );
+
+    // Check every servo supports the rate before changing any
+    let mut changes = Vec::new();
+    for &id in ids {
+        let model = detect_model(proto.as_mut(), id)?;
+        let reg = model
+            .register("BaudRate")
+            .ok_or(anyhow!("{} has no BaudRate register", model.name()))?;
+        let rates = model.baudrates();
+        let value = rates
+            .iter()
+            .find(|(_, rate)| *rate == baudrate)
+            .map(|(value, _)| *value)
+            .ok_or(anyhow!(
+                "Baud rate {} not supported by {}, expected one of {}",
+                baudrate,
+                model.name(),
+                rates
+                    .iter()
+                    .map(|(_, rate)| rate.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))?;
+
+        let old_value = read_reg_value(proto.as_mut(), id, &reg)
+            .with_context(|| format!("Failed to read baud rate of id {}", id))?;
+        let old_baudrate = rates
+            .iter()
+            .find(|(value, _)| u32::from(*value) == old_value)
+            .map_or(bus.baudrate, |(_, rate)| *rate);
+        changes.push((id, reg, value, old_baudrate));
+    }
+
+    for (id, reg, value, _) in &changes {
+        write_reg_value(proto.as_mut(), *id, reg, (*value).into())
+            .with_context(|| format!("Failed to write baud rate to id {}", id))?;
+    }
+    drop(proto);
+    drop(port);
+
+    let mut port = bus.open(baudrate)?;
+    let mut proto = protocol::master::make_protocol(
+        bus.protocol,
+        port.as_mut(),
+        bus.retries,
+        bus.timeout,
+        bus.tx,
+    );
+    let missing = ids
+        .iter()
+        .filter(|&&id| proto.ping(id).is_err())
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        return Err(anyhow!(
+            "No answer at {} baud from id {}",
+            baudrate,
+            slice_to_line(&missing)
+        ));
+    }
+
+    Ok(match fmt {
+        OutputFormat::Plain | OutputFormat::Csv | OutputFormat::Yaml => changes
+            .iter()
+            .map(|(id, _, _, old_baudrate)| {
+                format!(
+                    "Changed baud rate of id {} from {} to {}",
+                    id, old_baudrate, baudrate
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        OutputFormat::Json => json_results(
+            ids,
+            changes
+                .iter()
+                .map(|(id, _, _, old_baudrate)| {
+                    json::object! {
+                        id: *id,
+                        old_baudrate: *old_baudrate,
+                        new_baudrate: baudrate,
+                    }
+                })
+                .collect(),
+            layout,
+        ),
+    })
+}
+
+fn cmd_factory_reset(
+    proto: &mut dyn Protocol,
+    ids: &[u8],
+    scope: ResetScope,
+    force: bool,
 ) -> Result<String> {
+    if (ids.len() > 1 || ids.contains(&BROADCAST_ID)) && !force {
+        return Err(anyhow!(
+            "Refusing to reset more than one servo at once, use --force to do it anyway"
+        ));
+    }
+
     ids.iter()
         .map(|&id| {
             proto
-                .write(id, address, values)
-                .with_context(|| format!("Failed to write bytes to id {}", id))
+                .factory_reset(id, scope)
+                .with_context(|| format!("Failed to reset id {}", id))
         })
         .collect::<Result<Vec<_>, _>>()
         .map(|_| Ok(String::new()))?
 }
 
-fn cmd_write_bytes_multiple(proto: &mut dyn Protocol, specs: &[MultiWriteSpec]) -> Result<String> {
-    specs
-        .iter()
-        .map(|spec| {
-            proto
-                .write(spec.id, spec.address, &spec.data)
-                .with_context(|| format!("Failed to write bytes to id {}", spec.id))
-        })
-        .collect::<Result<Vec<_>, _>>()
-        .map(|_| Ok(String::new()))?
+/// Run the lines of `script`, each through `exec`
+fn cmd_run(
+    exec: &mut dyn FnMut(cli::Commands) -> Result<String>,
+    script: &str,
+    keep_going: bool,
+    ndjson: bool,
+    fmt: OutputFormat,
+) -> Result<String> {
+    // Stdin is read line by line, so a driving program sees each result
+    // before sending the next command
+    let interactive = script == "-";
+    let (name, input): (&str, Box<dyn BufRead>) = if interactive {
+        ("stdin", Box::new(io::stdin().lock()))
+    } else {
+        let file = std::fs::File::open(script)
+            .with_context(|| format!("Failed to read script {}", script))?;
+        (script, Box::new(io::BufReader::new(file)))
+    };
+
+    let mut failed = 0;
+    for (i, line) in input.lines().enumerate() {
+        let line = line.with_context(|| format!("Failed to read script {}", script))?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let res = cli::ScriptLine::try_parse_from(line.split_whitespace())
+            // Only the message, without clap's usage hints
+            .map_err(|e| anyhow!("{}", e.to_string().lines().next().unwrap_or_default()))
+            .and_then(|script_line| match script_line.command {
+                // These reopen the port or run scripts themselves
+                cli::Commands::ListModels { .. }
+                | cli::Commands::Completions { .. }
+                | cli::Commands::ListRegisters { .. }
+                | cli::Commands::ListPorts
+                | cli::Commands::Identify { .. }
+                | cli::Commands::DetectBaud { .. }
+                | cli::Commands::ChangeBaud { .. }
+                | cli::Commands::Run { .. } => Err(anyhow!("command not supported in scripts")),
+                cli::Commands::Scan {
+                    ref bauds,
+                    all_bauds,
+                    ..
+                } if all_bauds || !bauds.is_empty() => Err(anyhow!(
+                    "scan at other baud rates is not supported in scripts"
+                )),
+                command => exec(command),
+            });
+
+        let res = match res {
+            Ok(output) if ndjson => ndjson_result(&output, fmt)
+                .map(|result| json_text(json::object! {seq: i + 1, result: result})),
+            res => res,
+        };
+
+        let mut out = Output;
+        match res {
+            Ok(output) if ndjson => writeln!(out, "{}", output)?,
+            Ok(output) => {
+                for output_line in concise_output(output).lines() {
+                    writeln!(out, "{}: {}", i + 1, output_line)?;
+                }
+            }
+            Err(e) => {
+                error!("{}:{}: {}: {:#}", name, i + 1, line, e);
+                if ndjson {
+                    let obj = json::object! {seq: i + 1, error: format!("{:#}", e)};
+                    writeln!(out, "{}", json_text(obj))?;
+                }
+                failed += 1;
+                if !keep_going && !interactive {
+                    return Err(anyhow!("Script stopped at line {}", i + 1));
+                }
+            }
+        }
+        out.flush()?;
+    }
+
+    match failed {
+        n if n == 0 || interactive => Ok(String::new()),
+        n => Err(anyhow!("{} script lines failed", n)),
+    }
+}
+
+/// Port of a `run --reconnect` session. A port that went away is dropped
+/// and opened again, by name or auto-detection, before the next command.
+struct ReconnectingPort<'a, O> {
+    bus: &'a Bus,
+    open: O,
+    port: Option<Box<dyn SerialPort + Send>>,
+}
+
+impl<'a, O> ReconnectingPort<'a, O>
+where
+    O: FnMut() -> Result<Box<dyn SerialPort + Send>>,
+{
+    fn new(bus: &'a Bus, mut open: O) -> Result<Self> {
+        let port = open()?;
+        Ok(Self {
+            bus,
+            open,
+            port: Some(port),
+        })
+    }
+
+    fn run(
+        &mut self,
+        command: cli::Commands,
+        fmt: OutputFormat,
+        layout: JsonLayout,
+        vregs: &[VirtualReg],
+    ) -> Result<String> {
+        let bus = self.bus;
+        if self.port.is_none() {
+            let port =
+                (self.open)().with_context(|| format!("Failed to reopen port {}", bus.port))?;
+            warn!("Reopened port {}", bus.port);
+            self.port = Some(port);
+        }
+        let port = self.port.as_mut().unwrap();
+
+        let mut proto = protocol::master::make_protocol(
+            bus.protocol,
+            port.as_mut(),
+            bus.retries,
+            bus.timeout,
+            bus.tx,
+        );
+        let res = run_command(proto.as_mut(), command, bus, fmt, layout, vregs);
+        drop(proto);
+
+        // An unplugged adapter fails every call, a timeout or a bad packet
+        // leaves the port usable
+        if res.is_err() && port.bytes_to_read().is_err() {
+            warn!(
+                "Port {} is gone, reopening it for the next command",
+                bus.port
+            );
+            self.port = None;
+        }
+        res
+    }
+}
+
+/// Result of a script line for `run --ndjson`. Json output is embedded as
+/// is, anything else as a string, and commands without output like writes
+/// give null. Json printed over several lines is an array of them.
+fn ndjson_result(output: &str, fmt: OutputFormat) -> Result<json::JsonValue> {
+    if output.trim().is_empty() {
+        return Ok(json::JsonValue::Null);
+    }
+    if !matches!(fmt, OutputFormat::Json) {
+        return Ok(output.trim_end().into());
+    }
+
+    let mut values = output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| json::parse(line).with_context(|| format!("Output is not json: {}", line)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(match values.len() {
+        1 => values.remove(0),
+        _ => values.into(),
+    })
 }
 
-fn cmd_write_reg(
-    proto: &mut dyn Protocol,
-    ids: &[u8],
-    regspec: RegSpec,
-    value: u32,
+/// Run a command against a port that records the instruction packets
+/// instead of sending them, and print those. Nothing answers, so commands
+/// stop at their first read.
+fn cmd_dry_run(
+    command: cli::Commands,
+    bus: &Bus,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+    vregs: &[VirtualReg],
 ) -> Result<String> {
-    let reg = regs::find_register(proto.version(), regspec).ok_or(anyhow!("Register not found"))?;
+    let mut port = DryRunPort::new(bus.protocol, bus.baudrate);
+    let res = {
+        // Retries would only repeat unanswered packets
+        let mut proto_box = protocol::master::make_protocol(
+            bus.protocol,
+            &mut port,
+            0,
+            bus.timeout,
+            Default::default(),
+        );
+        run_command(proto_box.as_mut(), command, bus, fmt, layout, vregs)
+    };
 
-    ids.iter()
-        .map(|&id| {
-            match reg.size {
-                regs::RegSize::Byte => {
-                    proto.write(id, reg.address, &u8::try_from(value)?.to_le_bytes())
-                }
-                regs::RegSize::Half => {
-                    proto.write(id, reg.address, &u16::try_from(value)?.to_le_bytes())
-                }
-                regs::RegSize::Word => proto.write(id, reg.address, &value.to_le_bytes()),
-                regs::RegSize::Variable => panic!("variable size registers not supported!"),
+    let packets = port.packets();
+    let stopped = match res {
+        // Failed before sending anything, e.g. an unknown register
+        Err(e) if packets.is_empty() => return Err(e),
+        Err(e) => {
+            debug!("Dry run stopped: {:#}", e);
+            true
+        }
+        Ok(_) => false,
+    };
+
+    let hex = |packet: &[u8]| {
+        packet
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    Ok(match fmt {
+        OutputFormat::Json => json::stringify(json::object! {
+            packets: packets
+                .iter()
+                .map(|packet| json::object! {
+                    instruction: master::describe_packet(bus.protocol, packet),
+                    packet: hex(packet),
+                })
+                .collect::<Vec<_>>(),
+            stopped: stopped,
+        }),
+        _ => {
+            let mut lines: Vec<_> = packets
+                .iter()
+                .map(|packet| {
+                    format!(
+                        "{}\n    {}",
+                        master::describe_packet(bus.protocol, packet),
+                        hex(packet)
+                    )
+                })
+                .collect();
+            if stopped {
+                lines.push("Stopped waiting for a reply".to_string());
             }
-            .with_context(|| format!("Failed to write register to id {}", id))
-        })
-        .collect::<Result<Vec<_>, _>>()
-        .map(|_| Ok(String::new()))?
+            lines.join("\n")
+        }
+    })
+}
+
+/// Run a command that talks to the bus through `proto`
+fn run_command(
+    proto: &mut dyn Protocol,
+    command: cli::Commands,
+    bus: &Bus,
+    fmt: OutputFormat,
+    layout: JsonLayout,
+    vregs: &[VirtualReg],
+) -> Result<String> {
+    match command {
+        // Nothing to reconnect here, the port is given
+        cli::Commands::Run {
+            script,
+            keep_going,
+            ndjson,
+            ..
+        } => {
+            let mut exec = |command| run_command(proto, command, bus, fmt, layout, vregs);
+            cmd_run(&mut exec, &script, keep_going, ndjson, fmt)
+        }
+        cli::Commands::Scan {
+            detail,
+            models,
+            sort,
+            scan_start,
+            scan_end,
+            ..
+        } => {
+            let report = scan_report(detail, models);
+            cmd_scan(proto, scan_start, scan_end, report, sort, fmt)
+        }
+        cli::Commands::Ping { ids } => cmd_ping(proto, &ids, fmt),
+        cli::Commands::Firmware { ids } => cmd_firmware(proto, &ids, fmt),
+        cli::Commands::ExpectCount {
+            count,
+            scan_start,
+            scan_end,
+        } => cmd_expect_count(proto, count, scan_start, scan_end, fmt),
+        cli::Commands::ReadUint8 {
+            ids,
+            address,
+            sync,
+            fast,
+            hex,
+        } => cmd_read_int::<1, u8>(proto, &ids, address, fmt, layout, sync, fast, hex),
+        cli::Commands::ReadUint16 {
+            ids,
+            address,
+            sync,
+            fast,
+            hex,
+        } => cmd_read_int::<2, u16>(proto, &ids, address, fmt, layout, sync, fast, hex),
+        cli::Commands::ReadUint32 {
+            ids,
+            address,
+            sync,
+            fast,
+            hex,
+        } => cmd_read_int::<4, u32>(proto, &ids, address, fmt, layout, sync, fast, hex),
+        cli::Commands::ReadInt8 {
+            ids,
+            address,
+            sync,
+            fast,
+            hex,
+        } => cmd_read_int::<1, i8>(proto, &ids, address, fmt, layout, sync, fast, hex),
+        cli::Commands::ReadInt16 {
+            ids,
+            address,
+            sync,
+            fast,
+            hex,
+        } => cmd_read_int::<2, i16>(proto, &ids, address, fmt, layout, sync, fast, hex),
+        cli::Commands::ReadInt32 {
+            ids,
+            address,
+            sync,
+            fast,
+            hex,
+        } => cmd_read_int::<4, i32>(proto, &ids, address, fmt, layout, sync, fast, hex),
+        cli::Commands::ReadBytes {
+            sync,
+            strict,
+            hex,
+            ids,
+            address,
+            count,
+        } => cmd_read_bytes(proto, &ids, address, count, sync, strict, hex, fmt, layout),
+        cli::Commands::ReadBytesMultiple { fast, hex, specs } => {
+            cmd_read_bytes_multiple(proto, &specs, fast, hex, fmt, layout)
+        }
+        cli::Commands::ReadReg {
+            scaled,
+            hex,
+            ids,
+            reg,
+        } => cmd_read_reg(proto, &ids, reg, scaled, hex, fmt, layout, vregs),
+        cli::Commands::WriteUint8 {
+            ids,
+            address,
+            value,
+            sync,
+            deferred,
+            map,
+        } => cmd_write_int::<1, u8>(proto, &ids, map, address, &value, sync, deferred),
+        cli::Commands::WriteUint16 {
+            ids,
+            address,
+            value,
+            sync,
+            deferred,
+            map,
+        } => cmd_write_int::<2, u16>(proto, &ids, map, address, &value, sync, deferred),
+        cli::Commands::WriteUint32 {
+            ids,
+            address,
+            value,
+            sync,
+            deferred,
+            map,
+        } => cmd_write_int::<4, u32>(proto, &ids, map, address, &value, sync, deferred),
+        cli::Commands::WriteInt8 {
+            ids,
+            address,
+            value,
+            sync,
+            deferred,
+            map,
+        } => cmd_write_int::<1, i8>(proto, &ids, map, address, &value, sync, deferred),
+        cli::Commands::WriteInt16 {
+            ids,
+            address,
+            value,
+            sync,
+            deferred,
+            map,
+        } => cmd_write_int::<2, i16>(proto, &ids, map, address, &value, sync, deferred),
+        cli::Commands::WriteInt32 {
+            ids,
+            address,
+            value,
+            sync,
+            deferred,
+            map,
+        } => cmd_write_int::<4, i32>(proto, &ids, map, address, &value, sync, deferred),
+        cli::Commands::WriteBytes {
+            ids,
+            address,
+            values,
+        } => cmd_write_bytes(proto, &ids, address, &values),
+        cli::Commands::RegWrite {
+            ids,
+            address,
+            values,
+        } => cmd_reg_write(proto, &ids, address, &values),
+        cli::Commands::Action { id } => cmd_action(proto, id),
+        cli::Commands::WriteReg { ids, reg, value } => cmd_write_reg(proto, &ids, reg, value),
+        cli::Commands::WriteBytesMultiple { bulk, specs } => {
+            cmd_write_bytes_multiple(proto, &specs, bulk)
+        }
+        cli::Commands::WriteRegs { id, assignments } => cmd_write_regs(proto, id, assignments, fmt),
+        cli::Commands::Blink {
+            model,
+            duration,
+            id,
+        } => cmd_blink(proto, id, model.as_deref(), duration),
+        cli::Commands::Move {
+            model,
+            wait,
+            timeout,
+            tolerance,
+            ids,
+            position,
+        } => cmd_move(
+            proto,
+            &ids,
+            model.as_deref(),
+            position,
+            wait,
+            timeout,
+            tolerance,
+            fmt,
+        ),
+        cli::Commands::DetectModel { ids } => cmd_detect_model(proto, &ids, fmt),
+        cli::Commands::Benchmark { count, size, id } => cmd_benchmark(proto, id, count, size, fmt),
+        cli::Commands::Torque { model, ids, state } => {
+            cmd_torque(proto, &ids, model.as_deref(), state == Switch::On, fmt)
+        }
+        cli::Commands::TorqueOn { model, ids } => {
+            cmd_torque(proto, &ids, model.as_deref(), true, fmt)
+        }
+        cli::Commands::TorqueOff { model, ids } => {
+            cmd_torque(proto, &ids, model.as_deref(), false, fmt)
+        }
+        cli::Commands::Monitor {
+            interval,
+            count,
+            ids,
+            regs,
+        } => cmd_monitor(proto, &ids, regs, interval, count, fmt),
+        cli::Commands::Watch {
+            interval,
+            count,
+            ids,
+            reg,
+        } => cmd_watch(proto, &ids, reg, interval, count, fmt),
+        cli::Commands::Restore {
+            id,
+            model,
+            file,
+            dry_run,
+        } => cmd_restore(proto, id, model.as_deref(), &file, dry_run, fmt),
+        cli::Commands::Verify {
+            id,
+            model,
+            file,
+            fix,
+        } => cmd_verify(proto, id, model.as_deref(), &file, fix, fmt),
+        cli::Commands::Dump {
+            ids,
+            model,
+            eeprom_only,
+            ram_only,
+            as_commands,
+        } => {
+            let area = match (eeprom_only, ram_only) {
+                (true, _) => Some(regs::Area::Eeprom),
+                (_, true) => Some(regs::Area::Ram),
+                _ => None,
+            };
+            cmd_dump(
+                proto,
+                &ids,
+                model.as_deref(),
+                area,
+                as_commands,
+                fmt,
+                layout,
+            )
+        }
+        cli::Commands::Diff {
+            model,
+            all,
+            ignore,
+            id_a,
+            id_b,
+        } => cmd_diff(proto, (id_a, id_b), model.as_deref(), all, &ignore, fmt),
+        cli::Commands::Tune { ids, gains } => cmd_tune(proto, &ids, &gains, fmt, layout),
+        cli::Commands::ShowIndirect { id, model } => cmd_show_indirect(proto, id, &model, fmt),
+        cli::Commands::SetReturnLevel { ids, model, level } => {
+            cmd_set_return_level(proto, &ids, model.as_deref(), level)
+        }
+        cli::Commands::Clear { ids } => cmd_clear(proto, &ids, bus.force),
+        cli::Commands::Backup { restore, ids } => cmd_backup(proto, &ids, restore),
+        cli::Commands::ChangeId {
+            model,
+            old_id,
+            new_id,
+        } => cmd_change_id(proto, old_id, new_id, model.as_deref(), bus.force, fmt),
+        cli::Commands::Reboot { ids } => cmd_reboot(proto, &ids, bus.force, fmt),
+        cli::Commands::FactoryReset {
+            ids,
+            keep_id,
+            keep_baud,
+        } => {
+            let scope = if keep_baud {
+                ResetScope::KeepIdBaudrate
+            } else if keep_id {
+                ResetScope::KeepId
+            } else {
+                ResetScope::All
+            };
+            cmd_factory_reset(proto, &ids, scope, bus.force)
+        }
+        _ => Err(anyhow!("unexpected command (this is a bug!)")),
+    }
 }
 
 fn do_main() -> Result<String> {
+    // Kept for scripts predating the completions command
     if std::env::var("GENERATE_COMPLETION").is_ok() {
-        generate(
-            Bash,
-            &mut cli::Cli::command(),
-            "dynamixel-tool",
-            &mut io::stdout(),
-        );
-
+        write!(
+            io::stdout(),
+            "{}",
+            completions::completion_script(Shell::Bash)
+        )?;
         return Ok(String::default());
     }
 
-    let cli = Cli::parse();
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        // Help and version are not errors
+        if !e.use_stderr() {
+            e.exit();
+        }
+        let _ = e.print();
+        std::process::exit(EXIT_USAGE);
+    });
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(if cli.debug {
         "debug"
     } else {
         "info"
     }))
-        .format_timestamp(Some(env_logger::TimestampPrecision::Micros))
-        .format_target(false)
-        .init();
+    .format_timestamp(Some(env_logger::TimestampPrecision::Micros))
+    .format_target(false)
+    .write_style(match cli.color {
+        ColorChoice::Auto => env_logger::WriteStyle::Auto,
+        ColorChoice::Always => env_logger::WriteStyle::Always,
+        ColorChoice::Never => env_logger::WriteStyle::Never,
+    })
+    .init();
 
-    let fmt = if cli.json {
+    let fmt = if cli.json || cli.concise_json || cli.json_map || cli.stream {
         OutputFormat::Json
+    } else if cli.csv {
+        OutputFormat::Csv
+    } else if cli.yaml {
+        OutputFormat::Yaml
     } else {
         OutputFormat::Plain
     };
+    let csv_supported = matches!(
+        cli.command,
+        cli::Commands::Scan { .. }
+            | cli::Commands::ReadUint8 { .. }
+            | cli::Commands::ReadUint16 { .. }
+            | cli::Commands::ReadUint32 { .. }
+            | cli::Commands::ReadInt8 { .. }
+            | cli::Commands::ReadInt16 { .. }
+            | cli::Commands::ReadInt32 { .. }
+            | cli::Commands::ReadReg { .. }
+    );
+    if cli.csv && !csv_supported {
+        return Err(anyhow!("Csv output is not supported by this command"));
+    }
+    let yaml_supported = matches!(
+        cli.command,
+        cli::Commands::Dump { .. } | cli::Commands::ReadReg { .. }
+    );
+    if cli.yaml && !yaml_supported {
+        return Err(anyhow!("Yaml output is not supported by this command"));
+    }
+    CONCISE_JSON.store(cli.concise_json, Ordering::Relaxed);
+    let layout = if cli.stream {
+        JsonLayout::Stream
+    } else if cli.json_always_array {
+        JsonLayout::Array
+    } else if cli.json_map {
+        JsonLayout::Map
+    } else {
+        JsonLayout::Auto
+    };
+
+    // Before deciding on colors, which a file never gets in auto mode
+    if let Some(path) = &cli.out {
+        open_output(path)?;
+    }
+
+    // Only results of bus queries are colored, never json
+    let color = matches!(fmt, OutputFormat::Plain)
+        && !cli.dry_run
+        && match cli.color {
+            ColorChoice::Auto => atty::is(atty::Stream::Stdout) && !output_is_file(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+        && matches!(
+            cli.command,
+            cli::Commands::Scan { .. }
+                | cli::Commands::Ping { .. }
+                | cli::Commands::Firmware { .. }
+                | cli::Commands::ExpectCount { .. }
+                | cli::Commands::Identify { .. }
+                | cli::Commands::DetectBaud { .. }
+                | cli::Commands::DetectModel { .. }
+                | cli::Commands::ReadUint8 { .. }
+                | cli::Commands::ReadUint16 { .. }
+                | cli::Commands::ReadUint32 { .. }
+                | cli::Commands::ReadInt8 { .. }
+                | cli::Commands::ReadInt16 { .. }
+                | cli::Commands::ReadInt32 { .. }
+                | cli::Commands::ReadBytes { .. }
+                | cli::Commands::ReadBytesMultiple { .. }
+                | cli::Commands::ReadReg { .. }
+                | cli::Commands::Dump { .. }
+        );
+    COLOR.store(color, Ordering::Relaxed);
+
+    if let Some(path) = &cli.regs_file {
+        regs::add_registers(&regs_file::load(path)?);
+    }
+    let vregs = match &cli.virtual_regs {
+        Some(path) => virtual_regs::load(path)?,
+        None => Vec::new(),
+    };
+
+    port::add_compatible_ids(&cli.usb_ids);
+    let bus = Bus {
+        port: cli.port,
+        baudrate: cli.baudrate,
+        force: cli.force,
+        retries: cli.retries,
+        timeout: match cli.timeout {
+            0 => Duration::MAX,
+            ms => Duration::from_millis(ms),
+        },
+        tx: master::TxOptions {
+            delay: Duration::from_micros(cli.tx_delay),
+            echo_cancel: cli.echo_cancel,
+        },
+        rs485: port::Rs485Config {
+            rts_on_send: cli.rs485_rts_on_send == Level::High,
+            rts_after_send: cli.rs485_rts_after_send == Level::High,
+            delay_before_send: cli.rs485_delay_before,
+            delay_after_send: cli.rs485_delay_after,
+        },
+        protocol: cli.protocol,
+    };
 
-    match cli.command {
-        cli::Commands::ListModels => cmd_list_models(cli.protocol, fmt),
-        cli::Commands::ListRegisters { model } => cmd_list_registers(cli.protocol, &model, fmt),
+    let output = match cli.command {
+        cli::Commands::ListModels { family, verbose } => {
+            cmd_list_models(cli.protocol, family, verbose, fmt)
+        }
+        cli::Commands::Completions { shell } => {
+            write!(Output, "{}", completions::completion_script(shell))?;
+            Ok(String::new())
+        }
+        cli::Commands::ListRegisters {
+            filter,
+            address,
+            access,
+            area,
+            sort,
+            model,
+        } => {
+            let query = regs::RegisterQuery {
+                filter,
+                address,
+                access: access.map(|access| match access {
+                    AccessArg::R => regs::Access::R,
+                    AccessArg::W => regs::Access::W,
+                    AccessArg::Rw => regs::Access::RW,
+                }),
+                area: area.map(|area| match area {
+                    AreaArg::Eeprom => regs::Area::Eeprom,
+                    AreaArg::Ram => regs::Area::Ram,
+                }),
+                order: match sort {
+                    RegisterSort::Address => regs::RegisterOrder::Address,
+                    RegisterSort::Name => regs::RegisterOrder::Name,
+                    RegisterSort::Size => regs::RegisterOrder::Size,
+                },
+            };
+            cmd_list_registers(cli.protocol, &model, &query, fmt)
+        }
+        cli::Commands::ListPorts => cmd_list_ports(fmt),
+        // These open the port at several baud rates themselves
+        cli::Commands::Identify { .. }
+        | cli::Commands::DetectBaud { .. }
+        | cli::Commands::ChangeBaud { .. }
+            if cli.dry_run =>
+        {
+            Err(anyhow!("This command doesn't support --dry-run"))
+        }
+        cli::Commands::Scan {
+            ref bauds,
+            all_bauds,
+            ..
+        } if cli.dry_run && (all_bauds || !bauds.is_empty()) => Err(anyhow!(
+            "Scan at other baud rates doesn't support --dry-run"
+        )),
+        cli::Commands::Identify {
+            ids,
+            probe_baudrates,
+        } => cmd_identify(&bus, &ids, probe_baudrates, fmt),
+        cli::Commands::DetectBaud { ids } => cmd_detect_baud(&bus, &ids, fmt),
+        cli::Commands::Scan {
+            detail,
+            models,
+            sort,
+            bauds,
+            all_bauds,
+            scan_start,
+            scan_end,
+        } if all_bauds || !bauds.is_empty() => {
+            let bauds = if all_bauds { BAUDRATES.to_vec() } else { bauds };
+            let report = scan_report(detail, models);
+            cmd_scan_bauds(&bus, &bauds, scan_start, scan_end, report, sort, fmt)
+        }
+        cli::Commands::ChangeBaud { ids, baudrate } => {
+            cmd_change_baud(&bus, &ids, baudrate, fmt, layout)
+        }
+        _ if cli.dry_run => cmd_dry_run(cli.command, &bus, fmt, layout, &vregs),
+        cli::Commands::Run {
+            script,
+            keep_going,
+            ndjson,
+            reconnect: true,
+        } => {
+            let mut port = ReconnectingPort::new(&bus, || bus.open(bus.baudrate))?;
+            let mut exec = |command| port.run(command, fmt, layout, &vregs);
+            cmd_run(&mut exec, &script, keep_going, ndjson, fmt)
+        }
         _ => {
-            let mut port = port::open_port(&cli.port, cli.baudrate, cli.force)?;
-            let mut proto_box =
-                protocol::master::make_protocol(cli.protocol, port.as_mut(), cli.retries);
+            let mut port = bus.open(bus.baudrate)?;
+            let mut proto_box = protocol::master::make_protocol(
+                bus.protocol,
+                port.as_mut(),
+                bus.retries,
+                bus.timeout,
+                bus.tx,
+            );
             let proto = proto_box.as_mut();
 
-            match cli.command {
-                cli::Commands::Scan {
-                    scan_start,
-                    scan_end,
-                } => cmd_scan(proto, scan_start, scan_end, fmt),
-                cli::Commands::ReadUint8 { ids, address, sync } => {
-                    cmd_read_int::<1, u8>(proto, &ids, address, fmt, sync)
-                }
-                cli::Commands::ReadUint16 { ids, address, sync } => {
-                    cmd_read_int::<2, u16>(proto, &ids, address, fmt, sync)
-                }
-                cli::Commands::ReadUint32 { ids, address, sync } => {
-                    cmd_read_int::<4, u32>(proto, &ids, address, fmt, sync)
-                }
-                cli::Commands::ReadBytes {
-                    ids,
-                    address,
-                    count,
-                } => cmd_read_bytes(proto, &ids, address, count, fmt),
-                cli::Commands::ReadBytesMultiple { specs } => {
-                    cmd_read_bytes_multiple(proto, &specs, fmt)
-                }
-                cli::Commands::ReadReg { ids, reg } => cmd_read_reg(proto, &ids, reg, fmt),
-                cli::Commands::WriteUint8 {
-                    ids,
-                    address,
-                    value,
-                    sync,
-                } => cmd_write_int(proto, &ids, address, &value, sync),
-                cli::Commands::WriteUint16 {
-                    ids,
-                    address,
-                    value,
-                    sync,
-                } => cmd_write_int(proto, &ids, address, &value, sync),
-                cli::Commands::WriteUint32 {
-                    ids,
-                    address,
-                    value,
-                    sync,
-                } => cmd_write_int(proto, &ids, address, &value, sync),
-                cli::Commands::WriteBytes {
-                    ids,
-                    address,
-                    values,
-                } => cmd_write_bytes(proto, &ids, address, &values),
-                cli::Commands::WriteReg { ids, reg, value } => {
-                    cmd_write_reg(proto, &ids, reg, value)
-                }
-                cli::Commands::WriteBytesMultiple { specs } => {
-                    cmd_write_bytes_multiple(proto, &specs)
-                }
-                _ => Err(anyhow!("unexpected command (this is a bug!)")),
-            }
+            run_command(proto, cli.command, &bus, fmt, layout, &vregs)
         }
-    }
+    }?;
+
+    Ok(output)
 }
 
 fn main() {
-    match do_main() {
-        Ok(s) => println!("{}", s),
-        Err(e) => error!("{:#}", e),
+    let res = do_main()
+        .and_then(|output| Ok(print_output(&output)?))
+        .and_then(|_| close_output(true));
+    let e = match res {
+        Ok(()) => return,
+        Err(e) if is_broken_pipe(&e) => return,
+        Err(e) => e,
+    };
+
+    if let Some(ExitFailure(output)) = e.downcast_ref::<ExitFailure>() {
+        // The output is complete, the status only reports what it found
+        let _ = print_output(output);
+        if let Err(e) = close_output(true) {
+            error!("{:#}", e);
+        }
+    } else {
+        let _ = close_output(false);
+        if log::log_enabled!(log::Level::Error) {
+            error!("{:#}", e);
+        } else {
+            // Logging turned off with RUST_LOG, the error still has to show
+            eprintln!("{:#}", e);
+        }
+    }
+    std::process::exit(exit_code(&e));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run `f` with a protocol over a dry run port, returning the packets
+    /// sent
+    fn dry_run<T>(
+        version: ProtocolVersion,
+        f: impl FnOnce(&mut dyn Protocol) -> T,
+    ) -> (T, Vec<Vec<u8>>) {
+        let mut port = DryRunPort::new(version, 1_000_000);
+        let res = {
+            let mut proto = master::make_protocol(
+                version,
+                &mut port,
+                0,
+                Duration::ZERO,
+                master::TxOptions::default(),
+            );
+            f(proto.as_mut())
+        };
+        (res, port.packets().to_vec())
+    }
+
+    fn describe(version: ProtocolVersion, packets: &[Vec<u8>]) -> Vec<String> {
+        packets
+            .iter()
+            .map(|packet| master::describe_packet(version, packet))
+            .collect()
+    }
+
+    #[test]
+    fn factory_reset_needs_force_for_several_ids() {
+        for ids in [&[1, 2][..], &[BROADCAST_ID][..]] {
+            let (res, packets) = dry_run(ProtocolVersion::V2, |proto| {
+                cmd_factory_reset(proto, ids, ResetScope::All, false)
+            });
+            assert!(res.is_err());
+            assert!(packets.is_empty());
+        }
+
+        let (res, packets) = dry_run(ProtocolVersion::V2, |proto| {
+            cmd_factory_reset(proto, &[1, 2], ResetScope::All, true)
+        });
+        assert!(res.is_ok());
+        assert_eq!(packets.len(), 2);
+    }
+
+    #[test]
+    fn negative_values_are_printed() {
+        let ids = [1, 2, 3];
+        let res = widen(vec![Some(-4000i16), None, Some(i16::MIN)]);
+
+        assert_eq!(
+            int_output(&ids, res.clone(), OutputFormat::Plain, JsonLayout::Auto),
+            "-4000 - -32768"
+        );
+        assert_eq!(
+            int_output(&ids, res.clone(), OutputFormat::Json, JsonLayout::Auto),
+            "[-4000,null,-32768]"
+        );
+        assert_eq!(
+            int_output(&ids, res, OutputFormat::Csv, JsonLayout::Auto),
+            "1,-4000\n2,\n3,-32768"
+        );
+        let min = widen(vec![Some(i32::MIN)]);
+        assert_eq!(
+            int_output(&[1], min, OutputFormat::Json, JsonLayout::Auto),
+            "-2147483648"
+        );
+    }
+
+    #[test]
+    fn negative_values_are_printed_as_twos_complement_hex() {
+        assert_eq!(Hex(-1i8, 1).to_string(), "0xFF");
+        assert_eq!(Hex(-4000i16, 2).to_string(), "0xF060");
+        assert_eq!(Hex(-2i32, 4).to_string(), "0xFFFFFFFE");
+    }
+
+    #[test]
+    fn sync_write_mixes_signs() {
+        let targets = WriteTargets::Ids("1,2".parse().unwrap());
+        let (res, packets) = dry_run(ProtocolVersion::V2, |proto| {
+            cmd_write_int::<2, i16>(proto, &targets, false, 116, &[i16::MIN, 1024], true, false)
+        });
+
+        assert!(res.is_ok());
+        assert_eq!(
+            describe(ProtocolVersion::V2, &packets),
+            ["SYNC_WRITE broadcast address 116 size 2: id 1 data 00 80, id 2 data 00 04"]
+        );
+    }
+
+    #[test]
+    fn map_write_rejects_out_of_range_values() {
+        let targets = WriteTargets::Map("1=-1,2=-40000".parse().unwrap());
+        let (res, packets) = dry_run(ProtocolVersion::V2, |proto| {
+            cmd_write_int::<2, i16>(proto, &targets, true, 116, &[], false, false)
+        });
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Value -40000 for id 2 is out of range for i16"
+        );
+        assert!(packets.is_empty());
+    }
+
+    #[test]
+    fn read_bytes_sync_sends_one_packet() {
+        let read = |version, strict| {
+            dry_run(version, |proto| {
+                let fmt = OutputFormat::Plain;
+                cmd_read_bytes(
+                    proto,
+                    &[1, 2, 3],
+                    132,
+                    4,
+                    true,
+                    strict,
+                    false,
+                    fmt,
+                    JsonLayout::Auto,
+                )
+            })
+        };
+
+        // Nothing answers a dry run, only the packets sent matter
+        let (_, packets) = read(ProtocolVersion::V2, false);
+        assert_eq!(
+            describe(ProtocolVersion::V2, &packets),
+            ["SYNC_READ broadcast address 132 count 4 ids [1, 2, 3]"]
+        );
+
+        // Protocol 1 falls back to a read per id, unless strict
+        let (res, packets) = read(ProtocolVersion::V1, true);
+        assert!(res.is_err());
+        assert!(packets.is_empty());
+        let (_, packets) = read(ProtocolVersion::V1, false);
+        assert_eq!(
+            describe(ProtocolVersion::V1, &packets),
+            [
+                "READ id 1 address 132 count 4",
+                "READ id 2 address 132 count 4",
+                "READ id 3 address 132 count 4",
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_names_get_suggestions() {
+        let model = lookup_model(ProtocolVersion::V1, "AX-12A").unwrap();
+        assert_eq!(
+            register_not_found(&model, "goal_position").to_string(),
+            "Register goal_position not found for AX-12A; did you mean GoalPosition?"
+        );
+        assert_eq!(
+            register_not_found(&model, "Frobnicate").to_string(),
+            "Register Frobnicate not found for AX-12A"
+        );
+        assert_eq!(
+            lookup_model(ProtocolVersion::V2, "xm430-w35")
+                .unwrap_err()
+                .to_string(),
+            "Model xm430-w35 not found (protocol 2); did you mean XM430-W350 or XH430-W350?"
+        );
+    }
+
+    #[test]
+    fn did_you_mean_lists_names() {
+        assert_eq!(did_you_mean(&[]), "");
+        assert_eq!(did_you_mean(&["A"]), "; did you mean A?");
+        assert_eq!(did_you_mean(&["A", "B", "C"]), "; did you mean A, B or C?");
+    }
+
+    #[test]
+    fn errors_map_to_exit_codes() {
+        let busy = port::OpenPortError::PortBusy {
+            port_name: "/dev/ttyUSB0".to_string(),
+        };
+        let status = ProtocolError::StatusError(StatusByte {
+            version: ProtocolVersion::V2,
+            value: 0x80,
+        });
+
+        assert_eq!(exit_code(&busy.into()), EXIT_PORT);
+        assert_eq!(
+            exit_code(&port::OpenPortError::NoCompatiblePort.into()),
+            EXIT_PORT
+        );
+        assert_eq!(exit_code(&ProtocolError::TimedOut.into()), EXIT_TIMEOUT);
+        assert_eq!(exit_code(&status.into()), EXIT_STATUS);
+        assert_eq!(exit_code(&ProtocolError::BadPacket.into()), EXIT_FAILURE);
+        assert_eq!(exit_code(&anyhow!("Need 2 values, got 3")), EXIT_FAILURE);
+    }
+
+    #[test]
+    fn exit_code_looks_through_context() {
+        let e = anyhow::Error::from(ProtocolError::TimedOut).context("Failed to read from id 1");
+        assert_eq!(exit_code(&e), EXIT_TIMEOUT);
+    }
+
+    #[test]
+    fn broken_pipe_is_detected() {
+        let e = anyhow::Error::from(io::Error::from(io::ErrorKind::BrokenPipe));
+        assert!(is_broken_pipe(&e.context("Failed to print")));
+        assert!(!is_broken_pipe(&anyhow!("Failed to print")));
+    }
+
+    #[test]
+    fn return_level_address_is_per_model() {
+        let (res, packets) = dry_run(ProtocolVersion::V2, |proto| {
+            cmd_set_return_level(proto, &[1], Some("XL320"), 1)
+        });
+        assert!(res.is_ok());
+        assert_eq!(
+            describe(ProtocolVersion::V2, &packets),
+            ["WRITE id 1 address 17 data 01"]
+        );
+
+        // Nothing answers a dry run, so there is no model to detect
+        let (res, packets) = dry_run(ProtocolVersion::V2, |proto| {
+            cmd_set_return_level(proto, &[1], None, 1)
+        });
+        assert!(format!("{:#}", res.unwrap_err()).contains("--model"));
+        assert_eq!(describe(ProtocolVersion::V2, &packets), ["PING id 1"]);
+    }
+
+    #[test]
+    fn ndjson_results_are_parsed_per_line() {
+        let json = OutputFormat::Json;
+        assert_eq!(ndjson_result("", json).unwrap(), json::JsonValue::Null);
+        assert_eq!(ndjson_result("[1,2]\n", json).unwrap(), json::array![1, 2]);
+        assert_eq!(
+            ndjson_result("{\"id\":1}\n{\"id\":2}\n", json).unwrap(),
+            json::array![{id: 1}, {id: 2}]
+        );
+        assert_eq!(
+            ndjson_result("1 2\n3 4\n", OutputFormat::Plain).unwrap(),
+            "1 2\n3 4"
+        );
+
+        let err = ndjson_result("[1,2]\nnot json\n", json).unwrap_err();
+        assert_eq!(err.to_string(), "Output is not json: not json");
+    }
+
+    #[test]
+    fn concise_json_keeps_array_elements_in_place() {
+        let values = json::array![-4000, null, -32768];
+        assert_eq!(prune_json(values.clone()), values);
+        // diff output of a register only one of the devices could read
+        let diff = json::array![{name: "GoalPosition", values: [null, 5]}];
+        assert_eq!(prune_json(diff.clone()), diff);
+    }
+
+    #[test]
+    fn concise_json_drops_null_and_empty_members() {
+        let value = json::object! {
+            id: 1,
+            model: null,
+            regs: {},
+            values: [],
+            unit: {name: null},
+            firmware: {version: 42, date: null},
+            flags: [false, 0, ""],
+        };
+        assert_eq!(
+            prune_json(value),
+            json::object! {id: 1, firmware: {version: 42}, flags: [false, 0, ""]}
+        );
+    }
+
+    #[test]
+    fn results_are_colored_by_outcome() {
+        // Colors are off unless do_main turns them on
+        assert_eq!(paint(5, Outcome::Error), "5");
+        assert_eq!(
+            colored("5".to_string(), Color::Red),
+            "\x1b[0m\x1b[31m5\x1b[0m"
+        );
+        assert_eq!(colored(String::new(), Color::Green), "");
+    }
+
+    #[test]
+    fn interrupt_is_cleared_for_each_command() {
+        // Ctrl-C that stopped an earlier command of a run session
+        INTERRUPTED.store(true, Ordering::Relaxed);
+        catch_interrupt();
+        assert!(!INTERRUPTED.load(Ordering::Relaxed));
+    }
+
+    /// Serial port of an unplugged adapter, every call fails
+    struct GonePort;
+
+    fn gone() -> serialport::Error {
+        serialport::Error::new(serialport::ErrorKind::NoDevice, "device is gone")
+    }
+
+    impl io::Read for GonePort {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    impl io::Write for GonePort {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    impl SerialPort for GonePort {
+        fn name(&self) -> Option<String> {
+            None
+        }
+
+        fn baud_rate(&self) -> serialport::Result<u32> {
+            Err(gone())
+        }
+
+        fn data_bits(&self) -> serialport::Result<serialport::DataBits> {
+            Err(gone())
+        }
+
+        fn flow_control(&self) -> serialport::Result<serialport::FlowControl> {
+            Err(gone())
+        }
+
+        fn parity(&self) -> serialport::Result<serialport::Parity> {
+            Err(gone())
+        }
+
+        fn stop_bits(&self) -> serialport::Result<serialport::StopBits> {
+            Err(gone())
+        }
+
+        fn timeout(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        fn set_baud_rate(&mut self, _baud_rate: u32) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn set_data_bits(&mut self, _data_bits: serialport::DataBits) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn set_flow_control(
+            &mut self,
+            _flow_control: serialport::FlowControl,
+        ) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn set_parity(&mut self, _parity: serialport::Parity) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn set_stop_bits(&mut self, _stop_bits: serialport::StopBits) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn set_timeout(&mut self, _timeout: Duration) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn write_request_to_send(&mut self, _level: bool) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn write_data_terminal_ready(&mut self, _level: bool) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn read_clear_to_send(&mut self) -> serialport::Result<bool> {
+            Err(gone())
+        }
+
+        fn read_data_set_ready(&mut self) -> serialport::Result<bool> {
+            Err(gone())
+        }
+
+        fn read_ring_indicator(&mut self) -> serialport::Result<bool> {
+            Err(gone())
+        }
+
+        fn read_carrier_detect(&mut self) -> serialport::Result<bool> {
+            Err(gone())
+        }
+
+        fn bytes_to_read(&self) -> serialport::Result<u32> {
+            Err(gone())
+        }
+
+        fn bytes_to_write(&self) -> serialport::Result<u32> {
+            Err(gone())
+        }
+
+        fn clear(&self, _buffer_to_clear: serialport::ClearBuffer) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn try_clone(&self) -> serialport::Result<Box<dyn SerialPort>> {
+            Err(gone())
+        }
+
+        fn set_break(&self) -> serialport::Result<()> {
+            Err(gone())
+        }
+
+        fn clear_break(&self) -> serialport::Result<()> {
+            Err(gone())
+        }
+    }
+
+    #[test]
+    fn gone_port_is_reopened_for_the_next_command() {
+        let bus = Bus {
+            port: "test".to_string(),
+            baudrate: 1_000_000,
+            force: false,
+            retries: 0,
+            timeout: Duration::ZERO,
+            tx: master::TxOptions::default(),
+            rs485: port::Rs485Config::default(),
+            protocol: ProtocolVersion::V2,
+        };
+        let opened = std::cell::Cell::new(0);
+        let mut port = ReconnectingPort::new(&bus, || {
+            opened.set(opened.get() + 1);
+            let port: Box<dyn SerialPort + Send> = if opened.get() == 1 {
+                Box::new(GonePort)
+            } else {
+                Box::new(DryRunPort::new(bus.protocol, bus.baudrate))
+            };
+            Ok(port)
+        })
+        .unwrap();
+        let mut run = |line: &[&str]| {
+            let command = cli::ScriptLine::try_parse_from(line).unwrap().command;
+            port.run(command, OutputFormat::Plain, JsonLayout::Auto, &[])
+        };
+
+        assert!(run(&["action", "1"]).is_err());
+        assert!(run(&["action", "1"]).is_ok());
+        assert_eq!(opened.get(), 2);
+
+        // A timeout keeps the port
+        assert!(run(&["ping", "1"]).is_err());
+        assert!(run(&["action", "1"]).is_ok());
+        assert_eq!(opened.get(), 2);
     }
 }
@@ -0,0 +1,92 @@
+//! Register definitions loaded from a json file, for control tables missing
+//! from the built-in database, e.g.
+//!
+//! ```text
+//! [
+//!     {"model": "XM430-W350", "proto": 2, "name": "GoalPosition",
+//!      "address": 116, "size": 4, "access": "RW", "signed": true}
+//! ]
+//! ```
+//!
+//! `proto` is 1 or 2, `size` 1, 2 or 4 and `access` R, W or RW. `signed`
+//! is optional.
+
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+
+use dynamixel_lib::protocol::ProtocolVersion;
+use dynamixel_lib::regs::{Access, Reg, RegSize};
+
+/// The database holds `&'static str`s, and registers are loaded once
+fn leak(s: &str) -> &'static str {
+    Box::leak(s.to_string().into_boxed_str())
+}
+
+fn field<'a>(entry: &'a json::JsonValue, name: &str) -> Result<&'a json::JsonValue> {
+    match &entry[name] {
+        json::JsonValue::Null => Err(anyhow!("missing '{}'", name)),
+        value => Ok(value),
+    }
+}
+
+fn string_field(entry: &json::JsonValue, name: &str) -> Result<&'static str> {
+    field(entry, name)?
+        .as_str()
+        .map(leak)
+        .ok_or_else(|| anyhow!("'{}' is not a string", name))
+}
+
+fn parse_entry(entry: &json::JsonValue) -> Result<Reg> {
+    let proto = match field(entry, "proto")?.as_u8() {
+        Some(1) => ProtocolVersion::V1,
+        Some(2) => ProtocolVersion::V2,
+        _ => return Err(anyhow!("'proto' is not 1 or 2")),
+    };
+    let address = field(entry, "address")?
+        .as_u16()
+        .ok_or_else(|| anyhow!("'address' is not a 16-bit number"))?;
+    let size = match field(entry, "size")?.as_u8() {
+        Some(1) => RegSize::Byte,
+        Some(2) => RegSize::Half,
+        Some(4) => RegSize::Word,
+        _ => return Err(anyhow!("'size' is not 1, 2 or 4")),
+    };
+    let access = match field(entry, "access")?.as_str() {
+        Some("R") => Access::R,
+        Some("W") => Access::W,
+        Some("RW") => Access::RW,
+        _ => return Err(anyhow!("'access' is not R, W or RW")),
+    };
+    let signed = match &entry["signed"] {
+        json::JsonValue::Null => false,
+        value => value
+            .as_bool()
+            .ok_or_else(|| anyhow!("'signed' is not a boolean"))?,
+    };
+
+    let reg = Reg::new(
+        string_field(entry, "model")?,
+        proto,
+        string_field(entry, "name")?,
+        address,
+        size,
+        access,
+    );
+    Ok(if signed { reg.signed() } else { reg })
+}
+
+pub fn load(path: &str) -> Result<Vec<Reg>> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read registers from {}", path))?;
+    let doc = json::parse(&text).with_context(|| format!("Failed to parse {}", path))?;
+    if !doc.is_array() {
+        return Err(anyhow!("{}: expected an array of registers", path));
+    }
+
+    doc.members()
+        .enumerate()
+        .map(|(i, entry)| {
+            parse_entry(entry).with_context(|| format!("{}: register {}", path, i + 1))
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
 use std::convert::TryInto;
+use std::time::Duration;
 
 use anyhow::Result;
 use env_logger::TimestampPrecision;
@@ -11,7 +12,7 @@ use dynamixel_lib::protocol::ProtocolVersion;
 #[tokio::main]
 async fn main() -> Result<()> {
     let mut port = port::open_port_async("auto", 1000000, false)?;
-    let mut proto = make_async_protocol(ProtocolVersion::V2, &mut port);
+    let mut proto = make_async_protocol(ProtocolVersion::V2, &mut port, Duration::from_millis(100));
     let mut regs = [0u8; 65536];
     let my_id = 10u8;
 
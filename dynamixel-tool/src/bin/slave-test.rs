@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use anyhow::Result;
 
 use dynamixel_lib::port::{self};
@@ -9,7 +11,7 @@ use log::{info, error};
 #[tokio::main]
 async fn main() -> Result<()> {
     let mut port = port::open_port_async("auto", 1000000, false)?;
-    let mut proto = make_async_protocol(ProtocolVersion::V1, &mut port);
+    let mut proto = make_async_protocol(ProtocolVersion::V1, &mut port, Duration::from_millis(100));
     let mut regs = [0u8; 256];
     let my_id = 10u8;
 
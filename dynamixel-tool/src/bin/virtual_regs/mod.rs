@@ -0,0 +1,260 @@
+//! Virtual registers: formulas over real registers of a model, loaded from a
+//! file with one definition per line, e.g.
+//!
+//! ```text
+//! # comment
+//! XM430-W350/PresentAngle = PresentPosition * 360 / 4096
+//! ```
+//!
+//! Formulas support numbers, register names, `+ - * / %` and parentheses.
+
+use anyhow::{anyhow, Context, Result};
+use std::{fs, iter::Peekable, str::Chars};
+use thiserror::Error;
+
+use dynamixel_lib::protocol::ProtocolVersion;
+use dynamixel_lib::regs::{self, RegSpec};
+
+#[derive(Error, Debug)]
+pub enum ExprError {
+    #[error("unexpected '{0}'")]
+    Unexpected(char),
+    #[error("unexpected end of formula")]
+    UnexpectedEnd,
+    #[error("invalid number '{0}'")]
+    BadNumber(String),
+}
+
+#[derive(Debug)]
+enum Expr {
+    Num(f64),
+    Reg(String),
+    Neg(Box<Expr>),
+    Bin(char, Box<Expr>, Box<Expr>),
+}
+
+/// Recursive descent parser, one function per precedence level
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+        self.chars.peek().cloned()
+    }
+
+    fn sum(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.product()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.chars.next();
+            lhs = Expr::Bin(op, Box::new(lhs), Box::new(self.product()?));
+        }
+        Ok(lhs)
+    }
+
+    fn product(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.unary()?;
+        while let Some(op @ ('*' | '/' | '%')) = self.peek() {
+            self.chars.next();
+            lhs = Expr::Bin(op, Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ExprError> {
+        match self.peek() {
+            Some('-') => {
+                self.chars.next();
+                Ok(Expr::Neg(Box::new(self.unary()?)))
+            }
+            Some('(') => {
+                self.chars.next();
+                let expr = self.sum()?;
+                match self.peek() {
+                    Some(')') => {
+                        self.chars.next();
+                        Ok(expr)
+                    }
+                    Some(c) => Err(ExprError::Unexpected(c)),
+                    None => Err(ExprError::UnexpectedEnd),
+                }
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let mut s = String::new();
+                while let Some(c) = self
+                    .chars
+                    .next_if(|c| c.is_ascii_alphanumeric() || *c == '.')
+                {
+                    s.push(c);
+                }
+                parse_number(&s).map(Expr::Num)
+            }
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+                let mut s = String::new();
+                while let Some(c) = self
+                    .chars
+                    .next_if(|c| c.is_ascii_alphanumeric() || *c == '_')
+                {
+                    s.push(c);
+                }
+                Ok(Expr::Reg(s))
+            }
+            Some(c) => Err(ExprError::Unexpected(c)),
+            None => Err(ExprError::UnexpectedEnd),
+        }
+    }
+}
+
+fn parse_number(s: &str) -> Result<f64, ExprError> {
+    let value = if let Some(hex) = s.strip_prefix("0x") {
+        u32::from_str_radix(hex, 16).map(f64::from).ok()
+    } else {
+        s.parse().ok()
+    };
+    value.ok_or_else(|| ExprError::BadNumber(s.to_string()))
+}
+
+impl Expr {
+    fn parse(s: &str) -> Result<Self, ExprError> {
+        let mut parser = Parser {
+            chars: s.chars().peekable(),
+        };
+        let expr = parser.sum()?;
+        match parser.peek() {
+            Some(c) => Err(ExprError::Unexpected(c)),
+            None => Ok(expr),
+        }
+    }
+
+    fn registers<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Expr::Num(_) => {}
+            Expr::Reg(name) => {
+                if !names.contains(&name.as_str()) {
+                    names.push(name)
+                }
+            }
+            Expr::Neg(e) => e.registers(names),
+            Expr::Bin(_, lhs, rhs) => {
+                lhs.registers(names);
+                rhs.registers(names);
+            }
+        }
+    }
+
+    fn eval(&self, value: &dyn Fn(&str) -> f64) -> f64 {
+        match self {
+            Expr::Num(n) => *n,
+            Expr::Reg(name) => value(name),
+            Expr::Neg(e) => -e.eval(value),
+            Expr::Bin(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.eval(value), rhs.eval(value));
+                match op {
+                    '+' => lhs + rhs,
+                    '-' => lhs - rhs,
+                    '*' => lhs * rhs,
+                    '/' => lhs / rhs,
+                    _ => lhs % rhs,
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct VirtualReg {
+    pub model: String,
+    pub name: String,
+    expr: Expr,
+}
+
+impl VirtualReg {
+    /// Real registers the formula refers to, in order of appearance
+    pub fn registers(&self) -> Vec<RegSpec> {
+        let mut names = Vec::new();
+        self.expr.registers(&mut names);
+        names
+            .into_iter()
+            .map(|name| RegSpec {
+                model: Some(self.model.clone()),
+                name: name.to_string(),
+            })
+            .collect()
+    }
+
+    /// Evaluate with register values given in the order of `registers()`,
+    /// signed registers sign extended
+    pub fn eval(&self, values: &[i64]) -> f64 {
+        let mut names = Vec::new();
+        self.expr.registers(&mut names);
+        // Adding zero turns -0 into 0
+        self.expr.eval(&|name| {
+            let i = names.iter().position(|n| *n == name).unwrap();
+            values[i] as f64
+        }) + 0.0
+    }
+}
+
+fn is_real_register(model: &str, name: &str) -> bool {
+    [ProtocolVersion::V1, ProtocolVersion::V2]
+        .iter()
+        .any(|&proto| {
+            regs::list_registers(proto, model)
+                .iter()
+                .any(|reg| reg.name == name)
+        })
+}
+
+fn parse_line(line: &str) -> Result<VirtualReg> {
+    let (spec, formula) = line
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected MODEL/NAME = FORMULA"))?;
+    let spec: RegSpec = spec.trim().parse()?;
+    let model = spec
+        .model
+        .ok_or_else(|| anyhow!("expected MODEL/NAME = FORMULA"))?;
+    if is_real_register(&model, &spec.name) {
+        return Err(anyhow!("{}/{} is a real register", model, spec.name));
+    }
+
+    Ok(VirtualReg {
+        model,
+        name: spec.name,
+        expr: Expr::parse(formula)?,
+    })
+}
+
+pub fn load(path: &str) -> Result<Vec<VirtualReg>> {
+    let text = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read virtual registers from {}", path))?;
+
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.split('#').next().unwrap().trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_line(line).with_context(|| format!("{}:{}", path, i + 1)))
+        .collect()
+}
+
+pub fn find<'a>(vregs: &'a [VirtualReg], spec: &RegSpec) -> Option<&'a VirtualReg> {
+    vregs
+        .iter()
+        .find(|vreg| spec.model.as_ref() == Some(&vreg.model) && vreg.name == spec.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_registers_are_negative() {
+        let vreg = parse_line("XM430-W350/CurrentMA = PresentCurrent * 2.69").unwrap();
+        let spec = vreg.registers().remove(0);
+        assert_eq!(spec.name, "PresentCurrent");
+
+        let reg = regs::find_register(ProtocolVersion::V2, spec).unwrap();
+        let raw = -5i16 as u16 as u32;
+        assert_eq!(vreg.eval(&[reg.value_from_raw(raw)]), -5.0 * 2.69);
+    }
+}
@@ -0,0 +1,40 @@
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_dynamixel-tool"))
+        .args(args)
+        .output()
+        .expect("dynamixel-tool runs")
+}
+
+#[test]
+fn usage_error_exits_64() {
+    let output = run(&["read-uint8", "1"]);
+    assert_eq!(output.status.code(), Some(64));
+    assert!(output.stdout.is_empty());
+    assert!(!output.stderr.is_empty());
+}
+
+#[test]
+fn missing_port_exits_2() {
+    let output = run(&["--port", "/nonexistent/ttyUSB0", "read-uint8", "1", "0"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("/nonexistent/ttyUSB0"));
+}
+
+#[test]
+fn help_and_version_succeed() {
+    for arg in ["--help", "--version"] {
+        let output = run(&[arg]);
+        assert_eq!(output.status.code(), Some(0), "{}", arg);
+        assert!(!output.stdout.is_empty(), "{}", arg);
+    }
+}
+
+#[test]
+fn silent_success_prints_nothing() {
+    let output = run(&["list-registers", "AX-12A", "--filter", "NoSuchRegister"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+}